@@ -1,6 +1,6 @@
 use cosmwasm_std::{
-    coin, Addr, BankMsg, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
-    Uint128,
+    coin, to_json_binary, Addr, BankMsg, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdResult, SubMsg, Uint128, WasmMsg,
 };
 
 use osmosis_std::types::{
@@ -8,11 +8,14 @@ use osmosis_std::types::{
     osmosis::tokenfactory::v1beta1 as OsmosisFactory,
 };
 
+use cw20::{Cw20ExecuteMsg, MinterResponse};
+
 use gopstake_base::{
+    constants::CREATE_CW20_REPLY_ID,
     error::ContractError,
     minter::{
-        state::{CONFIG, TOKENS},
-        types::{Config, DenomUnit, Metadata},
+        state::{CONFIG, CW20_TOKENS, CW20_TOKEN_OWNER, TOKENS},
+        types::{Config, Cw20TokenInfo, DenomUnit, Metadata},
     },
     utils::{check_funds, unwrap_field, Attrs, AuthType, FundsType},
 };
@@ -71,6 +74,51 @@ pub fn try_create_denom(
         .add_attributes([("action", "try_create_denom")]))
 }
 
+pub fn try_create_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_owner: String,
+    cw20_code_id: u64,
+    token_info: Cw20TokenInfo,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
+
+    let owner = deps.api.addr_validate(&token_owner)?;
+    let Config { admin, .. } = CONFIG.load(deps.storage)?;
+    let Cw20TokenInfo {
+        name,
+        symbol,
+        decimals,
+    } = token_info;
+
+    // token address will be known only in reply
+    CW20_TOKEN_OWNER.save(deps.storage, &owner)?;
+
+    let msg = WasmMsg::Instantiate {
+        admin: Some(admin.to_string()),
+        code_id: cw20_code_id,
+        label: format!("cw20 {symbol}"),
+        msg: to_json_binary(&cw20_base::msg::InstantiateMsg {
+            name,
+            symbol,
+            decimals,
+            initial_balances: vec![],
+            mint: Some(MinterResponse {
+                minter: env.contract.address.to_string(),
+                cap: None,
+            }),
+            marketing: None,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(msg, CREATE_CW20_REPLY_ID))
+        .add_attributes([("action", "try_create_cw20")]))
+}
+
 pub fn try_mint_tokens(
     deps: DepsMut,
     env: Env,
@@ -104,6 +152,21 @@ pub fn try_mint_tokens(
         },
     )?;
 
+    if CW20_TOKENS.has(deps.storage, &Addr::unchecked(&denom)) {
+        let msg = WasmMsg::Execute {
+            contract_addr: denom,
+            msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+                recipient: mint_to_address,
+                amount,
+            })?,
+            funds: vec![],
+        };
+
+        return Ok(Response::new()
+            .add_message(msg)
+            .add_attributes([("action", "try_mint_tokens")]));
+    }
+
     let creator = &env.contract.address;
     let amount = coin(amount.u128(), denom);
 
@@ -175,6 +238,10 @@ pub fn try_set_metadata(
 
     let Metadata { base: denom, .. } = &metadata;
 
+    if CW20_TOKENS.has(deps.storage, &Addr::unchecked(denom)) {
+        Err(ContractError::WrongMinterTokenType)?;
+    }
+
     let owner_and_denoms = TOKENS
         .range(deps.storage, None, None, Order::Ascending)
         .flatten()
@@ -1,5 +1,6 @@
-use cosmwasm_std::{DepsMut, Env, Response, StdError};
+use cosmwasm_std::{DepsMut, Empty, Env, Reply, Response, StdError, StdResult};
 use cw2::{get_contract_version, set_contract_version};
+use cw_utils::{parse_reply_instantiate_data, MsgInstantiateContractResponse};
 
 use semver::Version;
 
@@ -34,3 +35,30 @@ pub fn migrate_contract(
 
     Ok(Response::new())
 }
+
+pub fn handle_create_cw20_reply(
+    deps: DepsMut,
+    _env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let MsgInstantiateContractResponse {
+        contract_address, ..
+    } = parse_reply_instantiate_data(reply).map_err(|e| StdError::generic_err(e.to_string()))?;
+    let token_address = deps.api.addr_validate(&contract_address)?;
+
+    let owner = state::CW20_TOKEN_OWNER.load(deps.storage)?;
+    state::CW20_TOKEN_OWNER.remove(deps.storage);
+
+    state::CW20_TOKENS.save(deps.storage, &token_address, &Empty {})?;
+    state::TOKENS.update(deps.storage, &owner, |x| -> StdResult<Vec<String>> {
+        let mut tokens = x.unwrap_or_default();
+        tokens.push(token_address.to_string());
+
+        Ok(tokens)
+    })?;
+
+    Ok(Response::new().add_attributes([
+        ("action", "handle_create_cw20_reply"),
+        ("token_address", token_address.as_str()),
+    ]))
+}
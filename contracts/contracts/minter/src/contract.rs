@@ -5,16 +5,18 @@ use cosmwasm_std::{
 };
 
 use gopstake_base::{
+    constants::CREATE_CW20_REPLY_ID,
     error::ContractError,
     minter::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
 };
 
 use crate::actions::{
     execute::{
-        try_burn_tokens, try_create_denom, try_mint_tokens, try_set_metadata, try_update_config,
+        try_burn_tokens, try_create_cw20, try_create_denom, try_mint_tokens, try_set_metadata,
+        try_update_config,
     },
     instantiate::try_instantiate,
-    other::migrate_contract,
+    other::{handle_create_cw20_reply, migrate_contract},
     query::{query_config, query_denoms_by_creator},
 };
 
@@ -42,6 +44,11 @@ pub fn execute(
             token_owner,
             subdenom,
         } => try_create_denom(deps, env, info, token_owner, subdenom),
+        ExecuteMsg::CreateCw20 {
+            token_owner,
+            cw20_code_id,
+            token_info,
+        } => try_create_cw20(deps, env, info, token_owner, cw20_code_id, token_info),
         ExecuteMsg::MintTokens {
             denom,
             amount,
@@ -69,10 +76,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
 /// Exposes all the replies available in the contract
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(_deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
-    let Reply { id: _, result: _ } = reply;
-
-    Err(ContractError::UndefinedReplyId)
+pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, ContractError> {
+    match reply.id {
+        CREATE_CW20_REPLY_ID => handle_create_cw20_reply(deps, env, reply),
+        _ => Err(ContractError::UndefinedReplyId),
+    }
 }
 
 /// Used for contract migration
@@ -1,35 +1,65 @@
 use cosmwasm_std::{
-    to_json_binary, Addr, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order, Response,
-    StdResult, Uint128, WasmMsg,
+    to_json_binary, Addr, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order,
+    QuerierWrapper, Response, StdError, StdResult, Storage, SubMsg, Timestamp, Uint128, WasmMsg,
 };
+use cw_storage_plus::{Bound, Map};
 
-use cw721::Cw721ExecuteMsg;
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, NumTokensResponse, OwnerOfResponse, TokensResponse};
 
 use gopstake_base::{
     assets::{Currency, Funds, Token, TokenUnverified},
-    constants::{MINS_PER_DAY, NANOS_PER_MIN},
-    converters::{dec256_to_uint128, dec_to_dec256, u128_to_dec, u128_to_dec256},
+    constants::{PAGINATION_DEFAULT_LIMIT, PAGINATION_MAX_LIMIT},
+    converters::u128_to_dec,
     error::ContractError,
+    math::calc_protocol_fee,
     staking_platform::{
+        events::{
+            ATTR_CAMPAIGN_ID, EMPTY_VALUE, EVENT_BONUS, EVENT_CAMPAIGN, EVENT_CAMPAIGN_CLAIM,
+            EVENT_CAMPAIGN_RECLAIM, EVENT_DEPOSIT, EVENT_STAKE, EVENT_UNSTAKE, EVENT_UPDATE_CONFIG,
+            EVENT_WITHDRAW, PREVIOUS_VALUE_PREFIX,
+        },
+        msg::StakeChangedExecuteMsg,
         state::{
-            COLLECTIONS, COLLECTIONS_BALANCES, CONFIG, FUNDS, IS_LOCKED, PROPOSALS,
-            PROPOSAL_COUNTER, STAKERS,
+            ACTIVE_SETS, BONUS_INDEXES, BONUS_POSITIONS, CAMPAIGNS, CAMPAIGN_CLAIMS,
+            CAMPAIGN_COUNTER, COLLECTIONS, COLLECTIONS_BALANCES, COLLECTION_MIGRATIONS,
+            COLLECTION_SETS, COLLECTION_STAKED_NFTS, COLLECTION_SUPPLIES,
+            COLLECTION_TOTAL_STAKED_NFTS, CONFIG, DISTRIBUTIONS, DISTRIBUTION_COUNTER, FUNDS,
+            HOOKS, HYBRID_MINTED, MIGRATED_TOKENS, PAUSED_ACTIONS, PAUSED_COLLECTIONS,
+            PENDING_REWARDS, PENDING_SET_BONUSES, PROPOSALS, PROPOSAL_APPROVALS, PROPOSAL_COUNTER,
+            PROTOCOL_FEES, RECEIPTS, RECEIPT_COUNTER, RECEIPT_IDS, RESTAKE_COOLDOWNS,
+            REVENUE_SPLIT, REWARDS_CHECKPOINTS, REWARD_CURVES, SETS, SET_COUNTER, SET_STAKERS,
+            STAKERS, TOKEN_ID_MAPPINGS, VESTINGS, VESTING_COUNTER, VESTING_POLICIES,
         },
         types::{
-            Collection, Config, EmissionType, Proposal, ProposalStatus, ProposalType,
-            StakedCollectionInfo, StakedTokenInfo,
+            ActionGroup, ActionPause, Campaign, Collection, CollectionMigration, Config,
+            Distribution, EmissionType, FixedPool, HybridEmission, Proposal, ProposalStatus,
+            ProposalType, Receipt, RestakeCooldown, RevenueSplit, RewardCurve, SetBonus,
+            StakeChangedHookMsg, StakeDirection, StakedCollectionInfo, StakedTokenInfo, StakingSet,
+            Vesting, VestingPolicy,
         },
     },
     utils::{check_funds, get_transfer_msg, unwrap_field, Attrs, AuthType, FundsType},
 };
 
+use crate::helpers::{
+    add_claimed_rewards, add_listing_revenue, add_minted_tokens, add_staked_nfts,
+    calc_token_rewards, decode_hash, deduct_protocol_fee, get_accrual_end_date,
+    get_accrual_start_date, get_funds_event, get_nft_event, get_proposal_event, get_protocol_fee,
+    get_rewards_event, get_set_bonus_list, get_stake_unlock_date, remove_staked_nfts,
+    save_rewards_checkpoint, take_bonus_rewards, verify_merkle_proof,
+};
+
+type RewardsList = Vec<(Funds<Token>, EmissionType, Addr)>;
+type FundsList = Vec<(Funds<Token>, EmissionType)>;
+
 pub fn try_stake(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     collections_to_stake: Vec<StakedCollectionInfo<String>>,
+    mint_receipts: Option<bool>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Staking, None)?;
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::Any)?;
 
@@ -38,6 +68,18 @@ pub fn try_stake(
         .unwrap_or_default();
     let mut msg_list: Vec<CosmosMsg> = vec![];
 
+    let receipt_collection = if mint_receipts.unwrap_or_default() {
+        Some(unwrap_field(
+            CONFIG.load(deps.storage)?.receipt_collection,
+            "receipt_collection",
+        )?)
+    } else {
+        None
+    };
+    let mut receipt_counter = RECEIPT_COUNTER.may_load(deps.storage)?.unwrap_or(1);
+    let mut hook_msg_list: Vec<SubMsg> = vec![];
+    let mut event_list: Vec<Event> = vec![];
+
     for StakedCollectionInfo {
         collection_address,
         staked_token_info_list,
@@ -49,8 +91,83 @@ pub fn try_stake(
             Err(ContractError::CollectionIsNotFound)?;
         }
 
+        check_action_pause(
+            deps.as_ref(),
+            &env,
+            ActionGroup::Staking,
+            Some(&collection_address),
+        )?;
+
+        if PAUSED_COLLECTIONS.has(deps.storage, &collection_address) {
+            Err(ContractError::CollectionIsPaused)?;
+        }
+
+        // old collection can't get new stakers until migration is finished
+        if COLLECTION_MIGRATIONS.has(deps.storage, &collection_address) {
+            Err(ContractError::CollectionMigrationInProgress)?;
+        }
+
+        add_staked_nfts(
+            deps.storage,
+            &env.block,
+            &sender_address,
+            &collection_address,
+            staked_token_info_list.len() as u128,
+        )?;
+
+        // staked NFTs are already counted
+        let Collection {
+            max_staked_per_address,
+            max_total_staked,
+            ..
+        } = COLLECTIONS.load(deps.storage, &collection_address)?;
+
+        if let Some(x) = max_staked_per_address {
+            let staked = COLLECTION_STAKED_NFTS
+                .may_load(deps.storage, (&collection_address, &sender_address))?
+                .unwrap_or_default();
+
+            if staked > x.u128() {
+                Err(ContractError::StakedPerAddressLimitExceeded)?;
+            }
+        }
+
+        if let Some(x) = max_total_staked {
+            let total_staked = COLLECTION_TOTAL_STAKED_NFTS
+                .may_load(deps.storage, &collection_address)?
+                .unwrap_or_default();
+
+            if total_staked > x.u128() {
+                Err(ContractError::TotalStakedLimitExceeded)?;
+            }
+        }
+
+        let token_ids: Vec<Uint128> = staked_token_info_list.iter().map(|x| x.token_id).collect();
+        event_list.push(get_nft_event(
+            EVENT_STAKE,
+            &sender_address,
+            &collection_address,
+            &token_ids,
+        ));
+        hook_msg_list.append(&mut get_hook_msg_list(
+            deps.storage,
+            &sender_address,
+            &collection_address,
+            token_ids,
+            StakeDirection::Stake,
+        )?);
+
         //  update state and create send messages
         for StakedTokenInfo { token_id, .. } in staked_token_info_list {
+            let cooldown_key = (&collection_address, token_id.u128());
+            if let Some(x) = RESTAKE_COOLDOWNS.may_load(deps.storage, cooldown_key)? {
+                if env.block.time < x.expiration_date {
+                    Err(ContractError::RestakeCooldownIsNotPassed)?;
+                }
+
+                RESTAKE_COOLDOWNS.remove(deps.storage, cooldown_key)?;
+            }
+
             let staked_collection = staker
                 .iter()
                 .find(|x| x.collection_address == collection_address);
@@ -84,29 +201,162 @@ pub fn try_stake(
             });
 
             msg_list.push(msg);
+
+            // mint receipt owning the position
+            if let Some(x) = &receipt_collection {
+                RECEIPTS.save(
+                    deps.storage,
+                    receipt_counter,
+                    &Receipt {
+                        collection_address: collection_address.clone(),
+                        token_id,
+                        staker: sender_address.clone(),
+                    },
+                )?;
+                RECEIPT_IDS.save(
+                    deps.storage,
+                    (&collection_address, token_id.u128()),
+                    &receipt_counter,
+                )?;
+
+                let cw721_msg = cw721_base::ExecuteMsg::<Empty, Empty>::Mint {
+                    token_id: receipt_counter.to_string(),
+                    owner: sender_address.to_string(),
+                    token_uri: None,
+                    extension: Empty {},
+                };
+
+                msg_list.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: x.to_string(),
+                    msg: to_json_binary(&cw721_msg)?,
+                    funds: vec![],
+                }));
+
+                receipt_counter += 1;
+            }
         }
     }
 
     STAKERS.save(deps.storage, &sender_address, &staker)?;
+    RECEIPT_COUNTER.save(deps.storage, &receipt_counter)?;
+
+    // staking can only complete sets, so there are no bonuses to settle
+    update_staker_sets(
+        deps.storage,
+        &env,
+        &sender_address,
+        &mut vec![],
+        &mut vec![],
+    )?;
 
     Ok(Response::new()
         .add_messages(msg_list)
+        .add_submessages(hook_msg_list)
+        .add_events(event_list)
         .add_attributes([("action", "try_stake")]))
 }
 
 pub fn try_unstake(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     collections_to_unstake: Vec<StakedCollectionInfo<String>>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Unstaking, None)?;
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::Any)?;
+    let mut set_bonus_list: RewardsList = vec![];
+    let mut protocol_fee_list: FundsList = vec![];
+    let hook_msg_list = sync_receipts(
+        &mut deps,
+        &env,
+        &sender_address,
+        None,
+        None,
+        &mut set_bonus_list,
+        &mut protocol_fee_list,
+    )?;
+
+    unstake_tokens(
+        deps,
+        env,
+        sender_address,
+        collections_to_unstake,
+        hook_msg_list,
+        set_bonus_list,
+        protocol_fee_list,
+    )
+}
+
+pub fn try_unstake_receipt(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Unstaking, None)?;
+    let (receipt_collection, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(
+        deps.as_ref(),
+        &receipt_collection,
+        AuthType::Specified {
+            allowlist: vec![CONFIG.load(deps.storage)?.receipt_collection],
+        },
+    )?;
+
+    let holder = deps.api.addr_validate(&sender)?;
+    let receipt_id: u128 = token_id
+        .parse()
+        .map_err(|_| StdError::parse_err("u128", &token_id))?;
+    let receipt = RECEIPTS.load(deps.storage, receipt_id)?;
+    let mut set_bonus_list: RewardsList = vec![];
+    let mut protocol_fee_list: FundsList = vec![];
+
+    // position is moved to receipt holder before unstaking
+    let hook_msg_list = if receipt.staker != holder {
+        move_receipt_position(
+            deps.storage,
+            &env,
+            receipt_id,
+            &holder,
+            &mut set_bonus_list,
+            &mut protocol_fee_list,
+        )?
+    } else {
+        vec![]
+    };
+
+    unstake_tokens(
+        deps,
+        env,
+        holder,
+        vec![StakedCollectionInfo {
+            collection_address: receipt.collection_address.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: receipt.token_id,
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+        hook_msg_list,
+        set_bonus_list,
+        protocol_fee_list,
+    )
+}
 
+/// Unstakes tokens of sender claiming their rewards, burns receipts of unstaked tokens
+fn unstake_tokens(
+    deps: DepsMut,
+    env: Env,
+    sender_address: Addr,
+    collections_to_unstake: Vec<StakedCollectionInfo<String>>,
+    mut hook_msg_list: Vec<SubMsg>,
+    mut set_bonus_list: RewardsList,
+    mut protocol_fee_list: FundsList,
+) -> Result<Response, ContractError> {
+    let receipt_collection = CONFIG.load(deps.storage)?.receipt_collection;
     let mut msg_list: Vec<CosmosMsg> = vec![];
-    let time_in_nanos: u128 = env.block.time.nanos().into();
-    let mins_per_day = u128_to_dec256(MINS_PER_DAY);
 
     let addresses_of_collections_to_unstake: Vec<String> = collections_to_unstake
         .iter()
@@ -114,7 +364,8 @@ pub fn try_unstake(
         .collect();
     let current_collection_list = STAKERS.load(deps.storage, &sender_address)?;
     let mut new_collection_list: Vec<StakedCollectionInfo<Addr>> = vec![];
-    let mut staking_rewards_and_emission_type_list: Vec<(Funds<Token>, EmissionType)> = vec![];
+    let mut staking_rewards_and_emission_type_list: FundsList = vec![];
+    let mut event_list: Vec<Event> = vec![];
 
     // check if each item of addresses_of_collections_to_unstake is included in list
     // of staked collections
@@ -157,19 +408,39 @@ pub fn try_unstake(
             }
         }
 
+        check_action_pause(
+            deps.as_ref(),
+            &env,
+            ActionGroup::Unstaking,
+            Some(current_collection_address),
+        )?;
+
+        // rewards of NFTs unstaked while claiming is paused are kept until it's resumed
+        let is_claiming_paused = get_action_pause(
+            deps.storage,
+            &env,
+            ActionGroup::Claiming,
+            Some(current_collection_address),
+        )?
+        .is_some();
+
+        let collection = COLLECTIONS.load(deps.storage, current_collection_address)?;
         let Collection {
             staking_currency,
-            daily_rewards,
             emission_type,
+            min_stake_duration,
+            restake_cooldown,
             ..
-        } = COLLECTIONS.load(deps.storage, current_collection_address)?;
+        } = collection.clone();
 
-        let mut collection_balances = match emission_type {
-            EmissionType::Spending => {
-                Some(COLLECTIONS_BALANCES.load(deps.storage, current_collection_address)?)
-            }
-            EmissionType::Minting => None,
-        };
+        let (mut collection_balances, mint_limit) =
+            load_rewards_budget(deps.storage, current_collection_address, &collection)?;
+        let accrual_end_date =
+            get_accrual_end_date(deps.storage, current_collection_address, env.block.time)?;
+        let protocol_fee = get_protocol_fee(deps.storage, current_collection_address)?;
+
+        let staked_amount = current_collection.staked_token_info_list.len();
+        let mut unstaked_token_ids: Vec<Uint128> = vec![];
 
         // prepare new collection we want to fill with tokens not included in tokens_to_unstake
         let mut new_collection: StakedCollectionInfo<Addr> = StakedCollectionInfo {
@@ -177,6 +448,8 @@ pub fn try_unstake(
             ..current_collection.clone()
         };
 
+        let mut collection_rewards = Uint128::zero();
+
         // iterate over tokens of current collection
         for token in current_collection.staked_token_info_list {
             let tokens_to_unstake: Vec<Uint128> = collection_to_unstake
@@ -191,17 +464,33 @@ pub fn try_unstake(
                 continue;
             }
 
-            let last_claim_date_in_nanos: u128 =
-                unwrap_field(token.last_claim_date, "last_claim_date")?
-                    .nanos()
-                    .into();
+            if let Some(unlock_date) = get_stake_unlock_date(&token, min_stake_duration) {
+                if env.block.time < unlock_date {
+                    Err(ContractError::MinStakeDurationIsNotPassed)?;
+                }
+            }
+
+            if let Some(x) = restake_cooldown {
+                RESTAKE_COOLDOWNS.save(
+                    deps.storage,
+                    (current_collection_address, token.token_id.u128()),
+                    &RestakeCooldown {
+                        staker: sender_address.clone(),
+                        expiration_date: env.block.time.plus_seconds(x),
+                    },
+                )?;
+            }
 
-            let time_diff_in_mins = u128_to_dec256(time_in_nanos - last_claim_date_in_nanos)
-                / u128_to_dec256(NANOS_PER_MIN);
+            unstaked_token_ids.push(token.token_id);
 
             // calculate staking rewards
-            let amount =
-                dec256_to_uint128(time_diff_in_mins * dec_to_dec256(daily_rewards) / mins_per_day);
+            let amount = calc_token_rewards(
+                deps.storage,
+                current_collection_address,
+                &collection,
+                get_accrual_start_date(&token, min_stake_duration)?,
+                accrual_end_date,
+            )?;
 
             // update staking rewards considering collection balances
             // it must be executed to prevent blocking unstaking NFT if collection balances
@@ -211,6 +500,21 @@ pub fn try_unstake(
                 _ => amount,
             };
 
+            // protocol fee is taken from staking rewards of spending collections
+            let protocol_fee_amount = calc_protocol_fee(amount, protocol_fee);
+            add_to_funds_list(
+                &mut protocol_fee_list,
+                protocol_fee_amount,
+                &staking_currency,
+                &emission_type,
+            );
+
+            // update collection balances if it's required
+            collection_balances = collection_balances.map(|mut x| {
+                x.amount -= amount;
+                x
+            });
+
             // update staking rewards list
             let is_token_found =
                 staking_rewards_and_emission_type_list
@@ -227,18 +531,7 @@ pub fn try_unstake(
                 ));
             }
 
-            for (funds, emission) in staking_rewards_and_emission_type_list.iter_mut() {
-                if (funds.currency.token == staking_currency.token) && (emission == &emission_type)
-                {
-                    funds.amount += amount;
-                }
-            }
-
-            // update collection balances if it's required
-            collection_balances = collection_balances.map(|mut x| {
-                x.amount -= amount;
-                x
-            });
+            collection_rewards += deduct_protocol_fee(amount, protocol_fee_amount, &emission_type);
 
             // create message to send NFT
             let cw721_msg = Cw721ExecuteMsg::TransferNft {
@@ -253,19 +546,112 @@ pub fn try_unstake(
             });
 
             msg_list.push(msg);
+
+            // burn receipt, it must be approved to the platform by holder
+            let receipt_key = (current_collection_address, token.token_id.u128());
+            if let Some(receipt_id) = RECEIPT_IDS.may_load(deps.storage, receipt_key)? {
+                RECEIPTS.remove(deps.storage, receipt_id);
+                RECEIPT_IDS.remove(deps.storage, receipt_key);
+
+                let cw721_msg = Cw721ExecuteMsg::Burn {
+                    token_id: receipt_id.to_string(),
+                };
+
+                msg_list.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: unwrap_field(receipt_collection.clone(), "receipt_collection")?
+                        .to_string(),
+                    msg: to_json_binary(&cw721_msg)?,
+                    funds: vec![],
+                }));
+            }
+        }
+
+        let collection_rewards = if is_claiming_paused {
+            add_pending_rewards(
+                deps.storage,
+                &PENDING_REWARDS,
+                &sender_address,
+                current_collection_address,
+                &Funds::new(collection_rewards, &staking_currency),
+                &emission_type,
+            )?;
+
+            Uint128::zero()
+        } else {
+            add_claimed_rewards(
+                deps.storage,
+                env.block.time,
+                &sender_address,
+                current_collection_address,
+                &Funds::new(collection_rewards, &staking_currency),
+                &emission_type,
+            )?;
+
+            // rewards of collection with vesting policy are locked in the platform
+            vest_rewards(
+                deps.storage,
+                &env,
+                &sender_address,
+                current_collection_address,
+                Funds::new(collection_rewards, &staking_currency),
+                &emission_type,
+                &mut msg_list,
+            )?
+        };
+
+        for (funds, emission) in staking_rewards_and_emission_type_list.iter_mut() {
+            if (funds.currency.token == staking_currency.token) && (emission == &emission_type) {
+                funds.amount += collection_rewards;
+            }
         }
 
+        remove_staked_nfts(
+            deps.storage,
+            &env.block,
+            &sender_address,
+            current_collection_address,
+            (staked_amount - new_collection.staked_token_info_list.len()) as u128,
+        )?;
+        event_list.push(get_nft_event(
+            EVENT_UNSTAKE,
+            &sender_address,
+            current_collection_address,
+            &unstaked_token_ids,
+        ));
+        hook_msg_list.append(&mut get_hook_msg_list(
+            deps.storage,
+            &sender_address,
+            current_collection_address,
+            unstaked_token_ids,
+            StakeDirection::Unstake,
+        )?);
+
         // if new_collection has tokens add it in new_collection_list
         if !new_collection.staked_token_info_list.is_empty() {
             new_collection_list.push(new_collection);
         }
 
         if let Some(x) = collection_balances {
+            let x = settle_rewards_budget(
+                deps.storage,
+                &env,
+                current_collection_address,
+                x,
+                mint_limit,
+                &mut msg_list,
+            )?;
             COLLECTIONS_BALANCES.save(deps.storage, current_collection_address, &x)?;
         }
     }
 
     STAKERS.save(deps.storage, &sender_address, &new_collection_list)?;
+    update_staker_sets(
+        deps.storage,
+        &env,
+        &sender_address,
+        &mut set_bonus_list,
+        &mut protocol_fee_list,
+    )?;
 
     let minter = unwrap_field(CONFIG.load(deps.storage)?.minter, "minter")?;
 
@@ -275,11 +661,18 @@ pub fn try_unstake(
             continue;
         }
 
+        event_list.push(get_rewards_event(
+            &sender_address,
+            amount,
+            &currency.token,
+            &emission,
+        ));
+
         let msg = match emission {
             EmissionType::Spending => get_transfer_msg(&sender_address, amount, &currency.token)?,
             EmissionType::Minting => {
                 let mint_msg = gopstake_base::minter::msg::ExecuteMsg::MintTokens {
-                    denom: currency.token.try_get_native()?,
+                    denom: currency.token.get_symbol(),
                     amount,
                     mint_to_address: sender_address.to_string(),
                 };
@@ -295,26 +688,46 @@ pub fn try_unstake(
         msg_list.push(msg);
     }
 
+    event_list.append(&mut get_rewards_event_list(&env, &set_bonus_list));
+    msg_list.append(&mut get_rewards_msg_list(deps.storage, set_bonus_list)?);
+    msg_list.append(&mut apply_protocol_fees(
+        deps.storage,
+        &env,
+        protocol_fee_list,
+    )?);
+
     Ok(Response::new()
         .add_messages(msg_list)
+        .add_submessages(hook_msg_list)
+        .add_events(event_list)
         .add_attributes([("action", "try_unstake")]))
 }
 
 pub fn try_claim_staking_rewards(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     collection: Option<String>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Claiming, None)?;
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::Any)?;
-
-    let time_in_nanos: u128 = env.block.time.nanos().into();
-    let mins_per_day = u128_to_dec256(MINS_PER_DAY);
+    let mut set_bonus_list: RewardsList = vec![];
+    let mut protocol_fee_list: FundsList = vec![];
+    let hook_msg_list = sync_receipts(
+        &mut deps,
+        &env,
+        &sender_address,
+        None,
+        None,
+        &mut set_bonus_list,
+        &mut protocol_fee_list,
+    )?;
 
     let mut collection_list = STAKERS.load(deps.storage, &sender_address)?;
-    let mut staking_rewards_and_emission_type_list: Vec<(Funds<Token>, EmissionType)> = vec![];
+    let mut staking_rewards_and_emission_type_list: FundsList = vec![];
+    let mut vesting_msg_list: Vec<CosmosMsg> = vec![];
+    let mut hybrid_msg_list: Vec<CosmosMsg> = vec![];
 
     let collection = collection
         .as_ref()
@@ -323,38 +736,59 @@ pub fn try_claim_staking_rewards(
 
     for collection_info in collection_list.iter_mut() {
         // claim rewards for specified collection or for all collections
+        // collections with paused claiming are skipped if collection isn't specified
         if let Some(x) = &collection {
             if collection_info.collection_address != x {
                 continue;
             }
+
+            check_action_pause(
+                deps.as_ref(),
+                &env,
+                ActionGroup::Claiming,
+                Some(&collection_info.collection_address),
+            )?;
+        } else if get_action_pause(
+            deps.storage,
+            &env,
+            ActionGroup::Claiming,
+            Some(&collection_info.collection_address),
+        )?
+        .is_some()
+        {
+            continue;
         }
 
+        let collection = COLLECTIONS.load(deps.storage, &collection_info.collection_address)?;
         let Collection {
             staking_currency,
-            daily_rewards,
             emission_type,
+            min_stake_duration,
             ..
-        } = COLLECTIONS.load(deps.storage, &collection_info.collection_address)?;
-
-        let mut collection_balances = match emission_type {
-            EmissionType::Spending => {
-                Some(COLLECTIONS_BALANCES.load(deps.storage, &collection_info.collection_address)?)
-            }
-            EmissionType::Minting => None,
-        };
+        } = collection.clone();
+
+        let (mut collection_balances, mint_limit) = load_rewards_budget(
+            deps.storage,
+            &collection_info.collection_address,
+            &collection,
+        )?;
+        let accrual_end_date = get_accrual_end_date(
+            deps.storage,
+            &collection_info.collection_address,
+            env.block.time,
+        )?;
+        let protocol_fee = get_protocol_fee(deps.storage, &collection_info.collection_address)?;
+        let mut collection_rewards = Uint128::zero();
 
         for token in collection_info.staked_token_info_list.iter_mut() {
-            let last_claim_date_in_nanos: u128 =
-                unwrap_field(token.last_claim_date, "last_claim_date")?
-                    .nanos()
-                    .into();
-
-            let time_diff_in_mins = u128_to_dec256(time_in_nanos - last_claim_date_in_nanos)
-                / u128_to_dec256(NANOS_PER_MIN);
-
             // calculate staking rewards
-            let amount =
-                dec256_to_uint128(time_diff_in_mins * dec_to_dec256(daily_rewards) / mins_per_day);
+            let amount = calc_token_rewards(
+                deps.storage,
+                &collection_info.collection_address,
+                &collection,
+                get_accrual_start_date(token, min_stake_duration)?,
+                accrual_end_date,
+            )?;
 
             let is_token_found =
                 staking_rewards_and_emission_type_list
@@ -391,192 +825,941 @@ pub fn try_claim_staking_rewards(
                 continue;
             }
 
-            for (funds, emission) in staking_rewards_and_emission_type_list.iter_mut() {
-                if (funds.currency.token == staking_currency.token) && (emission == &emission_type)
-                {
-                    funds.amount += amount;
-                }
-            }
+            // protocol fee is taken from staking rewards of spending collections
+            let protocol_fee_amount = calc_protocol_fee(amount, protocol_fee);
+            add_to_funds_list(
+                &mut protocol_fee_list,
+                protocol_fee_amount,
+                &staking_currency,
+                &emission_type,
+            );
+
+            collection_rewards += deduct_protocol_fee(amount, protocol_fee_amount, &emission_type);
 
             // reset last claim date
             token.last_claim_date = Some(env.block.time);
         }
 
+        add_claimed_rewards(
+            deps.storage,
+            env.block.time,
+            &sender_address,
+            &collection_info.collection_address,
+            &Funds::new(collection_rewards, &staking_currency),
+            &emission_type,
+        )?;
+
+        // rewards of collection with vesting policy are locked in the platform
+        let collection_rewards = vest_rewards(
+            deps.storage,
+            &env,
+            &sender_address,
+            &collection_info.collection_address,
+            Funds::new(collection_rewards, &staking_currency),
+            &emission_type,
+            &mut vesting_msg_list,
+        )?;
+
+        for (funds, emission) in staking_rewards_and_emission_type_list.iter_mut() {
+            if (funds.currency.token == staking_currency.token) && (emission == &emission_type) {
+                funds.amount += collection_rewards;
+            }
+        }
+
         if let Some(x) = collection_balances {
+            let x = settle_rewards_budget(
+                deps.storage,
+                &env,
+                &collection_info.collection_address,
+                x,
+                mint_limit,
+                &mut hybrid_msg_list,
+            )?;
             COLLECTIONS_BALANCES.save(deps.storage, &collection_info.collection_address, &x)?;
         }
     }
 
+    // rewards of NFTs unstaked while claiming was paused
+    let pending_rewards_list = PENDING_REWARDS
+        .prefix(&sender_address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, (Funds<Token>, EmissionType))>>>()?;
+
+    for (collection_address, (funds, emission_type)) in pending_rewards_list {
+        if collection.as_ref().is_some_and(|x| x != collection_address)
+            || get_action_pause(
+                deps.storage,
+                &env,
+                ActionGroup::Claiming,
+                Some(&collection_address),
+            )?
+            .is_some()
+        {
+            continue;
+        }
+
+        PENDING_REWARDS.remove(deps.storage, (&sender_address, &collection_address));
+        add_claimed_rewards(
+            deps.storage,
+            env.block.time,
+            &sender_address,
+            &collection_address,
+            &funds,
+            &emission_type,
+        )?;
+
+        let amount = vest_rewards(
+            deps.storage,
+            &env,
+            &sender_address,
+            &collection_address,
+            funds.clone(),
+            &emission_type,
+            &mut vesting_msg_list,
+        )?;
+        add_to_funds_list(
+            &mut staking_rewards_and_emission_type_list,
+            amount,
+            &funds.currency,
+            &emission_type,
+        );
+    }
+
+    // bonuses of sets broken while claiming was paused aren't vested
+    let pending_set_bonus_list = PENDING_SET_BONUSES
+        .prefix(&sender_address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, (Funds<Token>, EmissionType))>>>()?;
+
+    for (collection_address, (funds, emission_type)) in pending_set_bonus_list {
+        if collection.as_ref().is_some_and(|x| x != collection_address)
+            || get_action_pause(
+                deps.storage,
+                &env,
+                ActionGroup::Claiming,
+                Some(&collection_address),
+            )?
+            .is_some()
+        {
+            continue;
+        }
+
+        PENDING_SET_BONUSES.remove(deps.storage, (&sender_address, &collection_address));
+        add_claimed_rewards(
+            deps.storage,
+            env.block.time,
+            &sender_address,
+            &collection_address,
+            &funds,
+            &emission_type,
+        )?;
+
+        set_bonus_list.push((funds, emission_type, sender_address.clone()));
+    }
+
     STAKERS.save(deps.storage, &sender_address, &collection_list)?;
+    update_staker_sets(
+        deps.storage,
+        &env,
+        &sender_address,
+        &mut set_bonus_list,
+        &mut protocol_fee_list,
+    )?;
+    claim_staker_sets(
+        deps.storage,
+        &env,
+        &sender_address,
+        collection.as_ref(),
+        &mut set_bonus_list,
+        &mut protocol_fee_list,
+    )?;
 
     let minter = unwrap_field(CONFIG.load(deps.storage)?.minter, "minter")?;
 
-    // create send messages
-    let msg_list = staking_rewards_and_emission_type_list
-        .into_iter()
+    let mut event_list: Vec<Event> = staking_rewards_and_emission_type_list
+        .iter()
         .filter(|(x, _)| !x.amount.is_zero())
-        .map(
-            |(Funds { amount, currency }, emission)| -> StdResult<CosmosMsg> {
-                match emission {
-                    EmissionType::Spending => {
-                        get_transfer_msg(&sender_address, amount, &currency.token)
-                    }
-                    EmissionType::Minting => {
-                        let mint_msg = gopstake_base::minter::msg::ExecuteMsg::MintTokens {
-                            denom: currency.token.try_get_native()?,
-                            amount,
-                            mint_to_address: sender_address.to_string(),
-                        };
+        .map(|(funds, emission)| {
+            get_rewards_event(
+                &sender_address,
+                funds.amount,
+                &funds.currency.token,
+                emission,
+            )
+        })
+        .collect();
 
-                        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
-                            contract_addr: minter.to_string(),
-                            msg: to_json_binary(&mint_msg)?,
-                            funds: vec![],
-                        }))
+    // create send messages, hybrid emission rewards must be minted to the platform first
+    let mut msg_list = hybrid_msg_list;
+    msg_list.append(
+        &mut staking_rewards_and_emission_type_list
+            .into_iter()
+            .filter(|(x, _)| !x.amount.is_zero())
+            .map(
+                |(Funds { amount, currency }, emission)| -> StdResult<CosmosMsg> {
+                    match emission {
+                        EmissionType::Spending => {
+                            get_transfer_msg(&sender_address, amount, &currency.token)
+                        }
+                        EmissionType::Minting => {
+                            let mint_msg = gopstake_base::minter::msg::ExecuteMsg::MintTokens {
+                                denom: currency.token.get_symbol(),
+                                amount,
+                                mint_to_address: sender_address.to_string(),
+                            };
+
+                            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                                contract_addr: minter.to_string(),
+                                msg: to_json_binary(&mint_msg)?,
+                                funds: vec![],
+                            }))
+                        }
                     }
-                }
-            },
-        )
-        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+                },
+            )
+            .collect::<StdResult<Vec<CosmosMsg>>>()?,
+    );
+
+    // bonuses of unstaked or migrated collections are paid as well
+    let bonus_collection_list = BONUS_POSITIONS
+        .prefix(&sender_address)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    for collection_address in bonus_collection_list {
+        if collection.as_ref().is_some_and(|x| x != collection_address)
+            || get_action_pause(
+                deps.storage,
+                &env,
+                ActionGroup::Claiming,
+                Some(&collection_address),
+            )?
+            .is_some()
+        {
+            continue;
+        }
+
+        for (token, amount) in
+            take_bonus_rewards(deps.storage, &sender_address, &collection_address)?
+        {
+            event_list.push(get_rewards_event(
+                &sender_address,
+                amount,
+                &token,
+                &EmissionType::Spending,
+            ));
+            msg_list.push(get_transfer_msg(&sender_address, amount, &token)?);
+        }
+    }
+
+    msg_list.append(&mut vesting_msg_list);
+    event_list.append(&mut get_rewards_event_list(&env, &set_bonus_list));
+    msg_list.append(&mut get_rewards_msg_list(deps.storage, set_bonus_list)?);
+    msg_list.append(&mut apply_protocol_fees(
+        deps.storage,
+        &env,
+        protocol_fee_list,
+    )?);
 
     Ok(Response::new()
         .add_messages(msg_list)
+        .add_submessages(hook_msg_list)
+        .add_events(event_list)
         .add_attributes([("action", "try_claim_staking_rewards")]))
 }
 
+pub fn try_sync_receipts(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Claiming, None)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::Any)?;
+    let mut set_bonus_list: RewardsList = vec![];
+    let mut protocol_fee_list: FundsList = vec![];
+    let hook_msg_list = sync_receipts(
+        &mut deps,
+        &env,
+        &sender_address,
+        start_after,
+        limit,
+        &mut set_bonus_list,
+        &mut protocol_fee_list,
+    )?;
+
+    let event_list = get_rewards_event_list(&env, &set_bonus_list);
+    let mut msg_list = get_rewards_msg_list(deps.storage, set_bonus_list)?;
+    msg_list.append(&mut apply_protocol_fees(
+        deps.storage,
+        &env,
+        protocol_fee_list,
+    )?);
+
+    Ok(Response::new()
+        .add_messages(msg_list)
+        .add_submessages(hook_msg_list)
+        .add_events(event_list)
+        .add_attributes([("action", "try_sync_receipts")]))
+}
+
+pub fn try_withdraw_vested_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Claiming, None)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::Any)?;
+
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start_bound = start_after.map(|x| Bound::exclusive(x.u128()));
+
+    let vestings = VESTINGS
+        .prefix(&sender_address)
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<(u128, Vesting)>>>()?;
+    let mut rewards_list: Vec<Funds<Token>> = vec![];
+
+    for (id, mut vesting) in vestings {
+        let amount = vesting.get_withdrawable_amount(env.block.time);
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        match rewards_list
+            .iter_mut()
+            .find(|x| x.currency.token == vesting.funds.currency.token)
+        {
+            Some(x) => x.amount += amount,
+            None => rewards_list.push(Funds::new(amount, &vesting.funds.currency)),
+        };
+
+        vesting.withdrawn_amount += amount;
+
+        // completed vestings are removed
+        if vesting.withdrawn_amount == vesting.funds.amount {
+            VESTINGS.remove(deps.storage, (&sender_address, id));
+        } else {
+            VESTINGS.save(deps.storage, (&sender_address, id), &vesting)?;
+        }
+    }
+
+    if rewards_list.is_empty() {
+        Err(ContractError::VestedRewardsAreNotFound)?;
+    }
+
+    let msg_list = rewards_list
+        .iter()
+        .map(|x| get_transfer_msg(&sender_address, x.amount, &x.currency.token))
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+
+    Ok(Response::new()
+        .add_messages(msg_list)
+        .add_attributes([("action", "try_withdraw_vested_rewards")]))
+}
+
 pub fn try_update_config(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     owner: Option<String>,
     minter: Option<String>,
+    protocol_fee: Option<Decimal>,
+    receipt_collection: Option<String>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::Admin)?;
 
+    if protocol_fee.is_some_and(|x| x > Decimal::one()) {
+        Err(ContractError::ProtocolFeeIsOutOfRange)?;
+    }
+
+    // replacing receipt collection would make existing receipts useless
+    if receipt_collection.is_some() && CONFIG.load(deps.storage)?.receipt_collection.is_some() {
+        Err(ContractError::ReceiptCollectionIsAlreadySet)?;
+    }
+
     let mut attrs = Attrs::init("try_update_config");
+    let mut diff_attrs: Vec<(String, String)> = vec![];
 
     CONFIG.update(deps.storage, |mut config| -> StdResult<Config> {
         if let Some(x) = owner {
-            config.owner = Some(deps.api.addr_validate(&x)?);
+            let previous = config.owner.replace(deps.api.addr_validate(&x)?);
+            diff_attrs.extend(get_config_diff("owner", previous, &x));
             attrs.push(("owner".to_string(), x));
         }
 
         if let Some(x) = minter {
-            config.minter = Some(deps.api.addr_validate(&x)?);
+            let previous = config.minter.replace(deps.api.addr_validate(&x)?);
+            diff_attrs.extend(get_config_diff("minter", previous, &x));
             attrs.push(("minter".to_string(), x));
         }
 
+        if let Some(x) = protocol_fee {
+            let previous = std::mem::replace(&mut config.protocol_fee, x);
+            diff_attrs.extend(get_config_diff(
+                "protocol_fee",
+                Some(previous),
+                &x.to_string(),
+            ));
+            attrs.push(("protocol_fee".to_string(), x.to_string()));
+        }
+
+        if let Some(x) = receipt_collection {
+            let previous = config
+                .receipt_collection
+                .replace(deps.api.addr_validate(&x)?);
+            diff_attrs.extend(get_config_diff("receipt_collection", previous, &x));
+            attrs.push(("receipt_collection".to_string(), x));
+        }
+
         Ok(config)
     })?;
 
-    Ok(Response::new().add_attributes(attrs))
+    Ok(Response::new()
+        .add_event(Event::new(EVENT_UPDATE_CONFIG).add_attributes(diff_attrs))
+        .add_attributes(attrs))
 }
 
-pub fn try_lock(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+pub fn try_add_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::Admin)?;
+
+    let address = deps.api.addr_validate(&address)?;
+    let mut hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+
+    if hooks.contains(&address) {
+        Err(ContractError::HookIsAlreadyAdded)?;
+    }
+
+    hooks.push(address);
+    HOOKS.save(deps.storage, &hooks)?;
+
+    Ok(Response::new().add_attributes([("action", "try_add_hook")]))
+}
+
+pub fn try_remove_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::Admin)?;
+
+    let address = deps.api.addr_validate(&address)?;
+    let mut hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+
+    if !hooks.contains(&address) {
+        Err(ContractError::HookIsNotFound)?;
+    }
+
+    hooks.retain(|x| x != address);
+    HOOKS.save(deps.storage, &hooks)?;
+
+    Ok(Response::new().add_attributes([("action", "try_remove_hook")]))
+}
+
+pub fn try_create_set(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    set: StakingSet<String>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::Admin)?;
+
+    let set = set.verify(&deps.as_ref())?;
+
+    // set must include at least 2 different collections
+    let mut collection_list = set.collection_list.clone();
+    collection_list.sort_unstable();
+    collection_list.dedup();
+
+    if (collection_list.len() != set.collection_list.len()) || (collection_list.len() < 2) {
+        Err(ContractError::WrongSetParameters)?;
+    }
+
+    for collection_address in &set.collection_list {
+        if !COLLECTIONS.has(deps.storage, collection_address) {
+            Err(ContractError::CollectionIsNotFound)?;
+        }
+    }
+
+    let is_bonus_valid = match &set.bonus {
+        SetBonus::Multiplier { value } => value > &Decimal::one(),
+        SetBonus::Flat {
+            collection_address,
+            daily_rewards,
+        } => set.collection_list.contains(collection_address) && !daily_rewards.is_zero(),
+    };
+
+    if !is_bonus_valid {
+        Err(ContractError::WrongSetParameters)?;
+    }
+
+    let set_counter = SET_COUNTER.may_load(deps.storage)?.unwrap_or(1);
+    SETS.save(deps.storage, set_counter, &set)?;
+    SET_COUNTER.save(deps.storage, &(set_counter + 1))?;
+
+    for collection_address in &set.collection_list {
+        COLLECTION_SETS.save(deps.storage, (collection_address, set_counter), &Empty {})?;
+    }
+
+    // stakers already completed the set start bonus accrual, it's enough to check
+    // stakers of the collection having least staked NFTs
+    let mut least_staked_collection = (&set.collection_list[0], u128::MAX);
+
+    for collection_address in &set.collection_list {
+        let staked = COLLECTION_TOTAL_STAKED_NFTS
+            .may_load(deps.storage, collection_address)?
+            .unwrap_or_default();
+
+        if staked < least_staked_collection.1 {
+            least_staked_collection = (collection_address, staked);
+        }
+    }
+
+    for staker_address in get_collection_stakers(deps.storage, least_staked_collection.0)? {
+        update_staker_sets(
+            deps.storage,
+            &env,
+            &staker_address,
+            &mut vec![],
+            &mut vec![],
+        )?;
+    }
+
+    Ok(Response::new().add_attributes([
+        ("action", "try_create_set"),
+        ("set_id", &set_counter.to_string()),
+    ]))
+}
+
+pub fn try_remove_set(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: Uint128,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::Admin)?;
+
+    let set = SETS
+        .load(deps.storage, id.u128())
+        .map_err(|_| ContractError::SetIsNotFound)?;
+
+    // accrued bonuses are settled, so claiming of set collections must be active
+    for collection_address in &set.collection_list {
+        check_action_pause(
+            deps.as_ref(),
+            &env,
+            ActionGroup::Claiming,
+            Some(collection_address),
+        )?;
+    }
+
+    let stakers = SET_STAKERS
+        .prefix(id.u128())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let mut set_bonus_list: RewardsList = vec![];
+    let mut protocol_fee_list: FundsList = vec![];
+
+    for staker_address in stakers {
+        let accrual_start_date = ACTIVE_SETS.load(deps.storage, (&staker_address, id.u128()))?;
+        settle_set_bonus(
+            deps.storage,
+            &env,
+            &staker_address,
+            &set,
+            accrual_start_date,
+            true,
+            &mut set_bonus_list,
+            &mut protocol_fee_list,
+        )?;
+        deactivate_set(deps.storage, &staker_address, id.u128());
+    }
+
+    for collection_address in &set.collection_list {
+        COLLECTION_SETS.remove(deps.storage, (collection_address, id.u128()));
+    }
+
+    SETS.remove(deps.storage, id.u128());
+
+    let event_list = get_rewards_event_list(&env, &set_bonus_list);
+    let mut msg_list = get_rewards_msg_list(deps.storage, set_bonus_list)?;
+    msg_list.append(&mut apply_protocol_fees(
+        deps.storage,
+        &env,
+        protocol_fee_list,
+    )?);
+
+    Ok(Response::new()
+        .add_messages(msg_list)
+        .add_events(event_list)
+        .add_attributes([("action", "try_remove_set")]))
+}
+
+pub fn try_pause_actions(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action_groups: Vec<ActionGroup>,
+    collection_address: Option<String>,
+    reason: String,
+    expiration_date: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
 
-    IS_LOCKED.update(deps.storage, |_| -> StdResult<bool> { Ok(true) })?;
+    let collection_address = collection_address
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?;
+
+    if expiration_date.is_some_and(|x| x <= env.block.time) {
+        Err(ContractError::ImproperExpirationDate)?;
+    }
+
+    for action_group in action_groups {
+        let key = get_action_pause_key(&action_group, collection_address.as_ref());
+
+        PAUSED_ACTIONS.save(
+            deps.storage,
+            key,
+            &ActionPause {
+                action_group,
+                collection_address: collection_address.clone(),
+                reason: reason.clone(),
+                expiration_date,
+            },
+        )?;
+    }
 
-    Ok(Response::new().add_attributes([("action", "try_lock")]))
+    Ok(Response::new().add_attributes([("action", "try_pause_actions")]))
 }
 
-pub fn try_unlock(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn try_resume_actions(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    action_groups: Vec<ActionGroup>,
+    collection_address: Option<String>,
+) -> Result<Response, ContractError> {
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
 
-    IS_LOCKED.update(deps.storage, |_| -> StdResult<bool> { Ok(false) })?;
+    let collection_address = collection_address
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?;
+
+    for action_group in action_groups {
+        let key = get_action_pause_key(&action_group, collection_address.as_ref());
+        PAUSED_ACTIONS.remove(deps.storage, key);
+    }
 
-    Ok(Response::new().add_attributes([("action", "try_unlock")]))
+    Ok(Response::new().add_attributes([("action", "try_resume_actions")]))
 }
 
-pub fn try_distribute_funds(
+pub fn try_update_collection_protocol_fee(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    address_and_weight_list: Vec<(String, Decimal)>,
+    collection_address: String,
+    protocol_fee: Option<Decimal>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
 
-    // verify weights
-    // check if all weights are in range [0, 1]
-    if address_and_weight_list
-        .iter()
-        .any(|(_, weight)| weight > &Decimal::one())
-    {
-        Err(ContractError::WeightIsOutOfRange)?;
+    let collection_address = &deps.api.addr_validate(&collection_address)?;
+    if !COLLECTIONS.has(deps.storage, collection_address) {
+        Err(ContractError::CollectionIsNotFound)?;
     }
 
-    // check if sum of weights is equal one
-    let weights_sum = address_and_weight_list
-        .iter()
-        .fold(Decimal::zero(), |acc, (_, weight)| acc + weight);
+    match protocol_fee {
+        Some(x) => {
+            if x > Decimal::one() {
+                Err(ContractError::ProtocolFeeIsOutOfRange)?;
+            }
 
-    if weights_sum != Decimal::one() {
-        Err(ContractError::WeightsAreUnbalanced)?;
+            PROTOCOL_FEES.save(deps.storage, collection_address, &x)?;
+        }
+        None => PROTOCOL_FEES.remove(deps.storage, collection_address),
+    };
+
+    Ok(Response::new().add_attributes([("action", "try_update_collection_protocol_fee")]))
+}
+
+pub fn try_update_collection_vesting(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    collection_address: String,
+    vesting_policy: Option<VestingPolicy>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
+
+    let collection_address = &deps.api.addr_validate(&collection_address)?;
+    if !COLLECTIONS.has(deps.storage, collection_address) {
+        Err(ContractError::CollectionIsNotFound)?;
+    }
+
+    match vesting_policy {
+        Some(x) => {
+            if x.cliff > x.duration {
+                Err(ContractError::WrongVestingPolicy)?;
+            }
+
+            VESTING_POLICIES.save(deps.storage, collection_address, &x)?;
+        }
+        None => VESTING_POLICIES.remove(deps.storage, collection_address),
+    };
+
+    Ok(Response::new().add_attributes([("action", "try_update_collection_vesting")]))
+}
+
+pub fn try_refresh_collection_supply(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_address: String,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let collection_address = &deps.api.addr_validate(&collection_address)?;
+    let collection = COLLECTIONS
+        .load(deps.storage, collection_address)
+        .map_err(|_| ContractError::CollectionIsNotFound)?;
+
+    check_authorization(
+        deps.as_ref(),
+        &sender_address,
+        AuthType::AdminOrOwnerOrSpecified {
+            allowlist: vec![Some(collection.owner)],
+        },
+    )?;
+
+    if collection.reward_curve.is_none() {
+        Err(ContractError::WrongRewardCurve)?;
+    }
+
+    // supply is updated before checkpoint to use it for accrual since block time
+    save_collection_supply(deps.storage, &deps.querier, collection_address)?;
+    save_rewards_checkpoint(deps.storage, collection_address, env.block.time)?;
+
+    Ok(Response::new().add_attributes([("action", "try_refresh_collection_supply")]))
+}
+
+pub fn try_update_revenue_split(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address_and_weight_list: Vec<(String, Decimal)>,
+    dust_recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
+
+    let revenue_split = RevenueSplit {
+        address_and_weight_list,
+        dust_recipient,
     }
+    .verify(&deps.as_ref())?;
+    check_weights(&revenue_split.address_and_weight_list)?;
+
+    REVENUE_SPLIT.save(deps.storage, &revenue_split)?;
+
+    Ok(Response::new().add_attributes([("action", "try_update_revenue_split")]))
+}
+
+pub fn try_distribute_funds(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address_and_weight_list: Option<Vec<(String, Decimal)>>,
+    token_and_amount_list: Option<Vec<(TokenUnverified, Option<Uint128>)>>,
+) -> Result<Response, ContractError> {
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Treasury, None)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
+
+    // use stored revenue split if weights aren't specified
+    let revenue_split = REVENUE_SPLIT.may_load(deps.storage)?;
+    let RevenueSplit {
+        address_and_weight_list,
+        dust_recipient,
+    } = match address_and_weight_list {
+        Some(address_and_weight_list) => RevenueSplit {
+            address_and_weight_list,
+            dust_recipient: None,
+        }
+        .verify(&deps.as_ref())
+        .map(|x| RevenueSplit {
+            dust_recipient: revenue_split.and_then(|y| y.dust_recipient),
+            ..x
+        })?,
+        None => revenue_split.ok_or(ContractError::RevenueSplitIsNotFound)?,
+    };
+    check_weights(&address_and_weight_list)?;
 
-    // create send messages and update state
+    // distribute all funds if tokens aren't specified
     let mut funds_list = FUNDS.load(deps.storage)?;
-    let mut msg_list: Vec<CosmosMsg> = vec![];
+    let token_and_amount_list = match token_and_amount_list {
+        Some(x) => x
+            .into_iter()
+            .map(|(token, amount)| -> StdResult<(Token, Option<Uint128>)> {
+                Ok((token.verify(&deps.as_ref())?, amount))
+            })
+            .collect::<StdResult<Vec<(Token, Option<Uint128>)>>>()?,
+        None => funds_list
+            .iter()
+            .map(|x| (x.currency.token.to_owned(), None))
+            .collect(),
+    };
 
-    let address_and_weight_list = address_and_weight_list
-        .into_iter()
-        .map(|(address, weight)| -> StdResult<(Addr, Decimal)> {
-            let recipient = deps.api.addr_validate(&address)?;
-            Ok((recipient, weight))
-        })
-        .collect::<StdResult<Vec<(Addr, Decimal)>>>()?;
+    // split funds and update state
+    let mut recipient_and_funds_list: Vec<(Addr, Funds<Token>)> = vec![];
+
+    for (token, amount) in token_and_amount_list {
+        let funds_list_item = funds_list
+            .iter_mut()
+            .find(|x| x.currency.token == token)
+            .ok_or(ContractError::AssetIsNotFound)?;
+        let amount = amount.unwrap_or(funds_list_item.amount);
+
+        if amount > funds_list_item.amount {
+            Err(ContractError::InsufficientFunds)?;
+        }
 
-    for funds_list_item in funds_list.iter_mut() {
         let mut amount_to_send = Uint128::zero();
 
         for (recipient, weight) in &address_and_weight_list {
-            let amount = (u128_to_dec(funds_list_item.amount.to_owned()) * weight).to_uint_floor();
-            amount_to_send += amount;
+            let recipient_amount = (u128_to_dec(amount) * weight).to_uint_floor();
+            amount_to_send += recipient_amount;
 
-            let msg = get_transfer_msg(recipient, amount, &funds_list_item.currency.token)?;
-            msg_list.push(msg);
+            recipient_and_funds_list.push((
+                recipient.to_owned(),
+                Funds::new(recipient_amount, &funds_list_item.currency),
+            ));
+        }
+
+        // dust is left in funds if its recipient isn't specified
+        if let Some(recipient) = &dust_recipient {
+            recipient_and_funds_list.push((
+                recipient.to_owned(),
+                Funds::new(amount - amount_to_send, &funds_list_item.currency),
+            ));
+            amount_to_send = amount;
         }
 
         funds_list_item.amount -= amount_to_send;
     }
 
+    recipient_and_funds_list.retain(|(_, funds)| !funds.amount.is_zero());
+
+    let msg_list = recipient_and_funds_list
+        .iter()
+        .map(|(recipient, funds)| get_transfer_msg(recipient, funds.amount, &funds.currency.token))
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+
     FUNDS.save(deps.storage, &funds_list)?;
 
-    Ok(Response::new()
-        .add_messages(msg_list)
-        .add_attributes([("action", "try_distribute_funds")]))
+    // add distribution to history
+    let distribution_counter = DISTRIBUTION_COUNTER.may_load(deps.storage)?.unwrap_or(1);
+    DISTRIBUTION_COUNTER.save(deps.storage, &(distribution_counter + 1))?;
+    DISTRIBUTIONS.save(
+        deps.storage,
+        distribution_counter,
+        &Distribution {
+            distribution_date: env.block.time,
+            sender: sender_address,
+            recipient_and_funds_list,
+        },
+    )?;
+
+    Ok(Response::new().add_messages(msg_list).add_attributes([
+        ("action", "try_distribute_funds"),
+        ("distribution_id", &distribution_counter.to_string()),
+    ]))
 }
 
 pub fn try_remove_collection(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     address: String,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
 
-    // update state
     let collection_address = &deps.api.addr_validate(&address)?;
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Proposals,
+        Some(collection_address),
+    )?;
+
+    // stakers of migrating collections and sets would be blocked by removed collection
+    check_collection_migration(deps.as_ref(), collection_address)?;
+
+    if !COLLECTION_SETS
+        .prefix(collection_address)
+        .is_empty(deps.storage)
+    {
+        Err(ContractError::CollectionIsInSet)?;
+    }
+
+    // update state, bonus indexes are kept as distributed bonuses are still claimable
     COLLECTIONS.remove(deps.storage, collection_address);
+    PAUSED_COLLECTIONS.remove(deps.storage, collection_address);
+    PROTOCOL_FEES.remove(deps.storage, collection_address);
+    COLLECTION_SUPPLIES.remove(deps.storage, collection_address);
+    VESTING_POLICIES.remove(deps.storage, collection_address);
+    HYBRID_MINTED.remove(deps.storage, collection_address);
+
+    let checkpoint_list = REWARDS_CHECKPOINTS
+        .prefix(collection_address)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    for date in checkpoint_list {
+        REWARDS_CHECKPOINTS.remove(deps.storage, (collection_address, date));
+    }
+
+    let version_list = REWARD_CURVES
+        .prefix(collection_address)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    for version in version_list {
+        REWARD_CURVES.remove(deps.storage, (collection_address, version));
+    }
 
     Ok(Response::new().add_attributes([("action", "try_remove_collection")]))
 }
 
 pub fn try_create_proposal(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     proposal: Proposal<String, TokenUnverified>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Proposals, None)?;
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
 
@@ -597,6 +1780,13 @@ pub fn try_create_proposal(
                     daily_rewards,
                     emission_type,
                     owner,
+                    max_staked_per_address,
+                    max_total_staked,
+                    min_stake_duration,
+                    restake_cooldown,
+                    fixed_pool,
+                    hybrid_emission,
+                    reward_curve,
                 },
         } => {
             let collection_address = deps.api.addr_validate(&collection_address)?;
@@ -606,27 +1796,15 @@ pub fn try_create_proposal(
             );
             let owner = deps.api.addr_validate(&owner)?;
 
-            if !emission_type.is_spending() {
-                if !staking_currency.token.is_native() {
-                    Err(ContractError::WrongMinterTokenType)?;
-                }
-
-                // check if minter token was created by collection owner
-                let Config { minter, .. } = CONFIG.load(deps.storage)?;
-                let minter = unwrap_field(minter, "minter")?;
-
-                let gopstake_base::minter::types::QueryDenomsFromCreatorResponse { denoms } =
-                    deps.querier.query_wasm_smart(
-                        minter,
-                        &gopstake_base::minter::msg::QueryMsg::DenomsByCreator {
-                            creator: owner.to_string(),
-                        },
-                    )?;
-
-                if !denoms.contains(&staking_currency.token.try_get_native()?) {
-                    Err(ContractError::UnownedStakingCurrency)?;
-                }
-            }
+            verify_collection_emission(
+                deps.as_ref(),
+                &owner,
+                &staking_currency,
+                &emission_type,
+                &fixed_pool,
+                &hybrid_emission,
+                &reward_curve,
+            )?;
 
             let is_collection_found = COLLECTIONS
                 .range(deps.storage, None, None, Order::Ascending)
@@ -647,12 +1825,20 @@ pub fn try_create_proposal(
                     daily_rewards,
                     emission_type,
                     owner,
+                    max_staked_per_address,
+                    max_total_staked,
+                    min_stake_duration,
+                    restake_cooldown,
+                    fixed_pool,
+                    hybrid_emission,
+                    reward_curve,
                 },
             }
         }
         ProposalType::UpdateCollection {
             collection_address,
             new_collection_address,
+            token_id_mapping,
             new_collection:
                 Collection {
                     name,
@@ -660,6 +1846,13 @@ pub fn try_create_proposal(
                     daily_rewards,
                     emission_type,
                     owner,
+                    max_staked_per_address,
+                    max_total_staked,
+                    min_stake_duration,
+                    restake_cooldown,
+                    fixed_pool,
+                    hybrid_emission,
+                    reward_curve,
                 },
         } => {
             let collection_address = deps.api.addr_validate(&collection_address)?;
@@ -673,27 +1866,15 @@ pub fn try_create_proposal(
             );
             let owner = deps.api.addr_validate(&owner)?;
 
-            if !emission_type.is_spending() {
-                if !staking_currency.token.is_native() {
-                    Err(ContractError::WrongMinterTokenType)?;
-                }
-
-                // check if minter token was created by collection owner
-                let Config { minter, .. } = CONFIG.load(deps.storage)?;
-                let minter = unwrap_field(minter, "minter")?;
-
-                let gopstake_base::minter::types::QueryDenomsFromCreatorResponse { denoms } =
-                    deps.querier.query_wasm_smart(
-                        minter,
-                        &gopstake_base::minter::msg::QueryMsg::DenomsByCreator {
-                            creator: owner.to_string(),
-                        },
-                    )?;
-
-                if !denoms.contains(&staking_currency.token.try_get_native()?) {
-                    Err(ContractError::UnownedStakingCurrency)?;
-                }
-            }
+            verify_collection_emission(
+                deps.as_ref(),
+                &owner,
+                &staking_currency,
+                &emission_type,
+                &fixed_pool,
+                &hybrid_emission,
+                &reward_curve,
+            )?;
 
             let is_collection_found = COLLECTIONS
                 .range(deps.storage, None, None, Order::Ascending)
@@ -710,50 +1891,154 @@ pub fn try_create_proposal(
             ProposalType::UpdateCollection {
                 collection_address,
                 new_collection_address,
+                token_id_mapping,
                 new_collection: Collection {
                     name,
                     staking_currency,
                     daily_rewards,
                     emission_type,
                     owner,
+                    max_staked_per_address,
+                    max_total_staked,
+                    min_stake_duration,
+                    restake_cooldown,
+                    fixed_pool,
+                    hybrid_emission,
+                    reward_curve,
                 },
             }
         }
-    };
+        ProposalType::TransferCollectionOwnership {
+            collection_address,
+            owner,
+            new_owner,
+        } => {
+            let collection_address = deps.api.addr_validate(&collection_address)?;
+            let owner = deps.api.addr_validate(&owner)?;
+            let new_owner = deps.api.addr_validate(&new_owner)?;
+
+            verify_collection_owner(
+                deps.as_ref(),
+                &sender_address,
+                AuthType::AdminOrOwner,
+                &collection_address,
+                &owner,
+            )?;
 
-    let token = price.currency.token.verify(&deps.as_ref())?;
-    let proposal: Proposal<Addr, Token> = Proposal {
-        proposal_status: Some(ProposalStatus::Active),
-        proposal_type,
-        price: Funds::new(
-            price.amount,
-            &Currency::new(&token, price.currency.decimals),
-        ),
-    };
+            if new_owner == owner {
+                Err(ContractError::WrongCollectionOwner)?;
+            }
+
+            ProposalType::TransferCollectionOwnership {
+                collection_address,
+                owner,
+                new_owner,
+            }
+        }
+        ProposalType::PauseCollection {
+            collection_address,
+            owner,
+        } => {
+            let collection_address = deps.api.addr_validate(&collection_address)?;
+            let owner = deps.api.addr_validate(&owner)?;
+
+            verify_collection_owner(
+                deps.as_ref(),
+                &sender_address,
+                AuthType::AdminOrOwner,
+                &collection_address,
+                &owner,
+            )?;
+
+            if PAUSED_COLLECTIONS.has(deps.storage, &collection_address) {
+                Err(ContractError::CollectionIsPaused)?;
+            }
+
+            ProposalType::PauseCollection {
+                collection_address,
+                owner,
+            }
+        }
+        ProposalType::ResumeCollection {
+            collection_address,
+            owner,
+        } => {
+            let collection_address = deps.api.addr_validate(&collection_address)?;
+            let owner = deps.api.addr_validate(&owner)?;
+
+            verify_collection_owner(
+                deps.as_ref(),
+                &sender_address,
+                AuthType::AdminOrOwner,
+                &collection_address,
+                &owner,
+            )?;
+
+            if !PAUSED_COLLECTIONS.has(deps.storage, &collection_address) {
+                Err(ContractError::CollectionIsNotPaused)?;
+            }
+
+            ProposalType::ResumeCollection {
+                collection_address,
+                owner,
+            }
+        }
+    };
+
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Proposals,
+        Some(proposal_type.get_collection_address()),
+    )?;
+
+    let token = price.currency.token.verify(&deps.as_ref())?;
+    let proposal: Proposal<Addr, Token> = Proposal {
+        proposal_status: Some(ProposalStatus::Active),
+        proposal_type,
+        price: Funds::new(
+            price.amount,
+            &Currency::new(&token, price.currency.decimals),
+        ),
+    };
 
     // update list of proposals
     let proposal_counter = PROPOSAL_COUNTER.load(deps.storage)?;
     PROPOSALS.save(deps.storage, proposal_counter, &proposal)?;
     PROPOSAL_COUNTER.save(deps.storage, &(proposal_counter + 1))?;
 
-    Ok(Response::new().add_attributes([
-        ("action", "try_create_proposal"),
-        ("proposal_id", &proposal_counter.to_string()),
-    ]))
+    Ok(Response::new()
+        .add_event(get_proposal_event(
+            proposal_counter,
+            &proposal,
+            None,
+            &ProposalStatus::Active,
+        ))
+        .add_attributes([
+            ("action", "try_create_proposal"),
+            ("proposal_id", &proposal_counter.to_string()),
+        ]))
 }
 
 pub fn try_reject_proposal(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     id: Uint128,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
 
+    let proposal = PROPOSALS.load(deps.storage, id.u128())?;
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Proposals,
+        Some(proposal.proposal_type.get_collection_address()),
+    )?;
+
     // update proposal status
-    PROPOSALS.update(
+    let proposal = PROPOSALS.update(
         deps.storage,
         id.u128(),
         |proposal| -> StdResult<Proposal<Addr, Token>> {
@@ -769,7 +2054,32 @@ pub fn try_reject_proposal(
         },
     )?;
 
-    Ok(Response::new().add_attributes([("action", "try_reject_proposal")]))
+    // refund listing price of ownership transfer approved by new owner
+    let mut msg_list: Vec<CosmosMsg> = vec![];
+    let approvals = PROPOSAL_APPROVALS
+        .may_load(deps.storage, id.u128())?
+        .unwrap_or_default();
+    PROPOSAL_APPROVALS.remove(deps.storage, id.u128());
+
+    if let ProposalType::TransferCollectionOwnership { new_owner, .. } = &proposal.proposal_type {
+        if approvals.contains(new_owner) && !proposal.price.amount.is_zero() {
+            msg_list.push(get_transfer_msg(
+                new_owner,
+                proposal.price.amount,
+                &proposal.price.currency.token,
+            )?);
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(msg_list)
+        .add_event(get_proposal_event(
+            id.u128(),
+            &proposal,
+            Some(&ProposalStatus::Active),
+            &ProposalStatus::Rejected,
+        ))
+        .add_attributes([("action", "try_reject_proposal")]))
 }
 
 pub fn try_accept_proposal(
@@ -780,27 +2090,49 @@ pub fn try_accept_proposal(
     sender: Option<String>,
     amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
-    let (sender_address, asset_amount, asset_info) =
-        check_funds(deps.as_ref(), &info, FundsType::Single { sender, amount })?;
+    let (sender_address, asset_amount, asset_info) = if sender.is_none() && info.funds.is_empty() {
+        check_funds(deps.as_ref(), &info, FundsType::Empty)?
+    } else {
+        check_funds(deps.as_ref(), &info, FundsType::Single { sender, amount })?
+    };
 
     let id = id.u128();
     let proposal = PROPOSALS.load(deps.storage, id)?;
 
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Proposals,
+        Some(proposal.proposal_type.get_collection_address()),
+    )?;
+
     // verify funds
-    if (asset_amount != proposal.price.amount) || (asset_info != proposal.price.currency.token) {
+    // listing price isn't required if it's zero or if ownership transfer is approved
+    // by current collection owner
+    let is_payment_required = !proposal.price.amount.is_zero()
+        && !matches!(
+            &proposal.proposal_type,
+            ProposalType::TransferCollectionOwnership { owner, .. } if owner == sender_address
+        );
+
+    if (is_payment_required
+        && ((asset_amount != proposal.price.amount)
+            || (asset_info != proposal.price.currency.token)))
+        || (!is_payment_required && !asset_amount.is_zero())
+    {
         Err(ContractError::WrongFundsCombination)?;
     }
 
     // verify proposal status
     verify_proposal_status(&proposal, ProposalStatus::Active)?;
 
-    let time_in_nanos: u128 = env.block.time.nanos().into();
-    let mins_per_day = u128_to_dec256(MINS_PER_DAY);
+    let mut rewards_emission_staker_list: RewardsList = vec![];
+    let mut protocol_fee_list: FundsList = vec![];
+    let mut hook_msg_list: Vec<SubMsg> = vec![];
+    let mut hybrid_msg_list: Vec<CosmosMsg> = vec![];
+    let mut proposal_status = ProposalStatus::Accepted;
 
-    let mut rewards_emission_staker_list: Vec<(Funds<Token>, EmissionType, Addr)> = vec![];
-
-    let (collection_address, new_collection_address, collection) = match &proposal.proposal_type {
+    match &proposal.proposal_type {
         ProposalType::AddCollection {
             collection_address,
             collection,
@@ -817,19 +2149,35 @@ pub fn try_accept_proposal(
                 Err(ContractError::CollectionDuplication)?;
             }
 
+            // verify sender
+            check_authorization(
+                deps.as_ref(),
+                &sender_address,
+                AuthType::Specified {
+                    allowlist: vec![Some(collection.owner.clone())],
+                },
+            )?;
+
             COLLECTIONS_BALANCES.save(
                 deps.storage,
                 collection_address,
                 &Funds::new(0u128, &collection.staking_currency),
             )?;
 
-            (collection_address, None, collection)
+            COLLECTIONS.save(deps.storage, collection_address, collection)?;
+
+            if collection.reward_curve.is_some() {
+                save_collection_supply(deps.storage, &deps.querier, collection_address)?;
+            }
         }
         ProposalType::UpdateCollection {
             collection_address,
             new_collection_address,
+            token_id_mapping,
             new_collection,
         } => {
+            check_collection_migration(deps.as_ref(), collection_address)?;
+
             let collections = &COLLECTIONS
                 .range(deps.storage, None, None, Order::Ascending)
                 .flatten()
@@ -837,7 +2185,7 @@ pub fn try_accept_proposal(
 
             let is_collection_found = collections
                 .iter()
-                .any(|(current_address, _)| (current_address == collection_address));
+                .any(|(current_address, _)| current_address == collection_address);
 
             if !is_collection_found {
                 Err(ContractError::CollectionIsNotFound)?;
@@ -856,19 +2204,48 @@ pub fn try_accept_proposal(
             }
 
             // claim staking rewards for each collection staker before changing
-            // daily_rewards or staking_currency
+            // daily_rewards, fixed_pool, reward_curve or staking_currency
+            // in case of changing collection address rewards are claimed during migration
             let current_collection = COLLECTIONS.load(deps.storage, collection_address)?;
-
-            if (new_collection.daily_rewards != current_collection.daily_rewards)
-                || (new_collection.staking_currency != current_collection.staking_currency)
+            let accrual_end_date =
+                get_accrual_end_date(deps.storage, collection_address, env.block.time)?;
+
+            if new_collection_address.is_none()
+                && ((new_collection.daily_rewards != current_collection.daily_rewards)
+                    || (new_collection.fixed_pool != current_collection.fixed_pool)
+                    || (new_collection.reward_curve != current_collection.reward_curve)
+                    || (new_collection.staking_currency != current_collection.staking_currency))
             {
-                let mut collection_balances =
-                    COLLECTIONS_BALANCES.load(deps.storage, collection_address)?;
+                // positions of receipts are moved to their holders before claiming
+                for staker_address in get_collection_stakers(deps.storage, collection_address)? {
+                    let (mut msg_list, _) = sync_collection_receipts(
+                        deps.storage,
+                        &deps.querier,
+                        &env,
+                        &staker_address,
+                        collection_address,
+                        &mut rewards_emission_staker_list,
+                        &mut protocol_fee_list,
+                    )?;
+                    hook_msg_list.append(&mut msg_list);
+                }
+
+                // budget is loaded after the receipt sync as set bonuses are paid from it
+                let (collection_balances, mint_limit) =
+                    load_rewards_budget(deps.storage, collection_address, &current_collection)?;
+                let mut collection_balances = match collection_balances {
+                    Some(x) => x,
+                    None => COLLECTIONS_BALANCES.load(deps.storage, collection_address)?,
+                };
+                let protocol_fee = get_protocol_fee(deps.storage, collection_address)?;
 
-                let mut stakers: Vec<(Addr, Vec<StakedCollectionInfo<Addr>>)> = STAKERS
-                    .range(deps.storage, None, None, Order::Ascending)
-                    .flatten()
-                    .collect();
+                let mut stakers = get_collection_stakers(deps.storage, collection_address)?
+                    .into_iter()
+                    .map(|x| -> StdResult<(Addr, Vec<StakedCollectionInfo<Addr>>)> {
+                        let collection_list = STAKERS.load(deps.storage, &x)?;
+                        Ok((x, collection_list))
+                    })
+                    .collect::<StdResult<Vec<(Addr, Vec<StakedCollectionInfo<Addr>>)>>>()?;
 
                 for (staker_address, collection_list) in stakers.iter_mut() {
                     for staked_collection in collection_list {
@@ -879,33 +2256,62 @@ pub fn try_accept_proposal(
                         let mut staker_rewards = Uint128::zero();
 
                         for token in staked_collection.staked_token_info_list.iter_mut() {
-                            let last_claim_date_in_nanos: u128 =
-                                unwrap_field(token.last_claim_date, "last_claim_date")?
-                                    .nanos()
-                                    .into();
-
-                            let time_diff_in_mins =
-                                u128_to_dec256(time_in_nanos - last_claim_date_in_nanos)
-                                    / u128_to_dec256(NANOS_PER_MIN);
-
                             // calculate staking rewards
-                            let amount = dec256_to_uint128(
-                                time_diff_in_mins * dec_to_dec256(current_collection.daily_rewards)
-                                    / mins_per_day,
-                            );
+                            let amount = calc_token_rewards(
+                                deps.storage,
+                                collection_address,
+                                &current_collection,
+                                get_accrual_start_date(
+                                    token,
+                                    current_collection.min_stake_duration,
+                                )?,
+                                accrual_end_date,
+                            )?;
+
+                            // rewards are limited by collection budget to not block the update
+                            let amount = match current_collection.emission_type {
+                                EmissionType::Spending => {
+                                    let amount = amount.min(collection_balances.amount);
+                                    collection_balances.amount -= amount;
+                                    amount
+                                }
+                                EmissionType::Minting => amount,
+                            };
 
                             staker_rewards += amount;
 
-                            if current_collection.emission_type == EmissionType::Spending {
-                                collection_balances.amount -= amount;
-                            }
-
                             token.last_claim_date = Some(env.block.time);
                         }
 
                         if !staker_rewards.is_zero() {
+                            let protocol_fee_amount =
+                                calc_protocol_fee(staker_rewards, protocol_fee);
+                            add_to_funds_list(
+                                &mut protocol_fee_list,
+                                protocol_fee_amount,
+                                &current_collection.staking_currency,
+                                &current_collection.emission_type,
+                            );
+
+                            let funds = Funds::new(
+                                deduct_protocol_fee(
+                                    staker_rewards,
+                                    protocol_fee_amount,
+                                    &current_collection.emission_type,
+                                ),
+                                &current_collection.staking_currency,
+                            );
+                            add_claimed_rewards(
+                                deps.storage,
+                                env.block.time,
+                                staker_address,
+                                collection_address,
+                                &funds,
+                                &current_collection.emission_type,
+                            )?;
+
                             rewards_emission_staker_list.push((
-                                Funds::new(staker_rewards, &current_collection.staking_currency),
+                                funds,
                                 current_collection.emission_type.clone(),
                                 staker_address.clone(),
                             ));
@@ -917,6 +2323,17 @@ pub fn try_accept_proposal(
                     STAKERS.save(deps.storage, &staker_address, &collection_list)?;
                 }
 
+                if current_collection.emission_type == EmissionType::Spending {
+                    collection_balances = settle_rewards_budget(
+                        deps.storage,
+                        &env,
+                        collection_address,
+                        collection_balances,
+                        mint_limit,
+                        &mut hybrid_msg_list,
+                    )?;
+                }
+
                 // All funds from collection balances must be removed before
                 // changing staking currency
                 // Send unused funds to collection owner
@@ -943,110 +2360,285 @@ pub fn try_accept_proposal(
                 )?;
             }
 
-            (
-                collection_address,
-                new_collection_address.as_ref(),
-                new_collection,
-            )
+            // verify sender
+            check_authorization(
+                deps.as_ref(),
+                &sender_address,
+                AuthType::Specified {
+                    allowlist: vec![Some(new_collection.owner.clone())],
+                },
+            )?;
+
+            // update collection
+            match new_collection_address {
+                Some(x) => {
+                    // old collection is kept until all stakers will be migrated
+                    COLLECTIONS.save(deps.storage, x, new_collection)?;
+
+                    if new_collection.reward_curve.is_some() {
+                        save_collection_supply(deps.storage, &deps.querier, x)?;
+                    }
+
+                    COLLECTIONS_BALANCES.save(
+                        deps.storage,
+                        x,
+                        &Funds::new(0u128, &new_collection.staking_currency),
+                    )?;
+
+                    if let Some(pause_date) =
+                        PAUSED_COLLECTIONS.may_load(deps.storage, collection_address)?
+                    {
+                        PAUSED_COLLECTIONS.save(deps.storage, x, &pause_date)?;
+                    }
+
+                    COLLECTION_MIGRATIONS.save(
+                        deps.storage,
+                        collection_address,
+                        &CollectionMigration {
+                            new_collection_address: x.to_owned(),
+                            last_staker: None,
+                        },
+                    )?;
+
+                    for (old_token_id, new_token_id) in
+                        token_id_mapping.to_owned().unwrap_or_default()
+                    {
+                        TOKEN_ID_MAPPINGS.save(
+                            deps.storage,
+                            (collection_address, old_token_id.u128()),
+                            &new_token_id,
+                        )?;
+                    }
+                }
+                _ => {
+                    COLLECTIONS.save(deps.storage, collection_address, new_collection)?;
+
+                    if new_collection.reward_curve.is_some()
+                        && !COLLECTION_SUPPLIES.has(deps.storage, collection_address)
+                    {
+                        save_collection_supply(deps.storage, &deps.querier, collection_address)?;
+                    }
+
+                    save_rewards_checkpoint(deps.storage, collection_address, env.block.time)?;
+                }
+            };
         }
-    };
+        ProposalType::TransferCollectionOwnership {
+            collection_address,
+            owner,
+            new_owner,
+        } => {
+            check_collection_migration(deps.as_ref(), collection_address)?;
+
+            // verify sender
+            check_authorization(
+                deps.as_ref(),
+                &sender_address,
+                AuthType::Specified {
+                    allowlist: vec![Some(owner.to_owned()), Some(new_owner.to_owned())],
+                },
+            )?;
 
-    // verify sender
-    check_authorization(
-        deps.as_ref(),
-        &sender_address,
-        AuthType::Specified {
-            allowlist: vec![Some(collection.owner.clone())],
-        },
-    )?;
+            let mut collection = COLLECTIONS
+                .load(deps.storage, collection_address)
+                .map_err(|_| ContractError::CollectionIsNotFound)?;
 
-    // add/update collection
-    match new_collection_address {
-        Some(x) => {
-            COLLECTIONS.remove(deps.storage, collection_address);
-            COLLECTIONS.save(deps.storage, x, collection)?;
+            if collection.owner != owner {
+                Err(ContractError::WrongCollectionOwner)?;
+            }
+
+            // ownership is transferred only after approvals of both owners
+            let mut approvals = PROPOSAL_APPROVALS
+                .may_load(deps.storage, id)?
+                .unwrap_or_default();
+
+            if approvals.contains(&sender_address) {
+                Err(ContractError::ProposalIsAlreadyApproved)?;
+            }
+
+            approvals.push(sender_address.clone());
+
+            if approvals.contains(owner) && approvals.contains(new_owner) {
+                collection.owner = new_owner.to_owned();
+                COLLECTIONS.save(deps.storage, collection_address, &collection)?;
+                PROPOSAL_APPROVALS.remove(deps.storage, id);
+            } else {
+                proposal_status = ProposalStatus::Active;
+                PROPOSAL_APPROVALS.save(deps.storage, id, &approvals)?;
+            }
         }
-        _ => COLLECTIONS.save(deps.storage, collection_address, collection)?,
-    };
+        ProposalType::PauseCollection {
+            collection_address,
+            owner,
+        } => {
+            check_collection_migration(deps.as_ref(), collection_address)?;
+            verify_collection_owner(
+                deps.as_ref(),
+                &sender_address,
+                AuthType::Specified {
+                    allowlist: vec![Some(owner.to_owned())],
+                },
+                collection_address,
+                owner,
+            )?;
 
-    // update funds
-    let mut funds_list = FUNDS.load(deps.storage)?;
+            if PAUSED_COLLECTIONS.has(deps.storage, collection_address) {
+                Err(ContractError::CollectionIsPaused)?;
+            }
 
-    let funds = funds_list
-        .iter()
-        .find(|Funds { currency, .. }| currency.token == asset_info);
+            PAUSED_COLLECTIONS.save(deps.storage, collection_address, &env.block.time)?;
+            save_rewards_checkpoint(deps.storage, collection_address, env.block.time)?;
+        }
+        ProposalType::ResumeCollection {
+            collection_address,
+            owner,
+        } => {
+            check_collection_migration(deps.as_ref(), collection_address)?;
+            verify_collection_owner(
+                deps.as_ref(),
+                &sender_address,
+                AuthType::Specified {
+                    allowlist: vec![Some(owner.to_owned())],
+                },
+                collection_address,
+                owner,
+            )?;
 
-    if funds.is_none() {
-        funds_list.push(Funds::new(Uint128::zero(), &proposal.price.currency));
-    }
+            let pause_date = PAUSED_COLLECTIONS
+                .load(deps.storage, collection_address)
+                .map_err(|_| ContractError::CollectionIsNotPaused)?;
+
+            // shift last claim dates to exclude pause period from rewards accrual
+            // fixed pool and reward curve indexes aren't increased during pause so
+            // the dates are kept
+            let collection = COLLECTIONS.load(deps.storage, collection_address)?;
+            let is_index_based =
+                collection.fixed_pool.is_some() || collection.reward_curve.is_some();
+            let stakers: Vec<Addr> = if is_index_based {
+                vec![]
+            } else {
+                get_collection_stakers(deps.storage, collection_address)?
+            };
+
+            for staker_address in stakers {
+                let mut collection_list = STAKERS.load(deps.storage, &staker_address)?;
+                let mut is_updated = false;
+
+                for staked_collection in collection_list.iter_mut() {
+                    if collection_address != staked_collection.collection_address {
+                        continue;
+                    }
+
+                    for token in staked_collection.staked_token_info_list.iter_mut() {
+                        let last_claim_date =
+                            unwrap_field(token.last_claim_date, "last_claim_date")?;
+
+                        token.last_claim_date = Some(if last_claim_date < pause_date {
+                            last_claim_date.plus_nanos(env.block.time.nanos() - pause_date.nanos())
+                        } else {
+                            env.block.time
+                        });
+                    }
+
+                    is_updated = true;
+                }
+
+                if is_updated {
+                    STAKERS.save(deps.storage, &staker_address, &collection_list)?;
+                }
+            }
 
-    for funds_list_item in funds_list.iter_mut() {
-        if funds_list_item.currency.token == asset_info {
-            funds_list_item.amount += asset_amount;
+            PAUSED_COLLECTIONS.remove(deps.storage, collection_address);
+            save_rewards_checkpoint(deps.storage, collection_address, env.block.time)?;
         }
+    };
+
+    // update funds
+    // listing price of ownership transfer is kept in escrow until the transfer is completed
+    let listing_amount = match &proposal.proposal_type {
+        ProposalType::TransferCollectionOwnership { .. } => match proposal_status {
+            ProposalStatus::Active => Uint128::zero(),
+            _ => proposal.price.amount,
+        },
+        _ => asset_amount,
+    };
+
+    if !listing_amount.is_zero() {
+        let funds = Funds::new(listing_amount, &proposal.price.currency);
+        add_to_funds(deps.storage, &funds)?;
+        add_listing_revenue(
+            deps.storage,
+            proposal.proposal_type.get_collection_address(),
+            &funds,
+        )?;
     }
 
-    FUNDS.save(deps.storage, &funds_list)?;
+    let mut event_list = vec![get_proposal_event(
+        id,
+        &proposal,
+        Some(&ProposalStatus::Active),
+        &proposal_status,
+    )];
+    event_list.append(&mut get_rewards_event_list(
+        &env,
+        &rewards_emission_staker_list,
+    ));
 
     // update proposal status
     PROPOSALS.save(
         deps.storage,
         id,
         &Proposal {
-            proposal_status: Some(ProposalStatus::Accepted),
+            proposal_status: Some(proposal_status),
             ..proposal
         },
     )?;
 
-    // create messages to send rewards
-    let minter = unwrap_field(CONFIG.load(deps.storage)?.minter, "minter")?;
-
-    let msg_list = rewards_emission_staker_list
-        .into_iter()
-        .map(
-            |(Funds { amount, currency }, emission, staker_address)| -> StdResult<CosmosMsg> {
-                match emission {
-                    EmissionType::Spending => {
-                        get_transfer_msg(&staker_address, amount, &currency.token)
-                    }
-                    EmissionType::Minting => {
-                        let mint_msg = gopstake_base::minter::msg::ExecuteMsg::MintTokens {
-                            denom: currency.token.try_get_native()?,
-                            amount,
-                            mint_to_address: staker_address.to_string(),
-                        };
-
-                        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
-                            contract_addr: minter.to_string(),
-                            msg: to_json_binary(&mint_msg)?,
-                            funds: vec![],
-                        }))
-                    }
-                }
-            },
-        )
-        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+    // create messages to send rewards, hybrid emission rewards must be minted first
+    let mut msg_list = hybrid_msg_list;
+    msg_list.append(&mut get_rewards_msg_list(
+        deps.storage,
+        rewards_emission_staker_list,
+    )?);
+    msg_list.append(&mut apply_protocol_fees(
+        deps.storage,
+        &env,
+        protocol_fee_list,
+    )?);
 
     Ok(Response::new()
         .add_messages(msg_list)
+        .add_submessages(hook_msg_list)
+        .add_events(event_list)
         .add_attributes([("action", "try_accept_proposal")]))
 }
 
 pub fn try_deposit_tokens(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     collection_address: String,
     sender: Option<String>,
     amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
     let (sender_address, asset_amount, asset_info) =
         check_funds(deps.as_ref(), &info, FundsType::Single { sender, amount })?;
 
     let collection_address = &deps.api.addr_validate(&collection_address)?;
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Deposits,
+        Some(collection_address),
+    )?;
+
     let collection = COLLECTIONS.load(deps.storage, collection_address)?;
 
+    // old collection balances are used to claim rewards during migration
+    if COLLECTION_MIGRATIONS.has(deps.storage, collection_address) {
+        Err(ContractError::CollectionMigrationInProgress)?;
+    }
+
     // verify funds
     if asset_info != collection.staking_currency.token {
         Err(ContractError::AssetIsNotFound)?;
@@ -1079,20 +2671,36 @@ pub fn try_deposit_tokens(
         },
     )?;
 
-    Ok(Response::new().add_attributes([("action", "try_deposit_tokens")]))
+    Ok(Response::new()
+        .add_event(get_funds_event(
+            EVENT_DEPOSIT,
+            &sender_address,
+            collection_address,
+            asset_amount,
+            &asset_info,
+        ))
+        .add_attributes([("action", "try_deposit_tokens")]))
 }
 
-pub fn try_withdraw_tokens(
+pub fn try_distribute_bonus(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    collection_address: String,
-    amount: Uint128,
+    collection: String,
+    sender: Option<String>,
+    amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
-    check_lockout(deps.as_ref())?;
-    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let (sender_address, asset_amount, asset_info) =
+        check_funds(deps.as_ref(), &info, FundsType::Single { sender, amount })?;
+
+    let collection_address = &deps.api.addr_validate(&collection)?;
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Deposits,
+        Some(collection_address),
+    )?;
 
-    let collection_address = &deps.api.addr_validate(&collection_address)?;
     let collection = COLLECTIONS.load(deps.storage, collection_address)?;
 
     // verify sender
@@ -1104,32 +2712,1663 @@ pub fn try_withdraw_tokens(
         },
     )?;
 
-    // verify emmision type
-    if collection.emission_type != EmissionType::Spending {
-        Err(ContractError::ActionByEmissionType)?;
+    let total_staked = COLLECTION_TOTAL_STAKED_NFTS
+        .may_load(deps.storage, collection_address)?
+        .unwrap_or_default();
+
+    if total_staked == 0 {
+        Err(ContractError::StakersAreNotFound)?;
     }
 
-    COLLECTIONS_BALANCES.update(
-        deps.storage,
-        collection_address,
-        |x| -> StdResult<Funds<Token>> {
-            match x {
-                Some(funds) => Ok(Funds::new(funds.amount - amount, &funds.currency)),
-                _ => Err(ContractError::AssetIsNotFound)?,
-            }
-        },
-    )?;
+    // bonus per staked NFT is added to collection index, dust is left on the contract
+    let mut index_list = BONUS_INDEXES
+        .may_load(deps.storage, collection_address)?
+        .unwrap_or_default();
+    let index_increment = Decimal::from_ratio(asset_amount, total_staked);
 
-    let msg = get_transfer_msg(&sender_address, amount, &collection.staking_currency.token)?;
+    match index_list.iter_mut().find(|(x, _)| x == &asset_info) {
+        Some((_, x)) => *x += index_increment,
+        None => index_list.push((asset_info.clone(), index_increment)),
+    };
+
+    BONUS_INDEXES.save(deps.storage, collection_address, &index_list)?;
 
     Ok(Response::new()
-        .add_message(msg)
-        .add_attributes([("action", "try_withdraw_tokens")]))
+        .add_event(get_funds_event(
+            EVENT_BONUS,
+            &sender_address,
+            collection_address,
+            asset_amount,
+            &asset_info,
+        ))
+        .add_attributes([("action", "try_distribute_bonus")]))
 }
 
-fn check_lockout(deps: Deps) -> StdResult<()> {
-    if IS_LOCKED.load(deps.storage)? {
-        Err(ContractError::ContractIsLocked)?;
+#[allow(clippy::too_many_arguments)]
+pub fn try_create_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_address: String,
+    merkle_root: String,
+    expiration_date: Timestamp,
+    sender: Option<String>,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let (sender_address, asset_amount, asset_info) =
+        check_funds(deps.as_ref(), &info, FundsType::Single { sender, amount })?;
+
+    let collection_address = &deps.api.addr_validate(&collection_address)?;
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Deposits,
+        Some(collection_address),
+    )?;
+
+    let collection = COLLECTIONS.load(deps.storage, collection_address)?;
+
+    // verify sender
+    check_authorization(
+        deps.as_ref(),
+        &sender_address,
+        AuthType::AdminOrSpecified {
+            allowlist: vec![Some(collection.owner)],
+        },
+    )?;
+
+    // verify funds
+    if asset_info != collection.staking_currency.token {
+        Err(ContractError::AssetIsNotFound)?;
+    }
+
+    if expiration_date <= env.block.time {
+        Err(ContractError::ImproperExpirationDate)?;
+    }
+
+    decode_hash(&merkle_root).map_err(|_| ContractError::WrongMerkleRoot)?;
+
+    let campaign_id = CAMPAIGN_COUNTER.may_load(deps.storage)?.unwrap_or(1);
+    CAMPAIGNS.save(
+        deps.storage,
+        campaign_id,
+        &Campaign {
+            creator: sender_address.clone(),
+            collection_address: collection_address.to_owned(),
+            merkle_root,
+            funds: Funds::new(asset_amount, &collection.staking_currency),
+            claimed_amount: Uint128::zero(),
+            expiration_date,
+        },
+    )?;
+    CAMPAIGN_COUNTER.save(deps.storage, &(campaign_id + 1))?;
+
+    Ok(Response::new()
+        .add_event(
+            get_funds_event(
+                EVENT_CAMPAIGN,
+                &sender_address,
+                collection_address,
+                asset_amount,
+                &asset_info,
+            )
+            .add_attribute(ATTR_CAMPAIGN_ID, campaign_id.to_string()),
+        )
+        .add_attributes([("action", "try_create_campaign")]))
+}
+
+pub fn try_claim_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: Uint128,
+    amount: Uint128,
+    proof: Vec<String>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let campaign_id = id.u128();
+    let mut campaign = CAMPAIGNS
+        .load(deps.storage, campaign_id)
+        .map_err(|_| ContractError::CampaignIsNotFound)?;
+
+    // campaign funds aren't collection balances, so only global claiming pause is
+    // applied to not block claims while campaign expiration date is approaching
+    check_action_pause(deps.as_ref(), &env, ActionGroup::Claiming, None)?;
+
+    if env.block.time >= campaign.expiration_date {
+        Err(ContractError::CampaignIsExpired)?;
+    }
+
+    // each leaf can be claimed only once
+    if CAMPAIGN_CLAIMS.has(deps.storage, (campaign_id, &sender_address)) {
+        Err(ContractError::CampaignRewardsAreClaimed)?;
+    }
+
+    if amount.is_zero() {
+        Err(ContractError::CampaignClaimAmountIsZero)?;
+    }
+
+    // leaf is bound to the platform and the campaign, delimiters prevent the same leaf
+    // for different address and amount pairs
+    let leaf = format!(
+        "{}:{}:{}:{}",
+        env.contract.address, campaign_id, sender_address, amount
+    );
+    if !verify_merkle_proof(&campaign.merkle_root, &leaf, &proof).unwrap_or_default() {
+        Err(ContractError::WrongMerkleProof)?;
+    }
+
+    campaign.claimed_amount += amount;
+    if campaign.claimed_amount > campaign.funds.amount {
+        Err(ContractError::InsufficientFunds)?;
+    }
+
+    CAMPAIGNS.save(deps.storage, campaign_id, &campaign)?;
+    CAMPAIGN_CLAIMS.save(deps.storage, (campaign_id, &sender_address), &amount)?;
+
+    let token = &campaign.funds.currency.token;
+
+    Ok(Response::new()
+        .add_message(get_transfer_msg(&sender_address, amount, token)?)
+        .add_event(
+            get_funds_event(
+                EVENT_CAMPAIGN_CLAIM,
+                &sender_address,
+                &campaign.collection_address,
+                amount,
+                token,
+            )
+            .add_attribute(ATTR_CAMPAIGN_ID, campaign_id.to_string()),
+        )
+        .add_attributes([("action", "try_claim_campaign")]))
+}
+
+pub fn try_reclaim_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: Uint128,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let campaign_id = id.u128();
+    let campaign = CAMPAIGNS
+        .load(deps.storage, campaign_id)
+        .map_err(|_| ContractError::CampaignIsNotFound)?;
+
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Deposits,
+        Some(&campaign.collection_address),
+    )?;
+
+    // verify sender
+    check_authorization(
+        deps.as_ref(),
+        &sender_address,
+        AuthType::Specified {
+            allowlist: vec![Some(campaign.creator.clone())],
+        },
+    )?;
+
+    if env.block.time < campaign.expiration_date {
+        Err(ContractError::CampaignIsNotExpired)?;
+    }
+
+    // claims are kept as history, campaign ids aren't reused
+    CAMPAIGNS.remove(deps.storage, campaign_id);
+
+    let amount = campaign.funds.amount - campaign.claimed_amount;
+    let token = &campaign.funds.currency.token;
+    let mut response = Response::new();
+
+    if !amount.is_zero() {
+        response = response.add_message(get_transfer_msg(&campaign.creator, amount, token)?);
+    }
+
+    Ok(response
+        .add_event(
+            get_funds_event(
+                EVENT_CAMPAIGN_RECLAIM,
+                &sender_address,
+                &campaign.collection_address,
+                amount,
+                token,
+            )
+            .add_attribute(ATTR_CAMPAIGN_ID, campaign_id.to_string()),
+        )
+        .add_attributes([("action", "try_reclaim_campaign")]))
+}
+
+pub fn try_withdraw_tokens(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_address: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+
+    let collection_address = &deps.api.addr_validate(&collection_address)?;
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Deposits,
+        Some(collection_address),
+    )?;
+    let collection = COLLECTIONS.load(deps.storage, collection_address)?;
+
+    // old collection balances are used to claim rewards during migration
+    if COLLECTION_MIGRATIONS.has(deps.storage, collection_address) {
+        Err(ContractError::CollectionMigrationInProgress)?;
+    }
+
+    // verify sender
+    check_authorization(
+        deps.as_ref(),
+        &sender_address,
+        AuthType::Specified {
+            allowlist: vec![Some(collection.owner)],
+        },
+    )?;
+
+    // verify emmision type
+    if collection.emission_type != EmissionType::Spending {
+        Err(ContractError::ActionByEmissionType)?;
+    }
+
+    COLLECTIONS_BALANCES.update(
+        deps.storage,
+        collection_address,
+        |x| -> StdResult<Funds<Token>> {
+            match x {
+                Some(funds) => Ok(Funds::new(funds.amount - amount, &funds.currency)),
+                _ => Err(ContractError::AssetIsNotFound)?,
+            }
+        },
+    )?;
+
+    let msg = get_transfer_msg(&sender_address, amount, &collection.staking_currency.token)?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_event(get_funds_event(
+            EVENT_WITHDRAW,
+            &sender_address,
+            collection_address,
+            amount,
+            &collection.staking_currency.token,
+        ))
+        .add_attributes([("action", "try_withdraw_tokens")]))
+}
+
+pub fn try_migrate_collection(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_address: String,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+
+    // stakers get rewards during migration
+    let collection_address = &deps.api.addr_validate(&collection_address)?;
+    check_action_pause(
+        deps.as_ref(),
+        &env,
+        ActionGroup::Claiming,
+        Some(collection_address),
+    )?;
+    let CollectionMigration {
+        new_collection_address,
+        ..
+    } = COLLECTION_MIGRATIONS
+        .load(deps.storage, collection_address)
+        .map_err(|_| ContractError::CollectionMigrationIsNotFound)?;
+    let new_collection = COLLECTIONS.load(deps.storage, &new_collection_address)?;
+
+    // verify sender
+    check_authorization(
+        deps.as_ref(),
+        &sender_address,
+        AuthType::AdminOrOwnerOrSpecified {
+            allowlist: vec![Some(new_collection.owner)],
+        },
+    )?;
+
+    let mut protocol_fee_list: FundsList = vec![];
+    // hybrid emission rewards must be minted to the platform first
+    let mut msg_list: Vec<CosmosMsg> = vec![];
+    let (rewards_emission_staker_list, hook_msg_list, is_completed) = migrate_collection_stakers(
+        deps.branch(),
+        &env,
+        collection_address,
+        limit,
+        &mut protocol_fee_list,
+        &mut msg_list,
+    )?;
+    msg_list.append(&mut get_rewards_msg_list(
+        deps.storage,
+        rewards_emission_staker_list,
+    )?);
+    msg_list.append(&mut apply_protocol_fees(
+        deps.storage,
+        &env,
+        protocol_fee_list,
+    )?);
+
+    Ok(Response::new()
+        .add_messages(msg_list)
+        .add_submessages(hook_msg_list)
+        .add_attributes([
+            ("action", "try_migrate_collection"),
+            ("is_completed", &is_completed.to_string()),
+        ]))
+}
+
+pub fn try_return_migrated_nfts(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    collection_address: String,
+    token_ids: Vec<Uint128>,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_authorization(deps.as_ref(), &sender_address, AuthType::AdminOrOwner)?;
+
+    let collection_address = &deps.api.addr_validate(&collection_address)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    // old collection is removed when all stakers are migrated so its NFTs can't be staked
+    if COLLECTIONS.has(deps.storage, collection_address) {
+        Err(ContractError::CollectionDuplication)?;
+    }
+
+    let mut msg_list: Vec<CosmosMsg> = vec![];
+
+    for token_id in token_ids {
+        // only old NFTs of migrated positions can be returned, NFTs of stakers
+        // of removed collections stay on the platform
+        let token_key = (collection_address, token_id.u128());
+        if !MIGRATED_TOKENS.has(deps.storage, token_key) {
+            Err(ContractError::MigratedTokenIsNotFound)?;
+        }
+
+        MIGRATED_TOKENS.remove(deps.storage, token_key);
+        TOKEN_ID_MAPPINGS.remove(deps.storage, token_key);
+
+        let cw721_msg = Cw721ExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        };
+
+        msg_list.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: collection_address.to_string(),
+            msg: to_json_binary(&cw721_msg)?,
+            funds: vec![],
+        }));
+    }
+
+    Ok(Response::new()
+        .add_messages(msg_list)
+        .add_attributes([("action", "try_return_migrated_nfts")]))
+}
+
+/// Moves positions of next batch of stakers from old collection address to new one
+/// claiming their rewards. Old collection is removed when all stakers are migrated.
+/// Staking limits of new collection aren't checked as already staked positions can't
+/// be rejected. Returns rewards to send, hook messages and migration completion flag,
+/// hybrid emission mint messages are added to provided list
+fn migrate_collection_stakers(
+    deps: DepsMut,
+    env: &Env,
+    collection_address: &Addr,
+    limit: Option<u32>,
+    protocol_fee_list: &mut FundsList,
+    hybrid_msg_list: &mut Vec<CosmosMsg>,
+) -> StdResult<(RewardsList, Vec<SubMsg>, bool)> {
+    let migration = COLLECTION_MIGRATIONS.load(deps.storage, collection_address)?;
+    let CollectionMigration {
+        new_collection_address,
+        last_staker,
+    } = &migration;
+    let collection = COLLECTIONS.load(deps.storage, collection_address)?;
+    let accrual_end_date = get_accrual_end_date(deps.storage, collection_address, env.block.time)?;
+    let protocol_fee = get_protocol_fee(deps.storage, collection_address)?;
+
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start_bound = last_staker.as_ref().map(Bound::exclusive);
+
+    let staker_list = STAKERS
+        .keys(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let is_completed = staker_list.len() < limit;
+    let last_staker = staker_list.last().cloned();
+    let mut rewards_emission_staker_list: RewardsList = vec![];
+    let mut hook_msg_list: Vec<SubMsg> = vec![];
+
+    for staker_address in staker_list {
+        // positions of receipts are moved to their holders before migration, holders are
+        // migrated with the staker to not skip positions moved to already migrated addresses
+        let mut owner_list = vec![staker_address];
+        let mut i = 0;
+
+        while i < owner_list.len() {
+            let (mut msg_list, holder_list) = sync_collection_receipts(
+                deps.storage,
+                &deps.querier,
+                env,
+                &owner_list[i],
+                collection_address,
+                &mut rewards_emission_staker_list,
+                protocol_fee_list,
+            )?;
+            hook_msg_list.append(&mut msg_list);
+
+            for holder in holder_list {
+                if !owner_list.contains(&holder) {
+                    owner_list.push(holder);
+                }
+            }
+
+            i += 1;
+        }
+
+        for staker_address in owner_list {
+            let mut collection_list = STAKERS.load(deps.storage, &staker_address)?;
+
+            let Some(index) = collection_list
+                .iter()
+                .position(|x| x.collection_address == collection_address)
+            else {
+                continue;
+            };
+
+            let staked_collection = collection_list.remove(index);
+            let (mut collection_balances, mint_limit) =
+                load_rewards_budget(deps.storage, collection_address, &collection)?;
+            let mut staker_rewards = Uint128::zero();
+            let mut staked_token_info_list: Vec<StakedTokenInfo> = vec![];
+            let old_token_ids: Vec<Uint128> = staked_collection
+                .staked_token_info_list
+                .iter()
+                .map(|x| x.token_id)
+                .collect();
+
+            for token in staked_collection.staked_token_info_list {
+                // calculate staking rewards
+                let amount = calc_token_rewards(
+                    deps.storage,
+                    collection_address,
+                    &collection,
+                    get_accrual_start_date(&token, collection.min_stake_duration)?,
+                    accrual_end_date,
+                )?;
+
+                // rewards are limited by collection budget to not block the migration
+                let amount = match collection_balances.as_mut() {
+                    Some(x) => {
+                        let amount = amount.min(x.amount);
+                        x.amount -= amount;
+                        amount
+                    }
+                    None => amount,
+                };
+
+                staker_rewards += amount;
+
+                let token_id_key = (collection_address, token.token_id.u128());
+                let token_id = TOKEN_ID_MAPPINGS
+                    .may_load(deps.storage, token_id_key)?
+                    .unwrap_or(token.token_id);
+                TOKEN_ID_MAPPINGS.remove(deps.storage, token_id_key);
+                MIGRATED_TOKENS.save(deps.storage, token_id_key, &Empty {})?;
+
+                // receipts are moved to new collection with their tokens
+                let receipt_key = (collection_address, token.token_id.u128());
+                if let Some(receipt_id) = RECEIPT_IDS.may_load(deps.storage, receipt_key)? {
+                    RECEIPT_IDS.remove(deps.storage, receipt_key);
+                    RECEIPT_IDS.save(
+                        deps.storage,
+                        (new_collection_address, token_id.u128()),
+                        &receipt_id,
+                    )?;
+                    RECEIPTS.update(deps.storage, receipt_id, |x| -> StdResult<Receipt> {
+                        Ok(Receipt {
+                            collection_address: new_collection_address.to_owned(),
+                            token_id,
+                            ..unwrap_field(x, "receipt")?
+                        })
+                    })?;
+                }
+
+                staked_token_info_list.push(StakedTokenInfo {
+                    token_id,
+                    last_claim_date: Some(env.block.time),
+                    ..token
+                });
+            }
+
+            remove_staked_nfts(
+                deps.storage,
+                &env.block,
+                &staker_address,
+                collection_address,
+                staked_token_info_list.len() as u128,
+            )?;
+            add_staked_nfts(
+                deps.storage,
+                &env.block,
+                &staker_address,
+                new_collection_address,
+                staked_token_info_list.len() as u128,
+            )?;
+
+            // hooks get migration as unstaking of old tokens and staking of new ones
+            hook_msg_list.append(&mut get_hook_msg_list(
+                deps.storage,
+                &staker_address,
+                collection_address,
+                old_token_ids,
+                StakeDirection::Unstake,
+            )?);
+            hook_msg_list.append(&mut get_hook_msg_list(
+                deps.storage,
+                &staker_address,
+                new_collection_address,
+                staked_token_info_list.iter().map(|x| x.token_id).collect(),
+                StakeDirection::Stake,
+            )?);
+
+            // staker could stake tokens of new collection before migration
+            match collection_list
+                .iter_mut()
+                .find(|x| x.collection_address == new_collection_address)
+            {
+                Some(x) => x.staked_token_info_list.append(&mut staked_token_info_list),
+                None => collection_list.push(StakedCollectionInfo {
+                    collection_address: new_collection_address.to_owned(),
+                    staked_token_info_list,
+                }),
+            };
+
+            STAKERS.save(deps.storage, &staker_address, &collection_list)?;
+
+            // set bonuses can be paid from balances of migrating collection
+            if let Some(x) = collection_balances {
+                let x = settle_rewards_budget(
+                    deps.storage,
+                    env,
+                    collection_address,
+                    x,
+                    mint_limit,
+                    hybrid_msg_list,
+                )?;
+                COLLECTIONS_BALANCES.save(deps.storage, collection_address, &x)?;
+            }
+
+            update_staker_sets(
+                deps.storage,
+                env,
+                &staker_address,
+                &mut rewards_emission_staker_list,
+                protocol_fee_list,
+            )?;
+
+            if !staker_rewards.is_zero() {
+                let protocol_fee_amount = calc_protocol_fee(staker_rewards, protocol_fee);
+                add_to_funds_list(
+                    protocol_fee_list,
+                    protocol_fee_amount,
+                    &collection.staking_currency,
+                    &collection.emission_type,
+                );
+
+                let funds = Funds::new(
+                    deduct_protocol_fee(
+                        staker_rewards,
+                        protocol_fee_amount,
+                        &collection.emission_type,
+                    ),
+                    &collection.staking_currency,
+                );
+                add_claimed_rewards(
+                    deps.storage,
+                    env.block.time,
+                    &staker_address,
+                    collection_address,
+                    &funds,
+                    &collection.emission_type,
+                )?;
+
+                rewards_emission_staker_list.push((
+                    funds,
+                    collection.emission_type.clone(),
+                    staker_address,
+                ));
+            }
+        }
+    }
+
+    if !is_completed {
+        COLLECTION_MIGRATIONS.save(
+            deps.storage,
+            collection_address,
+            &CollectionMigration {
+                last_staker,
+                ..migration
+            },
+        )?;
+
+        return Ok((rewards_emission_staker_list, hook_msg_list, is_completed));
+    }
+
+    // move unused funds to new collection balances or send them to collection owner
+    // if it's impossible
+    let collection_balances = COLLECTIONS_BALANCES.load(deps.storage, collection_address)?;
+
+    if !collection_balances.amount.is_zero() {
+        let new_collection = COLLECTIONS.load(deps.storage, new_collection_address)?;
+
+        if (new_collection.staking_currency == collection_balances.currency)
+            && new_collection.emission_type.is_spending()
+        {
+            COLLECTIONS_BALANCES.update(
+                deps.storage,
+                new_collection_address,
+                |x| -> StdResult<Funds<Token>> {
+                    let mut funds = unwrap_field(x, "collection_balances")?;
+                    funds.amount += collection_balances.amount;
+                    Ok(funds)
+                },
+            )?;
+        } else {
+            rewards_emission_staker_list.push((
+                collection_balances,
+                EmissionType::Spending,
+                collection.owner,
+            ));
+        }
+    }
+
+    COLLECTIONS.remove(deps.storage, collection_address);
+    COLLECTIONS_BALANCES.remove(deps.storage, collection_address);
+    PAUSED_COLLECTIONS.remove(deps.storage, collection_address);
+    COLLECTION_MIGRATIONS.remove(deps.storage, collection_address);
+    COLLECTION_SUPPLIES.remove(deps.storage, collection_address);
+
+    Ok((rewards_emission_staker_list, hook_msg_list, is_completed))
+}
+
+/// Moves positions of staked tokens to current holders of their receipts. Positions
+/// of receipts transferred by sender are moved to new holders and positions of receipts
+/// received by sender (single page of them) are moved to sender, so unclaimed rewards
+/// follow the receipts. Returns hook messages, bonuses of sets broken by moving are added
+/// to provided lists
+fn sync_receipts(
+    deps: &mut DepsMut,
+    env: &Env,
+    sender_address: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    rewards_emission_staker_list: &mut RewardsList,
+    protocol_fee_list: &mut FundsList,
+) -> StdResult<Vec<SubMsg>> {
+    let Some(receipt_collection) = CONFIG.load(deps.storage)?.receipt_collection else {
+        return Ok(vec![]);
+    };
+    let mut hook_msg_list: Vec<SubMsg> = vec![];
+
+    // receipts transferred by sender
+    let staker = STAKERS
+        .may_load(deps.storage, sender_address)?
+        .unwrap_or_default();
+
+    for StakedCollectionInfo {
+        collection_address, ..
+    } in staker
+    {
+        let (mut msg_list, _) = sync_collection_receipts(
+            deps.storage,
+            &deps.querier,
+            env,
+            sender_address,
+            &collection_address,
+            rewards_emission_staker_list,
+            protocol_fee_list,
+        )?;
+        hook_msg_list.append(&mut msg_list);
+    }
+
+    // receipts received by sender
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT);
+    let TokensResponse { tokens } = deps.querier.query_wasm_smart(
+        &receipt_collection,
+        &Cw721QueryMsg::Tokens {
+            owner: sender_address.to_string(),
+            start_after,
+            limit: Some(limit),
+        },
+    )?;
+
+    for token in &tokens {
+        let receipt_id: u128 = token
+            .parse()
+            .map_err(|_| StdError::parse_err("u128", token))?;
+
+        if RECEIPTS.load(deps.storage, receipt_id)?.staker != sender_address {
+            hook_msg_list.append(&mut move_receipt_position(
+                deps.storage,
+                env,
+                receipt_id,
+                sender_address,
+                rewards_emission_staker_list,
+                protocol_fee_list,
+            )?);
+        }
+    }
+
+    Ok(hook_msg_list)
+}
+
+/// Returns addresses of stakers having staked tokens of the collection
+fn get_collection_stakers(
+    storage: &dyn Storage,
+    collection_address: &Addr,
+) -> StdResult<Vec<Addr>> {
+    COLLECTION_STAKED_NFTS
+        .prefix(collection_address)
+        .range(storage, None, None, Order::Ascending)
+        .filter(|x| !matches!(x, Ok((_, 0))))
+        .map(|x| x.map(|(staker_address, _)| staker_address))
+        .collect()
+}
+
+/// Moves staked tokens of the collection from staker to current holders of their
+/// receipts. Returns hook messages and holders received the positions
+fn sync_collection_receipts(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper,
+    env: &Env,
+    staker_address: &Addr,
+    collection_address: &Addr,
+    rewards_emission_staker_list: &mut RewardsList,
+    protocol_fee_list: &mut FundsList,
+) -> StdResult<(Vec<SubMsg>, Vec<Addr>)> {
+    let Some(receipt_collection) = CONFIG.load(storage)?.receipt_collection else {
+        return Ok((vec![], vec![]));
+    };
+    let mut hook_msg_list: Vec<SubMsg> = vec![];
+    let mut holder_list: Vec<Addr> = vec![];
+
+    let token_id_list: Vec<Uint128> = STAKERS
+        .may_load(storage, staker_address)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|x| x.collection_address == collection_address)
+        .flat_map(|x| x.staked_token_info_list)
+        .map(|x| x.token_id)
+        .collect();
+
+    for token_id in token_id_list {
+        let Some(receipt_id) =
+            RECEIPT_IDS.may_load(storage, (collection_address, token_id.u128()))?
+        else {
+            continue;
+        };
+
+        let OwnerOfResponse { owner, .. } = querier.query_wasm_smart(
+            &receipt_collection,
+            &Cw721QueryMsg::OwnerOf {
+                token_id: receipt_id.to_string(),
+                include_expired: None,
+            },
+        )?;
+        let holder = Addr::unchecked(owner);
+
+        // receipt sent to the platform is unstaked by its previous holder
+        if holder == staker_address || holder == env.contract.address {
+            continue;
+        }
+
+        hook_msg_list.append(&mut move_receipt_position(
+            storage,
+            env,
+            receipt_id,
+            &holder,
+            rewards_emission_staker_list,
+            protocol_fee_list,
+        )?);
+
+        if !holder_list.contains(&holder) {
+            holder_list.push(holder);
+        }
+    }
+
+    Ok((hook_msg_list, holder_list))
+}
+
+/// Moves staked token of the receipt from its staker to receipt holder keeping
+/// staking and last claim dates. Staking limits aren't checked as receipt transfer
+/// can't be rejected by the platform. Returns hook messages
+fn move_receipt_position(
+    storage: &mut dyn Storage,
+    env: &Env,
+    receipt_id: u128,
+    holder: &Addr,
+    rewards_emission_staker_list: &mut RewardsList,
+    protocol_fee_list: &mut FundsList,
+) -> StdResult<Vec<SubMsg>> {
+    let receipt = RECEIPTS.load(storage, receipt_id)?;
+    let mut staker = STAKERS.load(storage, &receipt.staker)?;
+
+    let collection = staker
+        .iter_mut()
+        .find(|x| x.collection_address == receipt.collection_address)
+        .ok_or(ContractError::CollectionIsNotFound)?;
+    let index = collection
+        .staked_token_info_list
+        .iter()
+        .position(|x| x.token_id == receipt.token_id)
+        .ok_or(ContractError::AssetIsNotFound)?;
+    let token = collection.staked_token_info_list.remove(index);
+
+    staker.retain(|x| !x.staked_token_info_list.is_empty());
+    STAKERS.save(storage, &receipt.staker, &staker)?;
+
+    let mut holder_staker = STAKERS.may_load(storage, holder)?.unwrap_or_default();
+
+    match holder_staker
+        .iter_mut()
+        .find(|x| x.collection_address == receipt.collection_address)
+    {
+        Some(x) => x.staked_token_info_list.push(token),
+        None => holder_staker.push(StakedCollectionInfo {
+            collection_address: receipt.collection_address.to_owned(),
+            staked_token_info_list: vec![token],
+        }),
+    };
+
+    STAKERS.save(storage, holder, &holder_staker)?;
+
+    for staker_address in [&receipt.staker, holder] {
+        update_staker_sets(
+            storage,
+            env,
+            staker_address,
+            rewards_emission_staker_list,
+            protocol_fee_list,
+        )?;
+    }
+    remove_staked_nfts(
+        storage,
+        &env.block,
+        &receipt.staker,
+        &receipt.collection_address,
+        1,
+    )?;
+    add_staked_nfts(storage, &env.block, holder, &receipt.collection_address, 1)?;
+
+    let mut hook_msg_list = get_hook_msg_list(
+        storage,
+        &receipt.staker,
+        &receipt.collection_address,
+        vec![receipt.token_id],
+        StakeDirection::TransferOut,
+    )?;
+    hook_msg_list.append(&mut get_hook_msg_list(
+        storage,
+        holder,
+        &receipt.collection_address,
+        vec![receipt.token_id],
+        StakeDirection::TransferIn,
+    )?);
+
+    RECEIPTS.save(
+        storage,
+        receipt_id,
+        &Receipt {
+            staker: holder.to_owned(),
+            ..receipt
+        },
+    )?;
+
+    Ok(hook_msg_list)
+}
+
+/// Creates StakeChangedHook messages for all registered hooks
+fn get_hook_msg_list(
+    storage: &dyn Storage,
+    staker: &Addr,
+    collection_address: &Addr,
+    token_ids: Vec<Uint128>,
+    direction: StakeDirection,
+) -> StdResult<Vec<SubMsg>> {
+    if token_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let msg = to_json_binary(&StakeChangedExecuteMsg::StakeChangedHook(
+        StakeChangedHookMsg {
+            staker: staker.to_owned(),
+            collection_address: collection_address.to_owned(),
+            token_ids,
+            direction,
+        },
+    ))?;
+
+    Ok(HOOKS
+        .may_load(storage)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|hook| {
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: hook.to_string(),
+                msg: msg.clone(),
+                funds: vec![],
+            })
+        })
+        .collect())
+}
+
+/// Accrues protocol fees to platform funds. Fees of minting collections are minted
+/// to the contract
+fn apply_protocol_fees(
+    storage: &mut dyn Storage,
+    env: &Env,
+    protocol_fee_list: FundsList,
+) -> StdResult<Vec<CosmosMsg>> {
+    let mut rewards_emission_staker_list: RewardsList = vec![];
+
+    for (funds, emission_type) in protocol_fee_list {
+        if funds.amount.is_zero() {
+            continue;
+        }
+
+        add_to_funds(storage, &funds)?;
+
+        if !emission_type.is_spending() {
+            add_minted_tokens(storage, &funds)?;
+            rewards_emission_staker_list.push((
+                funds,
+                emission_type,
+                env.contract.address.to_owned(),
+            ));
+        }
+    }
+
+    if rewards_emission_staker_list.is_empty() {
+        return Ok(vec![]);
+    }
+
+    get_rewards_msg_list(storage, rewards_emission_staker_list)
+}
+
+fn add_to_funds(storage: &mut dyn Storage, funds: &Funds<Token>) -> StdResult<()> {
+    let mut funds_list = FUNDS.load(storage)?;
+
+    match funds_list
+        .iter_mut()
+        .find(|x| x.currency.token == funds.currency.token)
+    {
+        Some(x) => x.amount += funds.amount,
+        None => funds_list.push(funds.to_owned()),
+    };
+
+    FUNDS.save(storage, &funds_list)
+}
+
+fn add_to_funds_list(
+    funds_list: &mut FundsList,
+    amount: Uint128,
+    currency: &Currency<Token>,
+    emission_type: &EmissionType,
+) {
+    if amount.is_zero() {
+        return;
+    }
+
+    match funds_list.iter_mut().find(|(funds, emission)| {
+        (funds.currency.token == currency.token) && (emission == emission_type)
+    }) {
+        Some((funds, _)) => funds.amount += amount,
+        None => funds_list.push((Funds::new(amount, currency), emission_type.to_owned())),
+    };
+}
+
+/// Locks rewards in new vesting schedule if collection has vesting policy, rewards of
+/// minting collections are minted to the platform. Returns amount to send to staker
+fn vest_rewards(
+    storage: &mut dyn Storage,
+    env: &Env,
+    staker_address: &Addr,
+    collection_address: &Addr,
+    funds: Funds<Token>,
+    emission_type: &EmissionType,
+    msg_list: &mut Vec<CosmosMsg>,
+) -> StdResult<Uint128> {
+    let Some(policy) = VESTING_POLICIES.may_load(storage, collection_address)? else {
+        return Ok(funds.amount);
+    };
+
+    if funds.amount.is_zero() {
+        return Ok(Uint128::zero());
+    }
+
+    if let EmissionType::Minting = emission_type {
+        let minter = unwrap_field(CONFIG.load(storage)?.minter, "minter")?;
+        let mint_msg = gopstake_base::minter::msg::ExecuteMsg::MintTokens {
+            denom: funds.currency.token.get_symbol(),
+            amount: funds.amount,
+            mint_to_address: env.contract.address.to_string(),
+        };
+
+        msg_list.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: minter.to_string(),
+            msg: to_json_binary(&mint_msg)?,
+            funds: vec![],
+        }));
+    }
+
+    let vesting_counter = VESTING_COUNTER.may_load(storage)?.unwrap_or(1);
+    VESTINGS.save(
+        storage,
+        (staker_address, vesting_counter),
+        &Vesting {
+            collection_address: collection_address.to_owned(),
+            funds,
+            start_date: env.block.time,
+            policy,
+            withdrawn_amount: Uint128::zero(),
+        },
+    )?;
+    VESTING_COUNTER.save(storage, &(vesting_counter + 1))?;
+
+    Ok(Uint128::zero())
+}
+
+/// Returns balances of spending collection extended with amount of tokens which can be
+/// minted by hybrid emission and this amount
+fn load_rewards_budget(
+    storage: &dyn Storage,
+    collection_address: &Addr,
+    collection: &Collection<Addr, Token>,
+) -> StdResult<(Option<Funds<Token>>, Uint128)> {
+    if !collection.emission_type.is_spending() {
+        return Ok((None, Uint128::zero()));
+    }
+
+    let mut collection_balances = COLLECTIONS_BALANCES.load(storage, collection_address)?;
+    let mint_limit = match &collection.hybrid_emission {
+        Some(HybridEmission {
+            mint_cap: Some(mint_cap),
+        }) => mint_cap.saturating_sub(
+            HYBRID_MINTED
+                .may_load(storage, collection_address)?
+                .unwrap_or_default(),
+        ),
+        Some(HybridEmission { mint_cap: None }) => Uint128::MAX - collection_balances.amount,
+        None => Uint128::zero(),
+    };
+    collection_balances.amount += mint_limit;
+
+    Ok((Some(collection_balances), mint_limit))
+}
+
+/// Returns collection balances left after paying rewards from budget. Balances are spent
+/// first, the shortfall is minted to the platform to be paid as spending rewards
+fn settle_rewards_budget(
+    storage: &mut dyn Storage,
+    env: &Env,
+    collection_address: &Addr,
+    budget: Funds<Token>,
+    mint_limit: Uint128,
+    msg_list: &mut Vec<CosmosMsg>,
+) -> StdResult<Funds<Token>> {
+    let (collection_balances, minted_amount) =
+        spend_rewards_budget(storage, collection_address, budget, mint_limit)?;
+
+    if minted_amount.is_zero() {
+        return Ok(collection_balances);
+    }
+
+    let minter = unwrap_field(CONFIG.load(storage)?.minter, "minter")?;
+    let mint_msg = gopstake_base::minter::msg::ExecuteMsg::MintTokens {
+        denom: collection_balances.currency.token.get_symbol(),
+        amount: minted_amount,
+        mint_to_address: env.contract.address.to_string(),
+    };
+
+    msg_list.push(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: minter.to_string(),
+        msg: to_json_binary(&mint_msg)?,
+        funds: vec![],
+    }));
+
+    Ok(collection_balances)
+}
+
+/// Returns collection balances left after paying rewards from budget and amount of
+/// tokens which must be minted to the platform by hybrid emission to cover the shortfall
+fn spend_rewards_budget(
+    storage: &mut dyn Storage,
+    collection_address: &Addr,
+    budget: Funds<Token>,
+    mint_limit: Uint128,
+) -> StdResult<(Funds<Token>, Uint128)> {
+    let minted_amount = mint_limit.saturating_sub(budget.amount);
+    let collection_balances =
+        Funds::new(budget.amount.saturating_sub(mint_limit), &budget.currency);
+
+    if !minted_amount.is_zero() {
+        HYBRID_MINTED.update(storage, collection_address, |x| -> StdResult<Uint128> {
+            Ok(x.unwrap_or_default() + minted_amount)
+        })?;
+        add_minted_tokens(storage, &Funds::new(minted_amount, &budget.currency))?;
+    }
+
+    Ok((collection_balances, minted_amount))
+}
+
+/// Activates sets completed by staker and settles bonuses of sets broken by staker
+fn update_staker_sets(
+    storage: &mut dyn Storage,
+    env: &Env,
+    staker_address: &Addr,
+    rewards_emission_staker_list: &mut RewardsList,
+    protocol_fee_list: &mut FundsList,
+) -> StdResult<()> {
+    let staked_collections: Vec<Addr> = STAKERS
+        .may_load(storage, staker_address)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|x| !x.staked_token_info_list.is_empty())
+        .map(|x| x.collection_address)
+        .collect();
+
+    // only sets including staked collections or already active sets can be changed
+    let mut id_list = ACTIVE_SETS
+        .prefix(staker_address)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u128>>>()?;
+
+    for collection_address in &staked_collections {
+        id_list.append(
+            &mut COLLECTION_SETS
+                .prefix(collection_address)
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<u128>>>()?,
+        );
+    }
+
+    id_list.sort_unstable();
+    id_list.dedup();
+
+    for id in id_list {
+        let set = SETS.load(storage, id)?;
+        let is_completed = set
+            .collection_list
+            .iter()
+            .all(|x| staked_collections.contains(x));
+
+        match ACTIVE_SETS.may_load(storage, (staker_address, id))? {
+            Some(accrual_start_date) if !is_completed => {
+                settle_set_bonus(
+                    storage,
+                    env,
+                    staker_address,
+                    &set,
+                    accrual_start_date,
+                    true,
+                    rewards_emission_staker_list,
+                    protocol_fee_list,
+                )?;
+                deactivate_set(storage, staker_address, id);
+            }
+            None if is_completed => {
+                ACTIVE_SETS.save(storage, (staker_address, id), &env.block.time)?;
+                SET_STAKERS.save(storage, (id, staker_address), &Empty {})?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn deactivate_set(storage: &mut dyn Storage, staker_address: &Addr, id: u128) {
+    ACTIVE_SETS.remove(storage, (staker_address, id));
+    SET_STAKERS.remove(storage, (id, staker_address));
+}
+
+/// Settles bonuses of active sets including specified collection or of all active sets
+fn claim_staker_sets(
+    storage: &mut dyn Storage,
+    env: &Env,
+    staker_address: &Addr,
+    collection_address: Option<&Addr>,
+    rewards_emission_staker_list: &mut RewardsList,
+    protocol_fee_list: &mut FundsList,
+) -> StdResult<()> {
+    let active_sets = ACTIVE_SETS
+        .prefix(staker_address)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u128, Timestamp)>>>()?;
+
+    for (id, accrual_start_date) in active_sets {
+        let set = SETS.load(storage, id)?;
+
+        if collection_address.is_some_and(|x| !set.collection_list.contains(x)) {
+            continue;
+        }
+
+        if settle_set_bonus(
+            storage,
+            env,
+            staker_address,
+            &set,
+            accrual_start_date,
+            false,
+            rewards_emission_staker_list,
+            protocol_fee_list,
+        )? {
+            ACTIVE_SETS.save(storage, (staker_address, id), &env.block.time)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds accrued set bonus to rewards list considering collection balances, vesting
+/// policies aren't applied. If claiming is paused for any set collection bonus of ended
+/// set is kept as pending one and bonus of active set isn't settled, returns false then
+#[allow(clippy::too_many_arguments)]
+fn settle_set_bonus(
+    storage: &mut dyn Storage,
+    env: &Env,
+    staker_address: &Addr,
+    set: &StakingSet<Addr>,
+    accrual_start_date: Timestamp,
+    is_ended: bool,
+    rewards_emission_staker_list: &mut RewardsList,
+    protocol_fee_list: &mut FundsList,
+) -> StdResult<bool> {
+    let mut is_claiming_paused = false;
+
+    for collection_address in &set.collection_list {
+        if get_action_pause(
+            storage,
+            env,
+            ActionGroup::Claiming,
+            Some(collection_address),
+        )?
+        .is_some()
+        {
+            is_claiming_paused = true;
+        }
+    }
+
+    if is_claiming_paused && !is_ended {
+        return Ok(false);
+    }
+
+    for (collection_address, Funds { amount, currency }) in
+        get_set_bonus_list(storage, set, accrual_start_date, env.block.time)?
+    {
+        let collection = COLLECTIONS.load(storage, &collection_address)?;
+        let emission_type = collection.emission_type.clone();
+
+        // bonus is limited by collection balances and hybrid emission mint limit
+        let amount = match load_rewards_budget(storage, &collection_address, &collection)? {
+            (Some(mut budget), mint_limit) => {
+                let amount = amount.min(budget.amount);
+                budget.amount -= amount;
+
+                let (collection_balances, minted_amount) =
+                    spend_rewards_budget(storage, &collection_address, budget, mint_limit)?;
+                COLLECTIONS_BALANCES.save(storage, &collection_address, &collection_balances)?;
+
+                // shortfall is minted to the platform before paying the bonus
+                rewards_emission_staker_list.push((
+                    Funds::new(minted_amount, &currency),
+                    EmissionType::Minting,
+                    env.contract.address.to_owned(),
+                ));
+
+                amount
+            }
+            (None, _) => amount,
+        };
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        let protocol_fee_amount =
+            calc_protocol_fee(amount, get_protocol_fee(storage, &collection_address)?);
+        add_to_funds_list(
+            protocol_fee_list,
+            protocol_fee_amount,
+            &currency,
+            &emission_type,
+        );
+
+        let funds = Funds::new(
+            deduct_protocol_fee(amount, protocol_fee_amount, &emission_type),
+            &currency,
+        );
+
+        if is_claiming_paused {
+            add_pending_rewards(
+                storage,
+                &PENDING_SET_BONUSES,
+                staker_address,
+                &collection_address,
+                &funds,
+                &emission_type,
+            )?;
+            continue;
+        }
+
+        add_claimed_rewards(
+            storage,
+            env.block.time,
+            staker_address,
+            &collection_address,
+            &funds,
+            &emission_type,
+        )?;
+
+        rewards_emission_staker_list.push((funds, emission_type, staker_address.to_owned()));
+    }
+
+    Ok(!is_claiming_paused)
+}
+
+fn get_rewards_event_list(env: &Env, rewards_emission_staker_list: &RewardsList) -> Vec<Event> {
+    // tokens minted to the platform by hybrid emission aren't rewards
+    rewards_emission_staker_list
+        .iter()
+        .filter(|(funds, _, staker_address)| {
+            !funds.amount.is_zero() && staker_address != env.contract.address
+        })
+        .map(|(funds, emission, staker_address)| {
+            get_rewards_event(
+                staker_address,
+                funds.amount,
+                &funds.currency.token,
+                emission,
+            )
+        })
+        .collect()
+}
+
+fn get_rewards_msg_list(
+    storage: &dyn Storage,
+    rewards_emission_staker_list: RewardsList,
+) -> StdResult<Vec<CosmosMsg>> {
+    let minter = unwrap_field(CONFIG.load(storage)?.minter, "minter")?;
+
+    rewards_emission_staker_list
+        .into_iter()
+        .filter(|(funds, ..)| !funds.amount.is_zero())
+        .map(
+            |(Funds { amount, currency }, emission, staker_address)| -> StdResult<CosmosMsg> {
+                match emission {
+                    EmissionType::Spending => {
+                        get_transfer_msg(&staker_address, amount, &currency.token)
+                    }
+                    EmissionType::Minting => {
+                        let mint_msg = gopstake_base::minter::msg::ExecuteMsg::MintTokens {
+                            denom: currency.token.get_symbol(),
+                            amount,
+                            mint_to_address: staker_address.to_string(),
+                        };
+
+                        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                            contract_addr: minter.to_string(),
+                            msg: to_json_binary(&mint_msg)?,
+                            funds: vec![],
+                        }))
+                    }
+                }
+            },
+        )
+        .collect()
+}
+
+fn add_pending_rewards<'a>(
+    storage: &mut dyn Storage,
+    pending_rewards: &Map<'a, (&'a Addr, &'a Addr), (Funds<Token>, EmissionType)>,
+    staker_address: &'a Addr,
+    collection_address: &'a Addr,
+    funds: &Funds<Token>,
+    emission_type: &EmissionType,
+) -> StdResult<()> {
+    if funds.amount.is_zero() {
+        return Ok(());
+    }
+
+    pending_rewards.update(
+        storage,
+        (staker_address, collection_address),
+        |x| -> StdResult<(Funds<Token>, EmissionType)> {
+            Ok(match x {
+                Some((mut pending_funds, emission)) => {
+                    pending_funds.amount += funds.amount;
+                    (pending_funds, emission)
+                }
+                None => (funds.to_owned(), emission_type.to_owned()),
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Returns new and previous values of updated config field
+fn get_config_diff(
+    field: &str,
+    previous: Option<impl ToString>,
+    value: &str,
+) -> [(String, String); 2] {
+    [
+        (field.to_string(), value.to_string()),
+        (
+            format!("{}{}", PREVIOUS_VALUE_PREFIX, field),
+            previous.map_or(EMPTY_VALUE.to_string(), |x| x.to_string()),
+        ),
+    ]
+}
+
+/// Verifies rewards parameters of added or updated collection. Minting collections and
+/// collections with hybrid emission require staking currency created by collection owner
+fn verify_collection_emission(
+    deps: Deps,
+    owner: &Addr,
+    staking_currency: &Currency<Token>,
+    emission_type: &EmissionType,
+    fixed_pool: &Option<FixedPool>,
+    hybrid_emission: &Option<HybridEmission>,
+    reward_curve: &Option<RewardCurve>,
+) -> Result<(), ContractError> {
+    if fixed_pool.as_ref().is_some_and(|x| x.epoch_duration == 0) {
+        Err(ContractError::WrongFixedPool)?;
+    }
+
+    check_reward_curve(reward_curve, fixed_pool)?;
+
+    if hybrid_emission.is_some() && !emission_type.is_spending() {
+        Err(ContractError::WrongHybridEmission)?;
+    }
+
+    if !emission_type.is_spending() || hybrid_emission.is_some() {
+        // check if minter token was created by collection owner
+        let Config { minter, .. } = CONFIG.load(deps.storage)?;
+        let minter = unwrap_field(minter, "minter")?;
+
+        let gopstake_base::minter::types::QueryDenomsFromCreatorResponse { denoms } =
+            deps.querier.query_wasm_smart(
+                minter,
+                &gopstake_base::minter::msg::QueryMsg::DenomsByCreator {
+                    creator: owner.to_string(),
+                },
+            )?;
+
+        if !denoms.contains(&staking_currency.token.get_symbol()) {
+            Err(ContractError::UnownedStakingCurrency)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if reward curve has breakpoints sorted by utilization in range [0, 1] and
+/// isn't combined with fixed pool
+fn check_reward_curve(
+    reward_curve: &Option<RewardCurve>,
+    fixed_pool: &Option<FixedPool>,
+) -> Result<(), ContractError> {
+    let Some(RewardCurve { point_list }) = reward_curve else {
+        return Ok(());
+    };
+
+    let is_sorted = point_list
+        .windows(2)
+        .all(|x| x[0].utilization < x[1].utilization);
+    let is_in_range = point_list
+        .last()
+        .is_some_and(|x| x.utilization <= Decimal::one());
+
+    if fixed_pool.is_some() || !is_sorted || !is_in_range {
+        Err(ContractError::WrongRewardCurve)?;
+    }
+
+    Ok(())
+}
+
+/// Queries amount of NFTs from cw721 contract and saves it as collection supply
+fn save_collection_supply(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper,
+    collection_address: &Addr,
+) -> StdResult<()> {
+    let NumTokensResponse { count } =
+        querier.query_wasm_smart(collection_address, &Cw721QueryMsg::NumTokens {})?;
+
+    COLLECTION_SUPPLIES.save(storage, collection_address, &Uint128::from(count))
+}
+
+/// Checks if all weights are in range [0, 1] and their sum is equal one
+fn check_weights(address_and_weight_list: &[(Addr, Decimal)]) -> Result<(), ContractError> {
+    if address_and_weight_list
+        .iter()
+        .any(|(_, weight)| weight > &Decimal::one())
+    {
+        Err(ContractError::WeightIsOutOfRange)?;
+    }
+
+    let weights_sum = address_and_weight_list
+        .iter()
+        .fold(Decimal::zero(), |acc, (_, weight)| acc + weight);
+
+    if weights_sum != Decimal::one() {
+        Err(ContractError::WeightsAreUnbalanced)?;
+    }
+
+    Ok(())
+}
+
+/// Returns active pause of action group set for all collections or for specified one
+fn get_action_pause(
+    storage: &dyn Storage,
+    env: &Env,
+    action_group: ActionGroup,
+    collection_address: Option<&Addr>,
+) -> StdResult<Option<ActionPause>> {
+    let mut keys = vec![get_action_pause_key(&action_group, None)];
+
+    if collection_address.is_some() {
+        keys.push(get_action_pause_key(&action_group, collection_address));
+    }
+
+    for key in keys {
+        if let Some(x) = PAUSED_ACTIONS.may_load(storage, key)? {
+            if x.is_active(env.block.time) {
+                return Ok(Some(x));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn check_action_pause(
+    deps: Deps,
+    env: &Env,
+    action_group: ActionGroup,
+    collection_address: Option<&Addr>,
+) -> StdResult<()> {
+    if let Some(ActionPause { reason, .. }) =
+        get_action_pause(deps.storage, env, action_group, collection_address)?
+    {
+        Err(ContractError::ActionIsPaused { reason })?;
+    }
+
+    Ok(())
+}
+
+fn get_action_pause_key(
+    action_group: &ActionGroup,
+    collection_address: Option<&Addr>,
+) -> (String, String) {
+    (
+        action_group.to_string(),
+        collection_address
+            .map(|x| x.to_string())
+            .unwrap_or_default(),
+    )
+}
+
+/// Checks if collection isn't involved in unfinished migration as old or new one
+fn check_collection_migration(deps: Deps, collection_address: &Addr) -> StdResult<()> {
+    let is_migrating = COLLECTION_MIGRATIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .flatten()
+        .any(|(old_address, migration)| {
+            (old_address == collection_address)
+                || (migration.new_collection_address == collection_address)
+        });
+
+    if is_migrating {
+        Err(ContractError::CollectionMigrationInProgress)?;
+    }
+
+    Ok(())
+}
+
+/// Checks if sender is authorized and specified in proposal collection owner matches current owner
+fn verify_collection_owner(
+    deps: Deps,
+    sender: &Addr,
+    auth_type: AuthType,
+    collection_address: &Addr,
+    owner: &Addr,
+) -> StdResult<()> {
+    check_authorization(deps, sender, auth_type)?;
+
+    let collection = COLLECTIONS
+        .load(deps.storage, collection_address)
+        .map_err(|_| ContractError::CollectionIsNotFound)?;
+
+    if collection.owner != owner {
+        Err(ContractError::WrongCollectionOwner)?;
     }
 
     Ok(())
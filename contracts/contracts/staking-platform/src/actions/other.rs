@@ -1,20 +1,66 @@
-use cosmwasm_std::{DepsMut, Env, Response, StdError};
-use cw2::{get_contract_version, set_contract_version};
+use cosmwasm_std::{
+    from_json, Addr, Binary, DepsMut, Env, Order, Response, StdError, StdResult, Storage,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_storage_plus::{Bound, PrimaryKey};
 
 use semver::Version;
 
 use gopstake_base::{
+    constants::MIGRATION_DEFAULT_LIMIT,
     error::ContractError,
-    staking_platform::{msg::MigrateMsg, state},
+    staking_platform::{
+        msg::MigrateMsg,
+        state,
+        types::{ActionGroup, ActionPause, MigrationProgress, StakedCollectionInfo},
+    },
 };
 
+use crate::helpers::add_staked_nfts;
+
+/// Processes up to `limit` items after `cursor` and returns the last processed key
+/// if there are items left
+type MigrationFn = fn(&mut dyn Storage, &Env, Option<Binary>, usize) -> StdResult<Option<Binary>>;
+
+/// Storage migration step
+struct MigrationStep {
+    /// Contract version requiring the step
+    version: &'static str,
+    name: &'static str,
+    run: MigrationFn,
+}
+
+/// Storage migration steps in order of applying
+const MIGRATION_STEPS: &[MigrationStep] = &[
+    MigrationStep {
+        version: "1.4.0",
+        name: "index_proposals",
+        run: index_proposals,
+    },
+    MigrationStep {
+        version: "1.4.0",
+        name: "replace_global_lock",
+        run: replace_global_lock,
+    },
+    MigrationStep {
+        version: "1.4.0",
+        name: "fill_staked_nfts_snapshots",
+        run: fill_staked_nfts_snapshots,
+    },
+];
+
 pub fn migrate_contract(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
-    let version_previous: Version = get_contract_version(deps.storage)?
-        .version
+    let ContractVersion { contract, version } = get_contract_version(deps.storage)?;
+
+    if contract != state::CONTRACT_NAME {
+        Err(ContractError::WrongContractName)?;
+    }
+
+    let version_previous: Version = version
         .parse()
         .map_err(|_| StdError::generic_err("Parsing previous version error!"))?;
 
@@ -28,9 +74,173 @@ pub fn migrate_contract(
         ))?;
     }
 
-    if version_new >= version_previous {
-        set_contract_version(deps.storage, state::CONTRACT_NAME, version_new.to_string())?;
+    if version_new < version_previous {
+        Err(ContractError::ContractDowngrade)?;
+    }
+
+    let step_list = get_migration_steps(&version_previous, &version_new)?;
+    let limit = msg.limit.unwrap_or(MIGRATION_DEFAULT_LIMIT) as usize;
+
+    // unfinished step is continued from its cursor even if target version is changed
+    // as steps are applied in the same order and previous version isn't updated
+    let (step_index, mut cursor) = match state::MIGRATION_PROGRESS.may_load(deps.storage)? {
+        Some(progress) => step_list
+            .iter()
+            .position(|x| x.name == progress.step)
+            .map(|i| (i, progress.cursor))
+            .unwrap_or_default(),
+        None => (0, None),
+    };
+
+    for step in step_list.iter().skip(step_index) {
+        let step_cursor = (step.run)(deps.storage, &env, cursor.take(), limit)?;
+
+        if step_cursor.is_some() {
+            state::MIGRATION_PROGRESS.save(
+                deps.storage,
+                &MigrationProgress {
+                    step: step.name.to_string(),
+                    cursor: step_cursor,
+                },
+            )?;
+
+            return Ok(Response::new().add_attributes([
+                ("action", "migrate_contract"),
+                ("status", "in_progress"),
+                ("step", step.name),
+            ]));
+        }
+    }
+
+    state::MIGRATION_PROGRESS.remove(deps.storage);
+    set_contract_version(deps.storage, state::CONTRACT_NAME, version_new.to_string())?;
+
+    Ok(Response::new().add_attributes([("action", "migrate_contract"), ("status", "completed")]))
+}
+
+fn get_migration_steps(
+    version_previous: &Version,
+    version_new: &Version,
+) -> StdResult<Vec<&'static MigrationStep>> {
+    let mut step_list: Vec<&MigrationStep> = vec![];
+
+    for step in MIGRATION_STEPS {
+        let version: Version = step
+            .version
+            .parse()
+            .map_err(|_| StdError::generic_err("Parsing step version error!"))?;
+
+        if version > *version_previous && version <= *version_new {
+            step_list.push(step);
+        }
+    }
+
+    Ok(step_list)
+}
+
+/// Proposals saved before adding secondary indexes must be indexed
+fn index_proposals(
+    storage: &mut dyn Storage,
+    _env: &Env,
+    cursor: Option<Binary>,
+    limit: usize,
+) -> StdResult<Option<Binary>> {
+    let proposals = state::PROPOSALS
+        .range(
+            storage,
+            cursor.map(|x| Bound::ExclusiveRaw(x.into())),
+            None,
+            Order::Ascending,
+        )
+        .take(limit + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let is_unfinished = proposals.len() > limit;
+    let mut cursor = None;
+
+    for (id, proposal) in proposals.into_iter().take(limit) {
+        state::PROPOSALS.save(storage, id, &proposal)?;
+        cursor = Some(Binary::from(id.joined_key()));
+    }
+
+    Ok(cursor.filter(|_| is_unfinished))
+}
+
+/// Global lock is replaced with action groups pauses
+fn replace_global_lock(
+    storage: &mut dyn Storage,
+    _env: &Env,
+    _cursor: Option<Binary>,
+    _limit: usize,
+) -> StdResult<Option<Binary>> {
+    if let Some(is_locked) = storage.get(b"is_locked") {
+        if from_json::<bool>(&is_locked)? {
+            for action_group in [
+                ActionGroup::Staking,
+                ActionGroup::Unstaking,
+                ActionGroup::Claiming,
+                ActionGroup::Proposals,
+                ActionGroup::Treasury,
+                ActionGroup::Deposits,
+            ] {
+                state::PAUSED_ACTIONS.save(
+                    storage,
+                    (action_group.to_string(), String::default()),
+                    &ActionPause {
+                        action_group,
+                        collection_address: None,
+                        reason: "Contract was locked".to_string(),
+                        expiration_date: None,
+                    },
+                )?;
+            }
+        }
+
+        storage.remove(b"is_locked");
+    }
+
+    Ok(None)
+}
+
+/// Staked NFTs snapshots must be filled with existing positions
+fn fill_staked_nfts_snapshots(
+    storage: &mut dyn Storage,
+    env: &Env,
+    cursor: Option<Binary>,
+    limit: usize,
+) -> StdResult<Option<Binary>> {
+    if cursor.is_none() {
+        if state::TOTAL_STAKED_NFTS.may_load(storage)?.is_some() {
+            return Ok(None);
+        }
+
+        state::TOTAL_STAKED_NFTS.save(storage, &0, env.block.height)?;
+    }
+
+    let stakers = state::STAKERS
+        .range(
+            storage,
+            cursor.map(|x| Bound::ExclusiveRaw(x.into())),
+            None,
+            Order::Ascending,
+        )
+        .take(limit + 1)
+        .collect::<StdResult<Vec<(Addr, Vec<StakedCollectionInfo<Addr>>)>>>()?;
+    let is_unfinished = stakers.len() > limit;
+    let mut cursor = None;
+
+    for (staker, collection_list) in stakers.into_iter().take(limit) {
+        for collection in collection_list {
+            add_staked_nfts(
+                storage,
+                &env.block,
+                &staker,
+                &collection.collection_address,
+                collection.staked_token_info_list.len() as u128,
+            )?;
+        }
+
+        cursor = Some(Binary::from(staker.joined_key()));
     }
 
-    Ok(Response::new())
+    Ok(cursor.filter(|_| is_unfinished))
 }
@@ -1,21 +1,47 @@
 use cosmwasm_std::{Addr, Coin, Deps, Env, Order, StdResult, Uint128};
+use cw2::get_contract_version;
+use cw_storage_plus::Bound;
 
 use gopstake_base::{
     assets::{Currency, Funds, Token},
-    constants::{MINS_PER_DAY, NANOS_PER_MIN},
-    converters::{dec256_to_uint128, dec_to_dec256, u128_to_dec256},
+    constants::{PAGINATION_DEFAULT_LIMIT, PAGINATION_MAX_LIMIT},
     error::ContractError,
+    math::calc_protocol_fee,
     staking_platform::{
         msg::{
-            BalancesResponseItem, QueryCollectionsBalancesResponseItem,
-            QueryCollectionsResponseItem, QueryProposalsResponseItem, QueryStakersResponseItem,
+            BalancesResponseItem, ClaimItem, ClaimedRewardsItem, InfoResponse,
+            PortfolioCollectionItem, PortfolioTokenItem, QueryActiveSetsResponseItem,
+            QueryCampaignsResponseItem, QueryClaimHistoryResponse,
+            QueryCollectionMigrationsResponseItem, QueryCollectionStatsResponse,
+            QueryCollectionTotalAtHeightResponse, QueryCollectionsBalancesResponseItem,
+            QueryCollectionsResponseItem, QueryDistributionsResponseItem,
+            QueryPlatformStatsResponse, QueryProposalsResponseItem, QuerySetsResponseItem,
+            QueryStakedAtHeightResponse, QueryStakerPortfolioResponse, QueryStakersResponseItem,
+            QueryVestingsResponseItem, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+        },
+        state::{
+            ACTIVE_SETS, CAMPAIGNS, CAMPAIGN_CLAIMS, CLAIMED_REWARDS, CLAIMS, COLLECTIONS,
+            COLLECTIONS_BALANCES, COLLECTION_MIGRATIONS, COLLECTION_STAKED_NFTS, COLLECTION_STATS,
+            COLLECTION_TOTAL_STAKED_NFTS, CONFIG, DISTRIBUTIONS, FUNDS, HOOKS, PAUSED_ACTIONS,
+            PAUSED_COLLECTIONS, PLATFORM_STATS, PROPOSALS, RECEIPTS, RESTAKE_COOLDOWNS,
+            REVENUE_SPLIT, SETS, STAKED_NFTS, STAKERS, TOTAL_STAKED_NFTS, VESTINGS,
+            VESTING_POLICIES,
+        },
+        types::{
+            ActionPause, Collection, CollectionStats, Config, EmissionType, PlatformStats,
+            Proposal, ProposalFilter, Receipt, RevenueSplit, StakedCollectionInfo, TokenTimeLock,
         },
-        state::{COLLECTIONS, COLLECTIONS_BALANCES, CONFIG, FUNDS, PROPOSALS, STAKERS},
-        types::{Collection, Config, StakedCollectionInfo},
     },
-    utils::{filter_by_address_list, unwrap_field},
+    utils::filter_by_address_list,
+};
+
+use crate::helpers::{
+    calc_token_rewards, deduct_protocol_fee, get_accrual_end_date, get_accrual_start_date,
+    get_protocol_fee, get_set_bonus_list, get_stake_unlock_date,
 };
 
+type ProposalIterator<'a> = Box<dyn Iterator<Item = StdResult<(u128, Proposal<Addr, Token>)>> + 'a>;
+
 pub fn query_config(deps: Deps, _env: Env) -> StdResult<Config> {
     CONFIG.load(deps.storage)
 }
@@ -26,23 +52,63 @@ pub fn query_funds(deps: Deps, _env: Env) -> StdResult<Vec<Funds<Token>>> {
 
 pub fn query_stakers(
     deps: Deps,
-    _env: Env,
+    env: Env,
     addresses: Option<Vec<String>>,
 ) -> StdResult<Vec<QueryStakersResponseItem>> {
     let stakers: Vec<(Addr, Vec<StakedCollectionInfo<Addr>>)> = STAKERS
         .range(deps.storage, None, None, Order::Ascending)
         .flatten()
         .collect();
+    let block_time = env.block.time;
 
-    Ok(filter_by_address_list(deps.api, &addresses, &stakers)?
+    filter_by_address_list(deps.api, &addresses, &stakers)?
         .into_iter()
         .map(
-            |(staker_address, staked_collection_info_list)| QueryStakersResponseItem {
-                staker_address,
-                staked_collection_info_list,
+            |(staker_address, staked_collection_info_list)| -> StdResult<QueryStakersResponseItem> {
+                let mut stake_lock_list: Vec<TokenTimeLock> = vec![];
+
+                for collection_info in &staked_collection_info_list {
+                    let min_stake_duration = COLLECTIONS
+                        .may_load(deps.storage, &collection_info.collection_address)?
+                        .and_then(|x| x.min_stake_duration);
+
+                    for token in &collection_info.staked_token_info_list {
+                        match get_stake_unlock_date(token, min_stake_duration) {
+                            Some(unlock_date) if block_time < unlock_date => {
+                                stake_lock_list.push(TokenTimeLock {
+                                    collection_address: collection_info.collection_address.clone(),
+                                    token_id: token.token_id,
+                                    remaining_time: unlock_date.seconds() - block_time.seconds(),
+                                });
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                let restake_cooldown_list = RESTAKE_COOLDOWNS
+                    .idx
+                    .staker
+                    .prefix(staker_address.clone())
+                    .range(deps.storage, None, None, Order::Ascending)
+                    .flatten()
+                    .filter(|(_, x)| block_time < x.expiration_date)
+                    .map(|((collection_address, token_id), x)| TokenTimeLock {
+                        collection_address,
+                        token_id: Uint128::new(token_id),
+                        remaining_time: x.expiration_date.seconds() - block_time.seconds(),
+                    })
+                    .collect();
+
+                Ok(QueryStakersResponseItem {
+                    staker_address,
+                    staked_collection_info_list,
+                    stake_lock_list,
+                    restake_cooldown_list,
+                })
             },
         )
-        .collect())
+        .collect()
 }
 
 pub fn query_staking_rewards(
@@ -50,32 +116,38 @@ pub fn query_staking_rewards(
     env: Env,
     address: String,
 ) -> StdResult<BalancesResponseItem> {
-    // There is no reason to update rewards every 1 ns. We will use 1 min timeframe
-    let time_in_nanos: u128 = env.block.time.nanos().into();
-    let mins_per_day = u128_to_dec256(MINS_PER_DAY);
-
     let staker_address = deps.api.addr_validate(&address)?;
     let collection_list = STAKERS.load(deps.storage, &staker_address)?;
 
     let mut funds_list: Vec<Funds<Token>> = vec![];
 
     for collection_info in collection_list {
+        let collection = COLLECTIONS.load(deps.storage, &collection_info.collection_address)?;
         let Collection {
             staking_currency,
-            daily_rewards,
+            min_stake_duration,
             ..
-        } = COLLECTIONS.load(deps.storage, &collection_info.collection_address)?;
+        } = collection.clone();
+        let accrual_end_date = get_accrual_end_date(
+            deps.storage,
+            &collection_info.collection_address,
+            env.block.time,
+        )?;
+        let protocol_fee = get_protocol_fee(deps.storage, &collection_info.collection_address)?;
 
         for token in collection_info.staked_token_info_list {
-            let last_claim_date_in_nanos: u128 =
-                unwrap_field(token.last_claim_date, "last_claim_date")?
-                    .nanos()
-                    .into();
-
-            let time_diff_in_mins = u128_to_dec256(time_in_nanos - last_claim_date_in_nanos)
-                / u128_to_dec256(NANOS_PER_MIN);
-            let amount =
-                dec256_to_uint128(time_diff_in_mins * dec_to_dec256(daily_rewards) / mins_per_day);
+            let amount = calc_token_rewards(
+                deps.storage,
+                &collection_info.collection_address,
+                &collection,
+                get_accrual_start_date(&token, min_stake_duration)?,
+                accrual_end_date,
+            )?;
+            let amount = deduct_protocol_fee(
+                amount,
+                calc_protocol_fee(amount, protocol_fee),
+                &collection.emission_type,
+            );
 
             let token_list: Vec<Token> = funds_list
                 .iter()
@@ -106,10 +178,6 @@ pub fn query_staking_rewards_per_collection(
     staker: String,
     collection: String,
 ) -> StdResult<BalancesResponseItem> {
-    // There is no reason to update rewards every 1 ns. We will use 1 min timeframe
-    let time_in_nanos: u128 = env.block.time.nanos().into();
-    let mins_per_day = u128_to_dec256(MINS_PER_DAY);
-
     let staker_address = deps.api.addr_validate(&staker)?;
     let collection_address = deps.api.addr_validate(&collection)?;
     let collection_list = STAKERS.load(deps.storage, &staker_address)?;
@@ -120,22 +188,32 @@ pub fn query_staking_rewards_per_collection(
 
     let mut funds_list: Vec<Funds<Token>> = vec![];
 
+    let collection = COLLECTIONS.load(deps.storage, &collection_info.collection_address)?;
     let Collection {
         staking_currency,
-        daily_rewards,
+        min_stake_duration,
         ..
-    } = COLLECTIONS.load(deps.storage, &collection_info.collection_address)?;
+    } = collection.clone();
+    let accrual_end_date = get_accrual_end_date(
+        deps.storage,
+        &collection_info.collection_address,
+        env.block.time,
+    )?;
+    let protocol_fee = get_protocol_fee(deps.storage, &collection_info.collection_address)?;
 
     for token in &collection_info.staked_token_info_list {
-        let last_claim_date_in_nanos: u128 =
-            unwrap_field(token.last_claim_date, "last_claim_date")?
-                .nanos()
-                .into();
-
-        let time_diff_in_mins = u128_to_dec256(time_in_nanos - last_claim_date_in_nanos)
-            / u128_to_dec256(NANOS_PER_MIN);
-        let amount =
-            dec256_to_uint128(time_diff_in_mins * dec_to_dec256(daily_rewards) / mins_per_day);
+        let amount = calc_token_rewards(
+            deps.storage,
+            &collection_info.collection_address,
+            &collection,
+            get_accrual_start_date(token, min_stake_duration)?,
+            accrual_end_date,
+        )?;
+        let amount = deduct_protocol_fee(
+            amount,
+            calc_protocol_fee(amount, protocol_fee),
+            &collection.emission_type,
+        );
 
         let token_list: Vec<Token> = funds_list
             .iter()
@@ -235,6 +313,137 @@ pub fn query_associated_balances(
     })
 }
 
+pub fn query_staker_portfolio(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<QueryStakerPortfolioResponse> {
+    let staker_address = deps.api.addr_validate(&address)?;
+    let staked_collection_list = STAKERS
+        .may_load(deps.storage, &staker_address)?
+        .unwrap_or_default();
+
+    let mut collection_list: Vec<PortfolioCollectionItem> = vec![];
+    let mut rewards_list: Vec<(Funds<Token>, EmissionType)> = vec![];
+    let mut total_staked = Uint128::zero();
+
+    for StakedCollectionInfo {
+        collection_address,
+        staked_token_info_list,
+    } in staked_collection_list
+    {
+        let collection = COLLECTIONS.load(deps.storage, &collection_address)?;
+        let accrual_end_date =
+            get_accrual_end_date(deps.storage, &collection_address, env.block.time)?;
+        let protocol_fee = get_protocol_fee(deps.storage, &collection_address)?;
+
+        let mut token_list: Vec<PortfolioTokenItem> = vec![];
+        let mut collection_rewards = Uint128::zero();
+
+        for token in staked_token_info_list {
+            let amount = calc_token_rewards(
+                deps.storage,
+                &collection_address,
+                &collection,
+                get_accrual_start_date(&token, collection.min_stake_duration)?,
+                accrual_end_date,
+            )?;
+            let rewards = deduct_protocol_fee(
+                amount,
+                calc_protocol_fee(amount, protocol_fee),
+                &collection.emission_type,
+            );
+
+            collection_rewards += rewards;
+            token_list.push(PortfolioTokenItem {
+                staked_token_info: token,
+                rewards,
+            });
+        }
+
+        total_staked += Uint128::from(token_list.len() as u128);
+
+        match rewards_list.iter_mut().find(|(funds, emission_type)| {
+            funds.currency.token == collection.staking_currency.token
+                && emission_type == &collection.emission_type
+        }) {
+            Some((funds, _)) => funds.amount += collection_rewards,
+            None => rewards_list.push((
+                Funds::new(collection_rewards, &collection.staking_currency),
+                collection.emission_type.clone(),
+            )),
+        }
+
+        collection_list.push(PortfolioCollectionItem {
+            collection_address,
+            rewards: Funds::new(collection_rewards, &collection.staking_currency),
+            collection,
+            token_list,
+        });
+    }
+
+    let BalancesResponseItem {
+        funds_list: balance_list,
+        ..
+    } = query_associated_balances(deps, env, address)?;
+
+    Ok(QueryStakerPortfolioResponse {
+        staker_address,
+        collection_list,
+        rewards_list,
+        balance_list,
+        total_staked,
+    })
+}
+
+pub fn query_platform_stats(deps: Deps, _env: Env) -> StdResult<QueryPlatformStatsResponse> {
+    let PlatformStats {
+        unique_stakers,
+        claimed_list,
+        minted_list,
+        listing_revenue_list,
+    } = PLATFORM_STATS.may_load(deps.storage)?.unwrap_or_default();
+
+    Ok(QueryPlatformStatsResponse {
+        total_staked: Uint128::new(
+            TOTAL_STAKED_NFTS
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+        ),
+        unique_stakers,
+        claimed_list,
+        minted_list,
+        listing_revenue_list,
+    })
+}
+
+pub fn query_collection_stats(
+    deps: Deps,
+    _env: Env,
+    address: String,
+) -> StdResult<QueryCollectionStatsResponse> {
+    let collection_address = deps.api.addr_validate(&address)?;
+    let CollectionStats {
+        unique_stakers,
+        claimed_list,
+        listing_revenue_list,
+    } = COLLECTION_STATS
+        .may_load(deps.storage, &collection_address)?
+        .unwrap_or_default();
+
+    Ok(QueryCollectionStatsResponse {
+        total_staked: Uint128::new(
+            COLLECTION_TOTAL_STAKED_NFTS
+                .may_load(deps.storage, &collection_address)?
+                .unwrap_or_default(),
+        ),
+        collection_address,
+        unique_stakers,
+        claimed_list,
+        listing_revenue_list,
+    })
+}
+
 pub fn query_proposals(
     deps: Deps,
     _env: Env,
@@ -258,6 +467,67 @@ pub fn query_proposals(
     Ok(proposals)
 }
 
+pub fn query_proposals_by_filter(
+    deps: Deps,
+    _env: Env,
+    filter: ProposalFilter<String>,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<QueryProposalsResponseItem>> {
+    let filter = filter.verify(&deps)?;
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start_bound = start_after.map(|x| Bound::exclusive(x.u128()));
+
+    // use the most selective index to get proposals and check the rest conditions after
+    let proposals: ProposalIterator = if let Some(x) = &filter.collection_owner {
+        Box::new(PROPOSALS.idx.collection_owner.prefix(x.to_owned()).range(
+            deps.storage,
+            start_bound,
+            None,
+            Order::Ascending,
+        ))
+    } else if let Some(x) = &filter.collection_address {
+        Box::new(PROPOSALS.idx.collection_address.prefix(x.to_owned()).range(
+            deps.storage,
+            start_bound,
+            None,
+            Order::Ascending,
+        ))
+    } else if let Some(x) = &filter.proposal_status {
+        Box::new(PROPOSALS.idx.status.prefix(x.to_string()).range(
+            deps.storage,
+            start_bound,
+            None,
+            Order::Ascending,
+        ))
+    } else if let Some(x) = &filter.proposal_kind {
+        Box::new(PROPOSALS.idx.proposal_kind.prefix(x.to_string()).range(
+            deps.storage,
+            start_bound,
+            None,
+            Order::Ascending,
+        ))
+    } else {
+        Box::new(PROPOSALS.range(deps.storage, start_bound, None, Order::Ascending))
+    };
+
+    proposals
+        .filter(|item| match item {
+            Ok((_, proposal)) => filter.is_matched(proposal),
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|item| {
+            item.map(|(id, proposal)| QueryProposalsResponseItem {
+                id: id.into(),
+                proposal,
+            })
+        })
+        .collect()
+}
+
 pub fn query_collections(
     deps: Deps,
     _env: Env,
@@ -268,13 +538,24 @@ pub fn query_collections(
         .flatten()
         .collect();
 
-    Ok(filter_by_address_list(deps.api, &addresses, &collections)?
+    filter_by_address_list(deps.api, &addresses, &collections)?
         .into_iter()
-        .map(|(address, collection)| QueryCollectionsResponseItem {
-            address,
-            collection,
-        })
-        .collect())
+        .map(
+            |(address, collection)| -> StdResult<QueryCollectionsResponseItem> {
+                Ok(QueryCollectionsResponseItem {
+                    pause_date: PAUSED_COLLECTIONS.may_load(deps.storage, &address)?,
+                    protocol_fee: get_protocol_fee(deps.storage, &address)?,
+                    vesting_policy: VESTING_POLICIES.may_load(deps.storage, &address)?,
+                    total_staked: COLLECTION_TOTAL_STAKED_NFTS
+                        .may_load(deps.storage, &address)?
+                        .unwrap_or_default()
+                        .into(),
+                    address,
+                    collection,
+                })
+            },
+        )
+        .collect()
 }
 
 pub fn query_collections_balances(
@@ -294,3 +575,340 @@ pub fn query_collections_balances(
             .collect(),
     )
 }
+
+pub fn query_collection_migrations(
+    deps: Deps,
+    _env: Env,
+) -> StdResult<Vec<QueryCollectionMigrationsResponseItem>> {
+    COLLECTION_MIGRATIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| {
+            let (collection_address, migration) = x?;
+
+            Ok(QueryCollectionMigrationsResponseItem {
+                collection_address,
+                migration,
+            })
+        })
+        .collect()
+}
+
+pub fn query_paused_actions(deps: Deps, env: Env) -> StdResult<Vec<ActionPause>> {
+    Ok(PAUSED_ACTIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .flatten()
+        .map(|(_, x)| x)
+        .filter(|x| x.is_active(env.block.time))
+        .collect())
+}
+
+pub fn query_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+    collection: Option<String>,
+) -> StdResult<VotingPowerAtHeightResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let height = height.unwrap_or(env.block.height);
+
+    let power = match collection {
+        Some(x) => {
+            let collection_address = deps.api.addr_validate(&x)?;
+            COLLECTION_STAKED_NFTS.may_load_at_height(
+                deps.storage,
+                (&collection_address, &address),
+                height,
+            )?
+        }
+        None => STAKED_NFTS.may_load_at_height(deps.storage, &address, height)?,
+    }
+    .unwrap_or_default();
+
+    Ok(VotingPowerAtHeightResponse {
+        power: power.into(),
+        height,
+    })
+}
+
+pub fn query_total_power_at_height(
+    deps: Deps,
+    env: Env,
+    height: Option<u64>,
+    collection: Option<String>,
+) -> StdResult<TotalPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+
+    let power = match collection {
+        Some(x) => {
+            let collection_address = deps.api.addr_validate(&x)?;
+            COLLECTION_TOTAL_STAKED_NFTS.may_load_at_height(
+                deps.storage,
+                &collection_address,
+                height,
+            )?
+        }
+        None => TOTAL_STAKED_NFTS.may_load_at_height(deps.storage, height)?,
+    }
+    .unwrap_or_default();
+
+    Ok(TotalPowerAtHeightResponse {
+        power: power.into(),
+        height,
+    })
+}
+
+pub fn query_staked_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<QueryStakedAtHeightResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let height = height.unwrap_or(env.block.height);
+
+    // snapshot keys aren't removed so removed and migrated collections are included
+    let collection_address_list = COLLECTION_TOTAL_STAKED_NFTS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let mut collection_and_amount_list: Vec<(Addr, Uint128)> = vec![];
+
+    for collection_address in collection_address_list {
+        let amount = COLLECTION_STAKED_NFTS
+            .may_load_at_height(deps.storage, (&collection_address, &address), height)?
+            .unwrap_or_default();
+
+        if amount != 0 {
+            collection_and_amount_list.push((collection_address, Uint128::new(amount)));
+        }
+    }
+
+    let total = STAKED_NFTS
+        .may_load_at_height(deps.storage, &address, height)?
+        .unwrap_or_default();
+
+    Ok(QueryStakedAtHeightResponse {
+        height,
+        total: Uint128::new(total),
+        collection_and_amount_list,
+    })
+}
+
+pub fn query_collection_total_at_height(
+    deps: Deps,
+    env: Env,
+    collection: String,
+    height: Option<u64>,
+) -> StdResult<QueryCollectionTotalAtHeightResponse> {
+    let collection_address = deps.api.addr_validate(&collection)?;
+    let height = height.unwrap_or(env.block.height);
+
+    let total = COLLECTION_TOTAL_STAKED_NFTS
+        .may_load_at_height(deps.storage, &collection_address, height)?
+        .unwrap_or_default();
+
+    Ok(QueryCollectionTotalAtHeightResponse {
+        height,
+        total: Uint128::new(total),
+    })
+}
+
+pub fn query_sets(deps: Deps, _env: Env) -> StdResult<Vec<QuerySetsResponseItem>> {
+    SETS.range(deps.storage, None, None, Order::Ascending)
+        .map(|x| {
+            let (id, set) = x?;
+
+            Ok(QuerySetsResponseItem {
+                id: Uint128::new(id),
+                set,
+            })
+        })
+        .collect()
+}
+
+pub fn query_active_sets(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<Vec<QueryActiveSetsResponseItem>> {
+    let address = deps.api.addr_validate(&address)?;
+
+    ACTIVE_SETS
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| {
+            let (id, accrual_start_date) = x?;
+            let set = SETS.load(deps.storage, id)?;
+            let bonus_list =
+                get_set_bonus_list(deps.storage, &set, accrual_start_date, env.block.time)?
+                    .into_iter()
+                    .map(|(_, funds)| funds)
+                    .collect();
+
+            Ok(QueryActiveSetsResponseItem {
+                id: Uint128::new(id),
+                set,
+                accrual_start_date,
+                bonus_list,
+            })
+        })
+        .collect()
+}
+
+pub fn query_vestings(
+    deps: Deps,
+    env: Env,
+    address: String,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<QueryVestingsResponseItem>> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start_bound = start_after.map(|x| Bound::exclusive(x.u128()));
+
+    VESTINGS
+        .prefix(&address)
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|x| {
+            let (id, vesting) = x?;
+
+            Ok(QueryVestingsResponseItem {
+                id: Uint128::new(id),
+                withdrawable_amount: vesting.get_withdrawable_amount(env.block.time),
+                vesting,
+            })
+        })
+        .collect()
+}
+
+pub fn query_claim_history(
+    deps: Deps,
+    _env: Env,
+    address: String,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<QueryClaimHistoryResponse> {
+    let staker_address = deps.api.addr_validate(&address)?;
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let end_bound = start_after.map(|x| Bound::exclusive(x.u128()));
+
+    let claimed_list = CLAIMED_REWARDS
+        .prefix(&staker_address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| {
+            let (collection_address, funds_list) = x?;
+
+            Ok(ClaimedRewardsItem {
+                collection_address,
+                funds_list,
+            })
+        })
+        .collect::<StdResult<Vec<ClaimedRewardsItem>>>()?;
+
+    let claim_list = CLAIMS
+        .prefix(&staker_address)
+        .range(deps.storage, None, end_bound, Order::Descending)
+        .take(limit)
+        .map(|x| {
+            let (id, claim) = x?;
+
+            Ok(ClaimItem {
+                id: Uint128::new(id),
+                claim,
+            })
+        })
+        .collect::<StdResult<Vec<ClaimItem>>>()?;
+
+    Ok(QueryClaimHistoryResponse {
+        staker_address,
+        claimed_list,
+        claim_list,
+    })
+}
+
+pub fn query_info(deps: Deps, _env: Env) -> StdResult<InfoResponse> {
+    Ok(InfoResponse {
+        info: get_contract_version(deps.storage)?,
+    })
+}
+
+pub fn query_dao(deps: Deps, _env: Env) -> StdResult<Addr> {
+    let Config { admin, owner, .. } = CONFIG.load(deps.storage)?;
+
+    Ok(owner.unwrap_or(admin))
+}
+
+pub fn query_hooks(deps: Deps, _env: Env) -> StdResult<Vec<Addr>> {
+    Ok(HOOKS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_receipt(deps: Deps, _env: Env, id: Uint128) -> StdResult<Receipt> {
+    RECEIPTS.load(deps.storage, id.u128())
+}
+
+pub fn query_revenue_split(deps: Deps, _env: Env) -> StdResult<Option<RevenueSplit<Addr>>> {
+    REVENUE_SPLIT.may_load(deps.storage)
+}
+
+pub fn query_distributions(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<QueryDistributionsResponseItem>> {
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start_bound = start_after.map(|x| Bound::exclusive(x.u128()));
+
+    DISTRIBUTIONS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(id, distribution)| QueryDistributionsResponseItem {
+                id: id.into(),
+                distribution,
+            })
+        })
+        .collect()
+}
+
+pub fn query_campaigns(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<QueryCampaignsResponseItem>> {
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start_bound = start_after.map(|x| Bound::exclusive(x.u128()));
+
+    CAMPAIGNS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(id, campaign)| QueryCampaignsResponseItem {
+                id: id.into(),
+                campaign,
+            })
+        })
+        .collect()
+}
+
+pub fn query_campaign_claim(
+    deps: Deps,
+    _env: Env,
+    id: Uint128,
+    address: String,
+) -> StdResult<Option<Uint128>> {
+    let address = deps.api.addr_validate(&address)?;
+
+    CAMPAIGN_CLAIMS.may_load(deps.storage, (id.u128(), &address))
+}
@@ -5,24 +5,39 @@ use cosmwasm_std::{
 };
 
 use cw20::Cw20ReceiveMsg;
+use cw721::Cw721ReceiveMsg;
 
 use gopstake_base::{
     error::ContractError,
-    staking_platform::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    staking_platform::{
+        msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+        state,
+    },
 };
 
 use crate::actions::{
     execute::{
-        try_accept_proposal, try_claim_staking_rewards, try_create_proposal, try_deposit_tokens,
-        try_distribute_funds, try_lock, try_reject_proposal, try_remove_collection, try_stake,
-        try_unlock, try_unstake, try_update_config, try_withdraw_tokens,
+        try_accept_proposal, try_add_hook, try_claim_campaign, try_claim_staking_rewards,
+        try_create_campaign, try_create_proposal, try_create_set, try_deposit_tokens,
+        try_distribute_bonus, try_distribute_funds, try_migrate_collection, try_pause_actions,
+        try_reclaim_campaign, try_refresh_collection_supply, try_reject_proposal,
+        try_remove_collection, try_remove_hook, try_remove_set, try_resume_actions,
+        try_return_migrated_nfts, try_stake, try_sync_receipts, try_unstake, try_unstake_receipt,
+        try_update_collection_protocol_fee, try_update_collection_vesting, try_update_config,
+        try_update_revenue_split, try_withdraw_tokens, try_withdraw_vested_rewards,
     },
     instantiate::try_instantiate,
     other::migrate_contract,
     query::{
-        query_associated_balances, query_collections, query_collections_balances, query_config,
-        query_funds, query_proposals, query_stakers, query_staking_rewards,
-        query_staking_rewards_per_collection,
+        query_active_sets, query_associated_balances, query_campaign_claim, query_campaigns,
+        query_claim_history, query_collection_migrations, query_collection_stats,
+        query_collection_total_at_height, query_collections, query_collections_balances,
+        query_config, query_dao, query_distributions, query_funds, query_hooks, query_info,
+        query_paused_actions, query_platform_stats, query_proposals, query_proposals_by_filter,
+        query_receipt, query_revenue_split, query_sets, query_staked_at_height,
+        query_staker_portfolio, query_stakers, query_staking_rewards,
+        query_staking_rewards_per_collection, query_total_power_at_height, query_vestings,
+        query_voting_power_at_height,
     },
 };
 
@@ -45,24 +60,88 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    if state::MIGRATION_PROGRESS.exists(deps.storage) {
+        Err(ContractError::MigrationIsInProgress)?;
+    }
+
     match msg {
         ExecuteMsg::Stake {
             collections_to_stake,
-        } => try_stake(deps, env, info, collections_to_stake),
+            mint_receipts,
+        } => try_stake(deps, env, info, collections_to_stake, mint_receipts),
         ExecuteMsg::Unstake {
             collections_to_unstake,
         } => try_unstake(deps, env, info, collections_to_unstake),
         ExecuteMsg::ClaimStakingRewards { collection } => {
             try_claim_staking_rewards(deps, env, info, collection)
         }
-        ExecuteMsg::UpdateConfig { owner, minter } => {
-            try_update_config(deps, env, info, owner, minter)
+        ExecuteMsg::SyncReceipts { start_after, limit } => {
+            try_sync_receipts(deps, env, info, start_after, limit)
         }
-        ExecuteMsg::Lock {} => try_lock(deps, env, info),
-        ExecuteMsg::Unlock {} => try_unlock(deps, env, info),
+        ExecuteMsg::WithdrawVestedRewards { start_after, limit } => {
+            try_withdraw_vested_rewards(deps, env, info, start_after, limit)
+        }
+        ExecuteMsg::UpdateConfig {
+            owner,
+            minter,
+            protocol_fee,
+            receipt_collection,
+        } => try_update_config(
+            deps,
+            env,
+            info,
+            owner,
+            minter,
+            protocol_fee,
+            receipt_collection,
+        ),
+        ExecuteMsg::AddHook { address } => try_add_hook(deps, env, info, address),
+        ExecuteMsg::RemoveHook { address } => try_remove_hook(deps, env, info, address),
+        ExecuteMsg::CreateSet { set } => try_create_set(deps, env, info, set),
+        ExecuteMsg::RemoveSet { id } => try_remove_set(deps, env, info, id),
+        ExecuteMsg::PauseActions {
+            action_groups,
+            collection_address,
+            reason,
+            expiration_date,
+        } => try_pause_actions(
+            deps,
+            env,
+            info,
+            action_groups,
+            collection_address,
+            reason,
+            expiration_date,
+        ),
+        ExecuteMsg::ResumeActions {
+            action_groups,
+            collection_address,
+        } => try_resume_actions(deps, env, info, action_groups, collection_address),
+        ExecuteMsg::UpdateCollectionProtocolFee {
+            collection_address,
+            protocol_fee,
+        } => try_update_collection_protocol_fee(deps, env, info, collection_address, protocol_fee),
+        ExecuteMsg::UpdateCollectionVesting {
+            collection_address,
+            vesting_policy,
+        } => try_update_collection_vesting(deps, env, info, collection_address, vesting_policy),
+        ExecuteMsg::RefreshCollectionSupply { collection_address } => {
+            try_refresh_collection_supply(deps, env, info, collection_address)
+        }
+        ExecuteMsg::UpdateRevenueSplit {
+            address_and_weight_list,
+            dust_recipient,
+        } => try_update_revenue_split(deps, env, info, address_and_weight_list, dust_recipient),
         ExecuteMsg::DistributeFunds {
             address_and_weight_list,
-        } => try_distribute_funds(deps, env, info, address_and_weight_list),
+            token_and_amount_list,
+        } => try_distribute_funds(
+            deps,
+            env,
+            info,
+            address_and_weight_list,
+            token_and_amount_list,
+        ),
         ExecuteMsg::RemoveCollection { address } => try_remove_collection(deps, env, info, address),
         ExecuteMsg::CreateProposal { proposal } => try_create_proposal(deps, env, info, proposal),
         ExecuteMsg::RejectProposal { id } => try_reject_proposal(deps, env, info, id),
@@ -74,6 +153,39 @@ pub fn execute(
             collection_address,
             amount,
         } => try_withdraw_tokens(deps, env, info, collection_address, amount),
+        ExecuteMsg::MigrateCollection {
+            collection_address,
+            limit,
+        } => try_migrate_collection(deps, env, info, collection_address, limit),
+        ExecuteMsg::ReturnMigratedNfts {
+            collection_address,
+            token_ids,
+            recipient,
+        } => try_return_migrated_nfts(deps, env, info, collection_address, token_ids, recipient),
+        ExecuteMsg::DistributeBonus { collection } => {
+            try_distribute_bonus(deps, env, info, collection, None, None)
+        }
+        ExecuteMsg::CreateCampaign {
+            collection_address,
+            merkle_root,
+            expiration_date,
+        } => try_create_campaign(
+            deps,
+            env,
+            info,
+            collection_address,
+            merkle_root,
+            expiration_date,
+            None,
+            None,
+        ),
+        ExecuteMsg::ClaimCampaign { id, amount, proof } => {
+            try_claim_campaign(deps, env, info, id, amount, proof)
+        }
+        ExecuteMsg::ReclaimCampaign { id } => try_reclaim_campaign(deps, env, info, id),
+        ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+            sender, token_id, ..
+        }) => try_unstake_receipt(deps, env, info, sender, token_id),
         ExecuteMsg::Receive(Cw20ReceiveMsg {
             sender,
             amount,
@@ -90,6 +202,23 @@ pub fn execute(
                 Some(sender),
                 Some(amount),
             ),
+            ExecuteMsg::DistributeBonus { collection } => {
+                try_distribute_bonus(deps, env, info, collection, Some(sender), Some(amount))
+            }
+            ExecuteMsg::CreateCampaign {
+                collection_address,
+                merkle_root,
+                expiration_date,
+            } => try_create_campaign(
+                deps,
+                env,
+                info,
+                collection_address,
+                merkle_root,
+                expiration_date,
+                Some(sender),
+                Some(amount),
+            ),
             _ => Err(ContractError::WrongMessageType)?,
         },
     }
@@ -116,12 +245,84 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::QueryProposals { last_amount } => {
             to_json_binary(&query_proposals(deps, env, last_amount)?)
         }
+        QueryMsg::QueryProposalsByFilter {
+            filter,
+            start_after,
+            limit,
+        } => to_json_binary(&query_proposals_by_filter(
+            deps,
+            env,
+            filter,
+            start_after,
+            limit,
+        )?),
         QueryMsg::QueryCollections { addresses } => {
             to_json_binary(&query_collections(deps, env, addresses)?)
         }
         QueryMsg::QueryCollectionsBalances { addresses } => {
             to_json_binary(&query_collections_balances(deps, env, addresses)?)
         }
+        QueryMsg::QueryCollectionMigrations {} => {
+            to_json_binary(&query_collection_migrations(deps, env)?)
+        }
+        QueryMsg::QueryPausedActions {} => to_json_binary(&query_paused_actions(deps, env)?),
+        QueryMsg::VotingPowerAtHeight {
+            address,
+            height,
+            collection,
+        } => to_json_binary(&query_voting_power_at_height(
+            deps, env, address, height, collection,
+        )?),
+        QueryMsg::TotalPowerAtHeight { height, collection } => {
+            to_json_binary(&query_total_power_at_height(deps, env, height, collection)?)
+        }
+        QueryMsg::Info {} => to_json_binary(&query_info(deps, env)?),
+        QueryMsg::Dao {} => to_json_binary(&query_dao(deps, env)?),
+        QueryMsg::QueryHooks {} => to_json_binary(&query_hooks(deps, env)?),
+        QueryMsg::QueryReceipt { id } => to_json_binary(&query_receipt(deps, env, id)?),
+        QueryMsg::QueryRevenueSplit {} => to_json_binary(&query_revenue_split(deps, env)?),
+        QueryMsg::QueryDistributions { start_after, limit } => {
+            to_json_binary(&query_distributions(deps, env, start_after, limit)?)
+        }
+        QueryMsg::QueryStakedAtHeight { address, height } => {
+            to_json_binary(&query_staked_at_height(deps, env, address, height)?)
+        }
+        QueryMsg::QueryCollectionTotalAtHeight { collection, height } => to_json_binary(
+            &query_collection_total_at_height(deps, env, collection, height)?,
+        ),
+        QueryMsg::QuerySets {} => to_json_binary(&query_sets(deps, env)?),
+        QueryMsg::QueryActiveSets { address } => {
+            to_json_binary(&query_active_sets(deps, env, address)?)
+        }
+        QueryMsg::QueryVestings {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_vestings(deps, env, address, start_after, limit)?),
+        QueryMsg::QueryStakerPortfolio { address } => {
+            to_json_binary(&query_staker_portfolio(deps, env, address)?)
+        }
+        QueryMsg::QueryPlatformStats {} => to_json_binary(&query_platform_stats(deps, env)?),
+        QueryMsg::QueryCollectionStats { address } => {
+            to_json_binary(&query_collection_stats(deps, env, address)?)
+        }
+        QueryMsg::QueryClaimHistory {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_claim_history(
+            deps,
+            env,
+            address,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryCampaigns { start_after, limit } => {
+            to_json_binary(&query_campaigns(deps, env, start_after, limit)?)
+        }
+        QueryMsg::QueryCampaignClaim { id, address } => {
+            to_json_binary(&query_campaign_claim(deps, env, id, address)?)
+        }
     }
 }
 
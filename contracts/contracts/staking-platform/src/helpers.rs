@@ -0,0 +1,641 @@
+use cosmwasm_std::{
+    Addr, BlockInfo, Decimal, Event, Order, StdError, StdResult, Storage, Timestamp, Uint128,
+};
+use cw_storage_plus::Bound;
+use sha2::{Digest, Sha256};
+
+use gopstake_base::{
+    assets::{Funds, Token},
+    constants::CLAIM_LOG_MAX_LENGTH,
+    math::{
+        calc_fixed_pool_index_increment, calc_reward_curve_index_increment, calc_staking_rewards,
+    },
+    staking_platform::{
+        events::{
+            ATTR_AMOUNT, ATTR_COLLECTION_ADDRESS, ATTR_EMISSION_TYPE, ATTR_PROPOSAL_ID,
+            ATTR_PROPOSAL_KIND, ATTR_SENDER, ATTR_STAKER, ATTR_STATUS_FROM, ATTR_STATUS_TO,
+            ATTR_TOKEN, ATTR_TOKEN_IDS, EMPTY_VALUE, EVENT_PROPOSAL, EVENT_REWARDS,
+        },
+        state::{
+            BONUS_INDEXES, BONUS_POSITIONS, CLAIMED_REWARDS, CLAIMS, CLAIM_COUNTER, COLLECTIONS,
+            COLLECTION_STAKED_NFTS, COLLECTION_STATS, COLLECTION_SUPPLIES,
+            COLLECTION_TOTAL_STAKED_NFTS, CONFIG, PAUSED_COLLECTIONS, PLATFORM_STATS,
+            PROTOCOL_FEES, REWARDS_CHECKPOINTS, REWARD_CURVES, STAKED_NFTS, TOTAL_STAKED_NFTS,
+        },
+        types::{
+            BonusPosition, ClaimRecord, Collection, EmissionType, Proposal, ProposalStatus,
+            RewardsCheckpoint, SetBonus, StakedTokenInfo, StakingSet,
+        },
+    },
+    utils::unwrap_field,
+};
+
+/// Rewards of paused collection are accumulated until pause date
+pub fn get_accrual_end_date(
+    storage: &dyn Storage,
+    collection_address: &Addr,
+    block_time: Timestamp,
+) -> StdResult<Timestamp> {
+    Ok(
+        match PAUSED_COLLECTIONS.may_load(storage, collection_address)? {
+            Some(pause_date) => pause_date.min(block_time),
+            None => block_time,
+        },
+    )
+}
+
+/// Date after which staked token accrues rewards and can be unstaked
+pub fn get_stake_unlock_date(
+    token: &StakedTokenInfo,
+    min_stake_duration: Option<u64>,
+) -> Option<Timestamp> {
+    token
+        .staking_start_date
+        .zip(min_stake_duration)
+        .map(|(staking_start_date, duration)| staking_start_date.plus_seconds(duration))
+}
+
+/// Rewards accrue from last claim date but not before min stake duration is passed
+pub fn get_accrual_start_date(
+    token: &StakedTokenInfo,
+    min_stake_duration: Option<u64>,
+) -> StdResult<Timestamp> {
+    let last_claim_date = unwrap_field(token.last_claim_date, "last_claim_date")?;
+
+    Ok(match get_stake_unlock_date(token, min_stake_duration) {
+        Some(unlock_date) => last_claim_date.max(unlock_date),
+        None => last_claim_date,
+    })
+}
+
+/// Returns staking rewards of single token accrued in specified period
+pub fn calc_token_rewards(
+    storage: &dyn Storage,
+    collection_address: &Addr,
+    collection: &Collection<Addr, Token>,
+    accrual_start_date: Timestamp,
+    accrual_end_date: Timestamp,
+) -> StdResult<Uint128> {
+    if collection.fixed_pool.is_none() && collection.reward_curve.is_none() {
+        return Ok(calc_staking_rewards(
+            collection.daily_rewards,
+            accrual_start_date,
+            accrual_end_date,
+        ));
+    }
+
+    if accrual_end_date <= accrual_start_date {
+        return Ok(Uint128::zero());
+    }
+
+    let index_diff = get_rewards_index(storage, collection_address, accrual_end_date)?
+        - get_rewards_index(storage, collection_address, accrual_start_date)?;
+
+    Ok(index_diff.to_uint_floor())
+}
+
+/// Returns cumulative fixed pool or reward curve rewards per staked NFT at specified date
+fn get_rewards_index(
+    storage: &dyn Storage,
+    collection_address: &Addr,
+    date: Timestamp,
+) -> StdResult<Decimal> {
+    let checkpoint = REWARDS_CHECKPOINTS
+        .prefix(collection_address)
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(date.nanos())),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?;
+
+    let Some((
+        checkpoint_date,
+        RewardsCheckpoint {
+            index,
+            total_staked,
+            fixed_pool,
+            reward_curve_version,
+            supply,
+        },
+    )) = checkpoint
+    else {
+        return Ok(Decimal::zero());
+    };
+    let reward_curve = reward_curve_version
+        .map(|x| REWARD_CURVES.load(storage, (collection_address, x)))
+        .transpose()?;
+
+    let time_diff_in_nanos = date.nanos() - checkpoint_date;
+
+    Ok(match (fixed_pool, reward_curve) {
+        (Some(x), _) => {
+            index + calc_fixed_pool_index_increment(&x, total_staked, time_diff_in_nanos)
+        }
+        (None, Some(x)) => {
+            index + calc_reward_curve_index_increment(&x, total_staked, supply, time_diff_in_nanos)
+        }
+        (None, None) => index,
+    })
+}
+
+/// Saves rewards index at block time, it must be called after changing total staked
+/// NFTs, supply, fixed pool or reward curve parameters or pause state of collection
+pub fn save_rewards_checkpoint(
+    storage: &mut dyn Storage,
+    collection_address: &Addr,
+    block_time: Timestamp,
+) -> StdResult<()> {
+    let (fixed_pool, reward_curve) = match COLLECTIONS.may_load(storage, collection_address)? {
+        Some(x) if !PAUSED_COLLECTIONS.has(storage, collection_address) => {
+            (x.fixed_pool, x.reward_curve)
+        }
+        _ => (None, None),
+    };
+    let is_checkpoint_found = !REWARDS_CHECKPOINTS
+        .prefix(collection_address)
+        .is_empty(storage);
+
+    if fixed_pool.is_none() && reward_curve.is_none() && !is_checkpoint_found {
+        return Ok(());
+    }
+
+    // curve is stored once per version instead of copying it to each checkpoint
+    let reward_curve_version = match reward_curve {
+        Some(reward_curve) => {
+            let last_version = REWARD_CURVES
+                .prefix(collection_address)
+                .range(storage, None, None, Order::Descending)
+                .next()
+                .transpose()?;
+
+            Some(match last_version {
+                Some((version, x)) if x == reward_curve => version,
+                _ => {
+                    REWARD_CURVES.save(
+                        storage,
+                        (collection_address, block_time.nanos()),
+                        &reward_curve,
+                    )?;
+                    block_time.nanos()
+                }
+            })
+        }
+        None => None,
+    };
+
+    let checkpoint = RewardsCheckpoint {
+        index: get_rewards_index(storage, collection_address, block_time)?,
+        total_staked: COLLECTION_TOTAL_STAKED_NFTS
+            .may_load(storage, collection_address)?
+            .unwrap_or_default(),
+        fixed_pool,
+        reward_curve_version,
+        supply: COLLECTION_SUPPLIES
+            .may_load(storage, collection_address)?
+            .unwrap_or_default()
+            .u128(),
+    };
+
+    REWARDS_CHECKPOINTS.save(
+        storage,
+        (collection_address, block_time.nanos()),
+        &checkpoint,
+    )
+}
+
+/// Decodes hex encoded sha256 hash
+pub fn decode_hash(hash: &str) -> StdResult<[u8; 32]> {
+    hex::decode(hash)
+        .ok()
+        .and_then(|x| x.try_into().ok())
+        .ok_or_else(|| StdError::generic_err("Wrong hash!"))
+}
+
+/// Checks if hashing leaf with proof hashes combined in sorted pairs results in
+/// merkle root, proof hashes and root are hex encoded
+pub fn verify_merkle_proof(merkle_root: &str, leaf: &str, proof: &[String]) -> StdResult<bool> {
+    let mut hash: [u8; 32] = Sha256::digest(leaf.as_bytes()).into();
+
+    for proof_hash in proof {
+        let proof_hash = decode_hash(proof_hash)?;
+        let pair = if hash <= proof_hash {
+            [hash, proof_hash]
+        } else {
+            [proof_hash, hash]
+        };
+
+        hash = Sha256::digest(pair.concat()).into();
+    }
+
+    Ok(hash == decode_hash(merkle_root)?)
+}
+
+/// Collection protocol fee is used if it's specified, otherwise - global one
+pub fn get_protocol_fee(storage: &dyn Storage, collection_address: &Addr) -> StdResult<Decimal> {
+    match PROTOCOL_FEES.may_load(storage, collection_address)? {
+        Some(protocol_fee) => Ok(protocol_fee),
+        None => Ok(CONFIG.load(storage)?.protocol_fee),
+    }
+}
+
+/// Protocol fee is taken from rewards of spending collections and is minted on top of
+/// rewards of minting collections. Returns amount to send to staker
+pub fn deduct_protocol_fee(
+    amount: Uint128,
+    protocol_fee_amount: Uint128,
+    emission_type: &EmissionType,
+) -> Uint128 {
+    match emission_type {
+        EmissionType::Spending => amount - protocol_fee_amount,
+        EmissionType::Minting => amount,
+    }
+}
+
+/// Returns set bonus accrued since specified date by paying collection before
+/// protocol fee. Removed collections don't pay bonuses
+pub fn get_set_bonus_list(
+    storage: &dyn Storage,
+    set: &StakingSet<Addr>,
+    accrual_start_date: Timestamp,
+    block_time: Timestamp,
+) -> StdResult<Vec<(Addr, Funds<Token>)>> {
+    let collection_and_daily_rewards_list = match &set.bonus {
+        SetBonus::Multiplier { value } => set
+            .collection_list
+            .iter()
+            .map(|x| -> StdResult<Option<(Addr, Decimal)>> {
+                Ok(COLLECTIONS.may_load(storage, x)?.map(|collection| {
+                    (
+                        x.to_owned(),
+                        collection.daily_rewards * (value - Decimal::one()),
+                    )
+                }))
+            })
+            .collect::<StdResult<Vec<Option<(Addr, Decimal)>>>>()?,
+        SetBonus::Flat {
+            collection_address,
+            daily_rewards,
+        } => vec![Some((
+            collection_address.to_owned(),
+            daily_rewards.to_owned(),
+        ))],
+    };
+
+    let mut bonus_list: Vec<(Addr, Funds<Token>)> = vec![];
+
+    for (collection_address, daily_rewards) in
+        collection_and_daily_rewards_list.into_iter().flatten()
+    {
+        let Some(collection) = COLLECTIONS.may_load(storage, &collection_address)? else {
+            continue;
+        };
+
+        let accrual_end_date = get_accrual_end_date(storage, &collection_address, block_time)?;
+        let amount = calc_staking_rewards(daily_rewards, accrual_start_date, accrual_end_date);
+
+        bonus_list.push((
+            collection_address,
+            Funds::new(amount, &collection.staking_currency),
+        ));
+    }
+
+    Ok(bonus_list)
+}
+
+/// Increases staked NFTs snapshots of staker and collection
+pub fn add_staked_nfts(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    staker: &Addr,
+    collection_address: &Addr,
+    amount: u128,
+) -> StdResult<()> {
+    update_staked_nfts(storage, block, staker, collection_address, |x| {
+        x.checked_add(amount)
+            .ok_or_else(|| StdError::generic_err("Staked NFTs amount overflow!"))
+    })
+}
+
+/// Decreases staked NFTs snapshots of staker and collection
+pub fn remove_staked_nfts(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    staker: &Addr,
+    collection_address: &Addr,
+    amount: u128,
+) -> StdResult<()> {
+    update_staked_nfts(storage, block, staker, collection_address, |x| {
+        // unstaking must not be blocked by inconsistent snapshots
+        Ok(x.saturating_sub(amount))
+    })
+}
+
+fn update_staked_nfts(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    staker: &Addr,
+    collection_address: &Addr,
+    action: impl Fn(u128) -> StdResult<u128>,
+) -> StdResult<()> {
+    let height = block.height;
+    let staked = STAKED_NFTS.may_load(storage, staker)?.unwrap_or_default();
+    let collection_staked = COLLECTION_STAKED_NFTS
+        .may_load(storage, (collection_address, staker))?
+        .unwrap_or_default();
+    // bonuses must be accrued before staked NFTs amount is changed
+    update_bonus_position(storage, staker, collection_address, collection_staked)?;
+    let staked_new = action(staked)?;
+    let collection_staked_new = action(collection_staked)?;
+
+    STAKED_NFTS.save(storage, staker, &staked_new, height)?;
+    COLLECTION_STAKED_NFTS.save(
+        storage,
+        (collection_address, staker),
+        &collection_staked_new,
+        height,
+    )?;
+    TOTAL_STAKED_NFTS.update(storage, height, |x| action(x.unwrap_or_default()))?;
+    COLLECTION_TOTAL_STAKED_NFTS.update(storage, collection_address, height, |x| {
+        action(x.unwrap_or_default())
+    })?;
+    save_rewards_checkpoint(storage, collection_address, block.time)?;
+
+    // unique stakers are counted when their staked NFTs amount leaves or reaches zero
+    let mut platform_stats = PLATFORM_STATS.may_load(storage)?.unwrap_or_default();
+    platform_stats.unique_stakers =
+        update_stakers_counter(platform_stats.unique_stakers, staked, staked_new);
+    PLATFORM_STATS.save(storage, &platform_stats)?;
+
+    let mut collection_stats = COLLECTION_STATS
+        .may_load(storage, collection_address)?
+        .unwrap_or_default();
+    collection_stats.unique_stakers = update_stakers_counter(
+        collection_stats.unique_stakers,
+        collection_staked,
+        collection_staked_new,
+    );
+    COLLECTION_STATS.save(storage, collection_address, &collection_stats)?;
+
+    Ok(())
+}
+
+fn update_stakers_counter(counter: Uint128, staked: u128, staked_new: u128) -> Uint128 {
+    match (staked, staked_new) {
+        (0, 1..) => counter + Uint128::one(),
+        (1.., 0) => counter.saturating_sub(Uint128::one()),
+        _ => counter,
+    }
+}
+
+/// Accrues collection bonuses distributed since last update of staker position
+fn update_bonus_position(
+    storage: &mut dyn Storage,
+    staker: &Addr,
+    collection_address: &Addr,
+    staked: u128,
+) -> StdResult<BonusPosition> {
+    let index_list = BONUS_INDEXES
+        .may_load(storage, collection_address)?
+        .unwrap_or_default();
+    let mut position = BONUS_POSITIONS
+        .may_load(storage, (staker, collection_address))?
+        .unwrap_or_default();
+
+    for (token, index) in &index_list {
+        let index_previous = position
+            .index_list
+            .iter()
+            .find(|(x, _)| x == token)
+            .map(|(_, x)| x.to_owned())
+            .unwrap_or_default();
+        let amount = Uint128::new(staked).mul_floor(*index - index_previous);
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        match position.pending_list.iter_mut().find(|(x, _)| x == token) {
+            Some((_, x)) => *x += amount,
+            None => position.pending_list.push((token.to_owned(), amount)),
+        };
+    }
+
+    position.index_list = index_list;
+    BONUS_POSITIONS.save(storage, (staker, collection_address), &position)?;
+
+    Ok(position)
+}
+
+/// Accrues collection bonuses and returns all pending ones resetting the position
+pub fn take_bonus_rewards(
+    storage: &mut dyn Storage,
+    staker: &Addr,
+    collection_address: &Addr,
+) -> StdResult<Vec<(Token, Uint128)>> {
+    let staked = COLLECTION_STAKED_NFTS
+        .may_load(storage, (collection_address, staker))?
+        .unwrap_or_default();
+    let BonusPosition {
+        index_list,
+        pending_list,
+    } = update_bonus_position(storage, staker, collection_address, staked)?;
+
+    if !pending_list.is_empty() {
+        BONUS_POSITIONS.save(
+            storage,
+            (staker, collection_address),
+            &BonusPosition {
+                index_list,
+                pending_list: vec![],
+            },
+        )?;
+    }
+
+    Ok(pending_list)
+}
+
+/// Updates counters of rewards claimed by stakers of collection and adds the claim to
+/// staker history
+pub fn add_claimed_rewards(
+    storage: &mut dyn Storage,
+    block_time: Timestamp,
+    staker: &Addr,
+    collection_address: &Addr,
+    funds: &Funds<Token>,
+    emission_type: &EmissionType,
+) -> StdResult<()> {
+    if funds.amount.is_zero() {
+        return Ok(());
+    }
+
+    let mut claimed_list = CLAIMED_REWARDS
+        .may_load(storage, (staker, collection_address))?
+        .unwrap_or_default();
+    add_to_list(&mut claimed_list, funds);
+    CLAIMED_REWARDS.save(storage, (staker, collection_address), &claimed_list)?;
+
+    // recent claims log is limited by dropping the oldest records
+    let claim_counter = CLAIM_COUNTER.may_load(storage)?.unwrap_or(1);
+    CLAIMS.save(
+        storage,
+        (staker, claim_counter),
+        &ClaimRecord {
+            collection_address: collection_address.to_owned(),
+            funds: funds.to_owned(),
+            emission_type: emission_type.to_owned(),
+            claim_date: block_time,
+        },
+    )?;
+    CLAIM_COUNTER.save(storage, &(claim_counter + 1))?;
+
+    let claim_id_list = CLAIMS
+        .prefix(staker)
+        .keys(storage, None, None, Order::Descending)
+        .skip(CLAIM_LOG_MAX_LENGTH as usize)
+        .collect::<StdResult<Vec<u128>>>()?;
+
+    for id in claim_id_list {
+        CLAIMS.remove(storage, (staker, id));
+    }
+
+    let mut collection_stats = COLLECTION_STATS
+        .may_load(storage, collection_address)?
+        .unwrap_or_default();
+    add_to_emission_list(&mut collection_stats.claimed_list, funds, emission_type);
+    COLLECTION_STATS.save(storage, collection_address, &collection_stats)?;
+
+    let mut platform_stats = PLATFORM_STATS.may_load(storage)?.unwrap_or_default();
+    add_to_emission_list(&mut platform_stats.claimed_list, funds, emission_type);
+    PLATFORM_STATS.save(storage, &platform_stats)?;
+
+    if let EmissionType::Minting = emission_type {
+        add_minted_tokens(storage, funds)?;
+    }
+
+    Ok(())
+}
+
+/// Updates counter of tokens minted via minter
+pub fn add_minted_tokens(storage: &mut dyn Storage, funds: &Funds<Token>) -> StdResult<()> {
+    let mut platform_stats = PLATFORM_STATS.may_load(storage)?.unwrap_or_default();
+    add_to_list(&mut platform_stats.minted_list, funds);
+    PLATFORM_STATS.save(storage, &platform_stats)
+}
+
+/// Updates counters of proposal prices paid for collection
+pub fn add_listing_revenue(
+    storage: &mut dyn Storage,
+    collection_address: &Addr,
+    funds: &Funds<Token>,
+) -> StdResult<()> {
+    let mut collection_stats = COLLECTION_STATS
+        .may_load(storage, collection_address)?
+        .unwrap_or_default();
+    add_to_list(&mut collection_stats.listing_revenue_list, funds);
+    COLLECTION_STATS.save(storage, collection_address, &collection_stats)?;
+
+    let mut platform_stats = PLATFORM_STATS.may_load(storage)?.unwrap_or_default();
+    add_to_list(&mut platform_stats.listing_revenue_list, funds);
+    PLATFORM_STATS.save(storage, &platform_stats)
+}
+
+fn add_to_list(funds_list: &mut Vec<Funds<Token>>, funds: &Funds<Token>) {
+    match funds_list
+        .iter_mut()
+        .find(|x| x.currency.token == funds.currency.token)
+    {
+        Some(x) => x.amount += funds.amount,
+        None => funds_list.push(funds.to_owned()),
+    };
+}
+
+fn add_to_emission_list(
+    funds_list: &mut Vec<(Funds<Token>, EmissionType)>,
+    funds: &Funds<Token>,
+    emission_type: &EmissionType,
+) {
+    match funds_list.iter_mut().find(|(x, emission)| {
+        (x.currency.token == funds.currency.token) && (emission == emission_type)
+    }) {
+        Some((x, _)) => x.amount += funds.amount,
+        None => funds_list.push((funds.to_owned(), emission_type.to_owned())),
+    };
+}
+
+/// Creates event of staking or unstaking NFTs of specified collection
+pub fn get_nft_event(
+    event_type: &str,
+    staker: &Addr,
+    collection_address: &Addr,
+    token_ids: &[Uint128],
+) -> Event {
+    let token_ids = token_ids
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    Event::new(event_type).add_attributes([
+        (ATTR_STAKER, staker.to_string()),
+        (ATTR_COLLECTION_ADDRESS, collection_address.to_string()),
+        (ATTR_TOKEN_IDS, token_ids),
+    ])
+}
+
+pub fn get_rewards_event(
+    staker: &Addr,
+    amount: Uint128,
+    token: &Token,
+    emission_type: &EmissionType,
+) -> Event {
+    Event::new(EVENT_REWARDS).add_attributes([
+        (ATTR_STAKER, staker.to_string()),
+        (ATTR_TOKEN, token.get_symbol()),
+        (ATTR_AMOUNT, amount.to_string()),
+        (ATTR_EMISSION_TYPE, emission_type.to_string()),
+    ])
+}
+
+/// Creates event of deposit or withdrawal of collection balance
+pub fn get_funds_event(
+    event_type: &str,
+    sender: &Addr,
+    collection_address: &Addr,
+    amount: Uint128,
+    token: &Token,
+) -> Event {
+    Event::new(event_type).add_attributes([
+        (ATTR_SENDER, sender.to_string()),
+        (ATTR_COLLECTION_ADDRESS, collection_address.to_string()),
+        (ATTR_TOKEN, token.get_symbol()),
+        (ATTR_AMOUNT, amount.to_string()),
+    ])
+}
+
+/// Creates event of proposal status transition, status_from is empty for new proposals
+pub fn get_proposal_event(
+    id: u128,
+    proposal: &Proposal<Addr, Token>,
+    status_from: Option<&ProposalStatus>,
+    status_to: &ProposalStatus,
+) -> Event {
+    Event::new(EVENT_PROPOSAL).add_attributes([
+        (ATTR_PROPOSAL_ID, id.to_string()),
+        (
+            ATTR_PROPOSAL_KIND,
+            proposal.proposal_type.get_kind().to_string(),
+        ),
+        (
+            ATTR_COLLECTION_ADDRESS,
+            proposal.proposal_type.get_collection_address().to_string(),
+        ),
+        (
+            ATTR_STATUS_FROM,
+            status_from.map_or(EMPTY_VALUE.to_string(), |x| x.to_string()),
+        ),
+        (ATTR_STATUS_TO, status_to.to_string()),
+    ])
+}
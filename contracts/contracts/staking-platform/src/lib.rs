@@ -1,4 +1,5 @@
 pub mod contract;
+pub mod helpers;
 
 pub mod actions {
     pub mod execute;
@@ -2,3 +2,13 @@ pub const DECIMALS_DEFAULT: u8 = 6;
 
 pub const MINS_PER_DAY: u128 = 24 * 60;
 pub const NANOS_PER_MIN: u128 = 60 * 1_000_000_000;
+
+pub const PAGINATION_DEFAULT_LIMIT: u32 = 10;
+pub const PAGINATION_MAX_LIMIT: u32 = 100;
+
+pub const MIGRATION_DEFAULT_LIMIT: u32 = 100;
+
+/// Max amount of recent claims stored per staker
+pub const CLAIM_LOG_MAX_LENGTH: u32 = 50;
+
+pub const CREATE_CW20_REPLY_ID: u64 = 1;
@@ -55,6 +55,15 @@ pub enum ContractError {
     #[error("Weight is out of range!")]
     WeightIsOutOfRange,
 
+    #[error("Revenue split is not found!")]
+    RevenueSplitIsNotFound,
+
+    #[error("Amount exceeds platform funds!")]
+    InsufficientFunds,
+
+    #[error("Protocol fee is out of range!")]
+    ProtocolFeeIsOutOfRange,
+
     #[error("Undefined Reply ID!")]
     UndefinedReplyId,
 
@@ -82,13 +91,115 @@ pub enum ContractError {
     #[error("This staking currency doesn't belong this owner!")]
     UnownedStakingCurrency,
 
-    #[error("The contract is temporary locked to stop bad guys")]
-    ContractIsLocked,
+    #[error("Action is paused! Reason: {reason}")]
+    ActionIsPaused { reason: String },
+
+    #[error("Expiration date must be later than current date!")]
+    ImproperExpirationDate,
+
+    #[error("Collection is paused!")]
+    CollectionIsPaused,
+
+    #[error("Collection is not paused!")]
+    CollectionIsNotPaused,
+
+    #[error("Wrong collection owner!")]
+    WrongCollectionOwner,
+
+    #[error("Proposal is already approved by sender!")]
+    ProposalIsAlreadyApproved,
+
+    #[error("Collection migration is in progress!")]
+    CollectionMigrationInProgress,
+
+    #[error("Collection migration is not found!")]
+    CollectionMigrationIsNotFound,
+
+    #[error("Migrated token is not found!")]
+    MigratedTokenIsNotFound,
+
+    #[error("Hook is already added!")]
+    HookIsAlreadyAdded,
+
+    #[error("Hook is not found!")]
+    HookIsNotFound,
+
+    #[error("Receipt collection is already set!")]
+    ReceiptCollectionIsAlreadySet,
+
+    #[error("Set is not found!")]
+    SetIsNotFound,
+
+    #[error("Wrong set parameters!")]
+    WrongSetParameters,
+
+    #[error("Collection is included in set!")]
+    CollectionIsInSet,
+
+    #[error("Wrong vesting policy!")]
+    WrongVestingPolicy,
+
+    #[error("There are no vested rewards!")]
+    VestedRewardsAreNotFound,
+
+    #[error("Contract name doesn't match!")]
+    WrongContractName,
+
+    #[error("Contract downgrade isn't allowed!")]
+    ContractDowngrade,
+
+    #[error("Migration is in progress!")]
+    MigrationIsInProgress,
+
+    #[error("Staked NFTs per address limit is exceeded!")]
+    StakedPerAddressLimitExceeded,
+
+    #[error("Total staked NFTs limit is exceeded!")]
+    TotalStakedLimitExceeded,
+
+    #[error("Min stake duration isn't passed!")]
+    MinStakeDurationIsNotPassed,
+
+    #[error("Restake cooldown isn't passed!")]
+    RestakeCooldownIsNotPassed,
+
+    #[error("There are no stakers in the collection!")]
+    StakersAreNotFound,
+
+    #[error("Wrong fixed pool parameters!")]
+    WrongFixedPool,
+
+    #[error("Hybrid emission is allowed only for spending collections!")]
+    WrongHybridEmission,
+
+    #[error("Wrong reward curve parameters!")]
+    WrongRewardCurve,
+
+    #[error("Campaign is not found!")]
+    CampaignIsNotFound,
+
+    #[error("Campaign is expired!")]
+    CampaignIsExpired,
+
+    #[error("Campaign is not expired!")]
+    CampaignIsNotExpired,
+
+    #[error("Campaign rewards are already claimed!")]
+    CampaignRewardsAreClaimed,
+
+    #[error("Campaign claim amount is zero!")]
+    CampaignClaimAmountIsZero,
+
+    #[error("Wrong merkle root!")]
+    WrongMerkleRoot,
+
+    #[error("Wrong merkle proof!")]
+    WrongMerkleProof,
 
     // minter
     #[error("Denom already exists!")]
     DenomExists,
 
-    #[error("Minter doesn't support CW20 tokens!")]
+    #[error("Operation isn't supported for CW20 tokens!")]
     WrongMinterTokenType,
 }
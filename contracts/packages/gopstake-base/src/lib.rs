@@ -5,6 +5,7 @@ pub mod minter {
 }
 
 pub mod staking_platform {
+    pub mod events;
     pub mod msg;
     pub mod state;
     pub mod types;
@@ -1,4 +1,107 @@
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
+use crate::{
+    constants::{MINS_PER_DAY, NANOS_PER_MIN},
+    converters::{dec256_to_dec, dec256_to_uint128, dec_to_dec256, u128_to_dec256},
+    staking_platform::types::{FixedPool, RewardCurve},
+};
+
 pub const P6: u128 = 1_000_000; // 1 asset with 6 decimals
 pub const P12: u128 = P6.pow(2); // 1_000_000 of assets with 6 decimals
 pub const P18: u128 = P6 * P12; // 1 of asset with 18 decimals
 pub const P24: u128 = P12.pow(2); // 1_000_000 of assets with 18 decimals
+
+/// Returns amount of staking rewards accumulated by single NFT in specified period
+pub fn calc_staking_rewards(
+    daily_rewards: Decimal,
+    last_claim_date: Timestamp,
+    accrual_end_date: Timestamp,
+) -> Uint128 {
+    if accrual_end_date <= last_claim_date {
+        return Uint128::zero();
+    }
+
+    let time_diff_in_nanos: u128 = (accrual_end_date.nanos() - last_claim_date.nanos()).into();
+    let time_diff_in_mins = u128_to_dec256(time_diff_in_nanos) / u128_to_dec256(NANOS_PER_MIN);
+
+    dec256_to_uint128(
+        time_diff_in_mins * dec_to_dec256(daily_rewards) / u128_to_dec256(MINS_PER_DAY),
+    )
+}
+
+/// Returns increase of fixed pool rewards per staked NFT for specified period
+pub fn calc_fixed_pool_index_increment(
+    fixed_pool: &FixedPool,
+    total_staked: u128,
+    time_diff_in_nanos: u64,
+) -> Decimal {
+    if total_staked == 0 || fixed_pool.epoch_duration == 0 {
+        return Decimal::zero();
+    }
+
+    let epoch_duration_in_nanos = u128::from(fixed_pool.epoch_duration) * 1_000_000_000;
+
+    dec256_to_dec(
+        dec_to_dec256(fixed_pool.epoch_rewards) * u128_to_dec256(u128::from(time_diff_in_nanos))
+            / u128_to_dec256(epoch_duration_in_nanos * total_staked),
+    )
+}
+
+/// Returns daily rewards per staked NFT for specified utilization interpolating
+/// reward curve breakpoints
+pub fn calc_reward_curve_rate(reward_curve: &RewardCurve, utilization: Decimal) -> Decimal {
+    let point_list = &reward_curve.point_list;
+
+    let (Some(first), Some(last)) = (point_list.first(), point_list.last()) else {
+        return Decimal::zero();
+    };
+
+    if utilization <= first.utilization {
+        return first.daily_rewards;
+    }
+
+    if utilization >= last.utilization {
+        return last.daily_rewards;
+    }
+
+    for (a, b) in point_list.iter().zip(point_list.iter().skip(1)) {
+        if utilization >= b.utilization {
+            continue;
+        }
+
+        let ratio = (utilization - a.utilization) / (b.utilization - a.utilization);
+
+        return if b.daily_rewards >= a.daily_rewards {
+            a.daily_rewards + (b.daily_rewards - a.daily_rewards) * ratio
+        } else {
+            a.daily_rewards - (a.daily_rewards - b.daily_rewards) * ratio
+        };
+    }
+
+    last.daily_rewards
+}
+
+/// Returns increase of reward curve rewards per staked NFT for specified period
+pub fn calc_reward_curve_index_increment(
+    reward_curve: &RewardCurve,
+    total_staked: u128,
+    supply: u128,
+    time_diff_in_nanos: u64,
+) -> Decimal {
+    let utilization = if supply == 0 {
+        Decimal::zero()
+    } else {
+        Decimal::from_ratio(total_staked, supply).min(Decimal::one())
+    };
+    let daily_rewards = calc_reward_curve_rate(reward_curve, utilization);
+
+    dec256_to_dec(
+        dec_to_dec256(daily_rewards) * u128_to_dec256(u128::from(time_diff_in_nanos))
+            / u128_to_dec256(NANOS_PER_MIN * MINS_PER_DAY),
+    )
+}
+
+/// Returns part of staking rewards accrued to platform funds
+pub fn calc_protocol_fee(rewards: Uint128, protocol_fee: Decimal) -> Uint128 {
+    rewards.mul_floor(protocol_fee)
+}
@@ -1,7 +1,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
 
-use crate::minter::types::Metadata;
+use crate::minter::types::{Cw20TokenInfo, Metadata};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -17,6 +17,12 @@ pub enum ExecuteMsg {
         subdenom: String,
     },
 
+    CreateCw20 {
+        token_owner: String,
+        cw20_code_id: u64,
+        token_info: Cw20TokenInfo,
+    },
+
     MintTokens {
         denom: String,
         amount: Uint128,
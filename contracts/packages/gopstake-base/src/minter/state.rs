@@ -1,4 +1,4 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Empty};
 use cw_storage_plus::{Item, Map};
 
 use crate::minter::types::Config;
@@ -8,3 +8,7 @@ pub const CONTRACT_NAME: &str = "crates.io:cryptogopniks-minter";
 pub const CONFIG: Item<Config> = Item::new("config");
 
 pub const TOKENS: Map<&Addr, Vec<String>> = Map::new("token by owner address");
+
+pub const CW20_TOKENS: Map<&Addr, Empty> = Map::new("cw20 token by address");
+
+pub const CW20_TOKEN_OWNER: Item<Addr> = Item::new("cw20 token owner");
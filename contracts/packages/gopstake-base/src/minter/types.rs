@@ -20,6 +20,13 @@ pub struct Metadata {
     pub uri_hash: Option<String>,
 }
 
+#[cw_serde]
+pub struct Cw20TokenInfo {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
 #[cw_serde]
 pub struct Config {
     pub admin: Addr,
@@ -0,0 +1,50 @@
+//! Event schema of the staking platform. Each event is emitted as `wasm-<event type>`
+//! along with the `action` attribute of the handler, token IDs are comma-separated
+
+/// NFTs are staked. Attributes: `staker`, `collection_address`, `token_ids`
+pub const EVENT_STAKE: &str = "gopstake_stake";
+/// NFTs are unstaked. Attributes: `staker`, `collection_address`, `token_ids`
+pub const EVENT_UNSTAKE: &str = "gopstake_unstake";
+/// Staking rewards are paid, one event per currency.
+/// Attributes: `staker`, `token`, `amount`, `emission_type`
+pub const EVENT_REWARDS: &str = "gopstake_rewards";
+/// Proposal status is changed, `status_from` is `none` for new proposals.
+/// Attributes: `proposal_id`, `proposal_kind`, `collection_address`, `status_from`, `status_to`
+pub const EVENT_PROPOSAL: &str = "gopstake_proposal";
+/// Collection balance is deposited.
+/// Attributes: `sender`, `collection_address`, `token`, `amount`
+pub const EVENT_DEPOSIT: &str = "gopstake_deposit";
+/// Collection balance is withdrawn.
+/// Attributes: `sender`, `collection_address`, `token`, `amount`
+pub const EVENT_WITHDRAW: &str = "gopstake_withdraw";
+/// Bonus is distributed to current stakers of collection.
+/// Attributes: `sender`, `collection_address`, `token`, `amount`
+pub const EVENT_BONUS: &str = "gopstake_bonus";
+/// Merkle distributor campaign is created.
+/// Attributes: `sender`, `collection_address`, `token`, `amount`, `campaign_id`
+pub const EVENT_CAMPAIGN: &str = "gopstake_campaign";
+/// Campaign rewards are claimed.
+/// Attributes: `sender`, `collection_address`, `token`, `amount`, `campaign_id`
+pub const EVENT_CAMPAIGN_CLAIM: &str = "gopstake_campaign_claim";
+/// Unclaimed funds of expired campaign are sent back to its creator.
+/// Attributes: `sender`, `collection_address`, `token`, `amount`, `campaign_id`
+pub const EVENT_CAMPAIGN_RECLAIM: &str = "gopstake_campaign_reclaim";
+/// Config is updated. Each changed field is emitted as `<field>` with new value and
+/// `previous_<field>` with old one, unset values are `none`
+pub const EVENT_UPDATE_CONFIG: &str = "gopstake_update_config";
+
+pub const ATTR_STAKER: &str = "staker";
+pub const ATTR_SENDER: &str = "sender";
+pub const ATTR_COLLECTION_ADDRESS: &str = "collection_address";
+pub const ATTR_TOKEN_IDS: &str = "token_ids";
+pub const ATTR_TOKEN: &str = "token";
+pub const ATTR_AMOUNT: &str = "amount";
+pub const ATTR_EMISSION_TYPE: &str = "emission_type";
+pub const ATTR_PROPOSAL_ID: &str = "proposal_id";
+pub const ATTR_PROPOSAL_KIND: &str = "proposal_kind";
+pub const ATTR_STATUS_FROM: &str = "status_from";
+pub const ATTR_STATUS_TO: &str = "status_to";
+pub const ATTR_CAMPAIGN_ID: &str = "campaign_id";
+
+pub const PREVIOUS_VALUE_PREFIX: &str = "previous_";
+pub const EMPTY_VALUE: &str = "none";
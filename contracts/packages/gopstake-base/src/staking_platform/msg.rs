@@ -1,11 +1,16 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
 
 use cw20::Cw20ReceiveMsg;
+use cw721::Cw721ReceiveMsg;
 
 use crate::{
     assets::{Funds, Token, TokenUnverified},
-    staking_platform::types::{Collection, Proposal, StakedCollectionInfo},
+    staking_platform::types::{
+        ActionGroup, Campaign, ClaimRecord, Collection, CollectionMigration, Distribution,
+        EmissionType, Proposal, ProposalFilter, StakeChangedHookMsg, StakedCollectionInfo,
+        StakedTokenInfo, StakingSet, TokenTimeLock, Vesting, VestingPolicy,
+    },
 };
 
 #[cw_serde]
@@ -15,10 +20,14 @@ pub struct InstantiateMsg {
 }
 
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     // stakers
+    /// Mints receipt NFT for each staked token if mint_receipts is true. Receipt holder
+    /// owns the position and its unclaimed rewards
     Stake {
         collections_to_stake: Vec<StakedCollectionInfo<String>>,
+        mint_receipts: Option<bool>,
     },
 
     Unstake {
@@ -29,19 +38,93 @@ pub enum ExecuteMsg {
         collection: Option<String>,
     },
 
+    /// Moves positions of receipts held by sender to sender. Claiming and unstaking sync
+    /// only first page of receipts, so holders of more receipts can sync the rest by pages
+    SyncReceipts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Sends vested rewards of a page of vesting schedules of sender
+    WithdrawVestedRewards {
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+
     // admin
     UpdateConfig {
         owner: Option<String>,
         minter: Option<String>,
+        protocol_fee: Option<Decimal>,
+        /// cw721 with platform as minter, it can be set only once
+        receipt_collection: Option<String>,
+    },
+
+    AddHook {
+        address: String,
+    },
+
+    RemoveHook {
+        address: String,
+    },
+
+    /// Bonus accrues to stakers of all set collections starting from set creation
+    /// or completion of the set
+    CreateSet {
+        set: StakingSet<String>,
+    },
+
+    /// Accrued bonuses of the set are paid to its stakers, claiming of set collections
+    /// must be active
+    RemoveSet {
+        id: Uint128,
     },
 
     // admin, owner
-    Lock {},
+    PauseActions {
+        action_groups: Vec<ActionGroup>,
+        collection_address: Option<String>,
+        reason: String,
+        expiration_date: Option<Timestamp>,
+    },
 
-    Unlock {},
+    ResumeActions {
+        action_groups: Vec<ActionGroup>,
+        collection_address: Option<String>,
+    },
 
-    DistributeFunds {
+    /// Sets protocol fee for specified collection or resets it to global one if
+    /// protocol_fee isn't specified
+    UpdateCollectionProtocolFee {
+        collection_address: String,
+        protocol_fee: Option<Decimal>,
+    },
+
+    /// Sets vesting policy applied to rewards claimed after the update or removes it
+    /// if vesting_policy isn't specified
+    UpdateCollectionVesting {
+        collection_address: String,
+        vesting_policy: Option<VestingPolicy>,
+    },
+
+    /// Queries amount of NFTs from cw721 contract to update utilization used by
+    /// collection reward curve
+    RefreshCollectionSupply {
+        collection_address: String,
+    },
+
+    /// Sets recipients of platform funds used by DistributeFunds by default
+    UpdateRevenueSplit {
         address_and_weight_list: Vec<(String, Decimal)>,
+        dust_recipient: Option<String>,
+    },
+
+    /// Distributes platform funds using stored revenue split if address_and_weight_list
+    /// isn't specified. Whole funds are distributed if token_and_amount_list isn't
+    /// specified, whole token balance is distributed if its amount isn't specified
+    DistributeFunds {
+        address_and_weight_list: Option<Vec<(String, Decimal)>>,
+        token_and_amount_list: Option<Vec<(TokenUnverified, Option<Uint128>)>>,
     },
 
     RemoveCollection {
@@ -70,7 +153,51 @@ pub enum ExecuteMsg {
         amount: Uint128,
     },
 
+    /// Continues migration of stakers positions to new collection address
+    MigrateCollection {
+        collection_address: String,
+        limit: Option<u32>,
+    },
+
+    /// Returns old NFTs left on the platform after completed collection migration.
+    /// Only tokens of migrated positions are accepted
+    ReturnMigratedNfts {
+        collection_address: String,
+        token_ids: Vec<Uint128>,
+        recipient: String,
+    },
+
+    /// Distributes sent funds to current stakers of collection pro rata to staked NFTs,
+    /// stakers get the bonus claiming staking rewards
+    DistributeBonus {
+        collection: String,
+    },
+
+    /// Locks sent funds in merkle distributor campaign, campaign is created by admin or
+    /// collection owner
+    CreateCampaign {
+        collection_address: String,
+        merkle_root: String,
+        expiration_date: Timestamp,
+    },
+
+    /// Claims amount specified in campaign merkle tree leaf of sender
+    ClaimCampaign {
+        id: Uint128,
+        amount: Uint128,
+        proof: Vec<String>,
+    },
+
+    /// Sends unclaimed funds of expired campaign back to its creator
+    ReclaimCampaign {
+        id: Uint128,
+    },
+
     Receive(Cw20ReceiveMsg),
+
+    /// Receipt sent with SendNft unstakes its token to receipt holder, so the receipt
+    /// doesn't have to be approved to the platform
+    ReceiveNft(Cw721ReceiveMsg),
 }
 
 #[cw_serde]
@@ -97,17 +224,136 @@ pub enum QueryMsg {
     #[returns(Vec<QueryProposalsResponseItem>)]
     QueryProposals { last_amount: Option<Uint128> },
 
+    #[returns(Vec<QueryProposalsResponseItem>)]
+    QueryProposalsByFilter {
+        filter: ProposalFilter<String>,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+
     #[returns(Vec<QueryCollectionsResponseItem>)]
     QueryCollections { addresses: Option<Vec<String>> },
 
     #[returns(Vec<QueryCollectionsBalancesResponseItem>)]
     QueryCollectionsBalances { addresses: Option<Vec<String>> },
+
+    #[returns(Vec<QueryCollectionMigrationsResponseItem>)]
+    QueryCollectionMigrations {},
+
+    #[returns(Vec<crate::staking_platform::types::ActionPause>)]
+    QueryPausedActions {},
+
+    /// Voting power of staker equal to amount of NFTs staked before specified height
+    /// in specified collection or in all collections. It's compatible with DAO DAO
+    /// voting module interface as well as TotalPowerAtHeight, Info and Dao
+    #[returns(VotingPowerAtHeightResponse)]
+    VotingPowerAtHeight {
+        address: String,
+        height: Option<u64>,
+        collection: Option<String>,
+    },
+
+    #[returns(TotalPowerAtHeightResponse)]
+    TotalPowerAtHeight {
+        height: Option<u64>,
+        collection: Option<String>,
+    },
+
+    #[returns(InfoResponse)]
+    Info {},
+
+    /// DAO using the platform as voting module must be set as its owner, admin is
+    /// returned if owner isn't set
+    #[returns(Addr)]
+    Dao {},
+
+    #[returns(Vec<Addr>)]
+    QueryHooks {},
+
+    #[returns(crate::staking_platform::types::Receipt)]
+    QueryReceipt { id: Uint128 },
+
+    #[returns(Option<crate::staking_platform::types::RevenueSplit<Addr>>)]
+    QueryRevenueSplit {},
+
+    #[returns(Vec<QueryDistributionsResponseItem>)]
+    QueryDistributions {
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+
+    /// Amounts of NFTs staked by address in each collection before specified height,
+    /// current height is used by default
+    #[returns(QueryStakedAtHeightResponse)]
+    QueryStakedAtHeight {
+        address: String,
+        height: Option<u64>,
+    },
+
+    #[returns(QueryCollectionTotalAtHeightResponse)]
+    QueryCollectionTotalAtHeight {
+        collection: String,
+        height: Option<u64>,
+    },
+
+    #[returns(Vec<QuerySetsResponseItem>)]
+    QuerySets {},
+
+    /// Sets completed by staker with bonuses accrued since last claim
+    #[returns(Vec<QueryActiveSetsResponseItem>)]
+    QueryActiveSets { address: String },
+
+    #[returns(Vec<QueryVestingsResponseItem>)]
+    QueryVestings {
+        address: String,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+
+    /// Staked NFTs, pending rewards and wallet balances of staker in single query
+    #[returns(QueryStakerPortfolioResponse)]
+    QueryStakerPortfolio { address: String },
+
+    #[returns(QueryPlatformStatsResponse)]
+    QueryPlatformStats {},
+
+    #[returns(QueryCollectionStatsResponse)]
+    QueryCollectionStats { address: String },
+
+    /// Cumulative claimed rewards and recent claims of staker, claims are sorted from
+    /// newest to oldest
+    #[returns(QueryClaimHistoryResponse)]
+    QueryClaimHistory {
+        address: String,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+
+    #[returns(Vec<QueryCampaignsResponseItem>)]
+    QueryCampaigns {
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+
+    /// Amount claimed by address from campaign, None if it isn't claimed
+    #[returns(Option<Uint128>)]
+    QueryCampaignClaim { id: Uint128, address: String },
+}
+
+/// Message which must be supported by contracts registered as hooks
+#[cw_serde]
+pub enum StakeChangedExecuteMsg {
+    StakeChangedHook(StakeChangedHookMsg),
 }
 
 #[cw_serde]
 pub struct QueryStakersResponseItem {
     pub staker_address: Addr,
     pub staked_collection_info_list: Vec<StakedCollectionInfo<Addr>>,
+    /// Staked tokens which min stake duration isn't passed
+    pub stake_lock_list: Vec<TokenTimeLock>,
+    /// Unstaked tokens which can't be staked again yet
+    pub restake_cooldown_list: Vec<TokenTimeLock>,
 }
 
 #[cw_serde]
@@ -126,6 +372,12 @@ pub struct QueryProposalsResponseItem {
 pub struct QueryCollectionsResponseItem {
     pub address: Addr,
     pub collection: Collection<Addr, Token>,
+    pub pause_date: Option<Timestamp>,
+    /// Protocol fee applied to staking rewards of the collection
+    pub protocol_fee: Decimal,
+    pub vesting_policy: Option<VestingPolicy>,
+    /// Amount of currently staked NFTs of the collection
+    pub total_staked: Uint128,
 }
 
 #[cw_serde]
@@ -134,7 +386,144 @@ pub struct QueryCollectionsBalancesResponseItem {
     pub funds: Funds<Token>,
 }
 
+#[cw_serde]
+pub struct QueryCollectionMigrationsResponseItem {
+    pub collection_address: Addr,
+    pub migration: CollectionMigration,
+}
+
+#[cw_serde]
+pub struct QueryDistributionsResponseItem {
+    pub id: Uint128,
+    pub distribution: Distribution,
+}
+
+#[cw_serde]
+pub struct QueryCampaignsResponseItem {
+    pub id: Uint128,
+    pub campaign: Campaign,
+}
+
+#[cw_serde]
+pub struct VotingPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct TotalPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct QuerySetsResponseItem {
+    pub id: Uint128,
+    pub set: StakingSet<Addr>,
+}
+
+#[cw_serde]
+pub struct QueryActiveSetsResponseItem {
+    pub id: Uint128,
+    pub set: StakingSet<Addr>,
+    pub accrual_start_date: Timestamp,
+    /// Accrued bonus before protocol fee
+    pub bonus_list: Vec<Funds<Token>>,
+}
+
+#[cw_serde]
+pub struct QueryVestingsResponseItem {
+    pub id: Uint128,
+    pub vesting: Vesting,
+    pub withdrawable_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct QueryStakerPortfolioResponse {
+    pub staker_address: Addr,
+    pub collection_list: Vec<PortfolioCollectionItem>,
+    /// Pending rewards of all collections by currency and emission type
+    pub rewards_list: Vec<(Funds<Token>, EmissionType)>,
+    /// Staker wallet balances of tokens used as staking rewards
+    pub balance_list: Vec<Funds<Token>>,
+    pub total_staked: Uint128,
+}
+
+#[cw_serde]
+pub struct PortfolioCollectionItem {
+    pub collection_address: Addr,
+    pub collection: Collection<Addr, Token>,
+    pub token_list: Vec<PortfolioTokenItem>,
+    /// Pending rewards of the collection tokens
+    pub rewards: Funds<Token>,
+}
+
+#[cw_serde]
+pub struct PortfolioTokenItem {
+    pub staked_token_info: StakedTokenInfo,
+    /// Pending rewards after protocol fee
+    pub rewards: Uint128,
+}
+
+#[cw_serde]
+pub struct QueryPlatformStatsResponse {
+    pub total_staked: Uint128,
+    pub unique_stakers: Uint128,
+    pub claimed_list: Vec<(Funds<Token>, EmissionType)>,
+    pub minted_list: Vec<Funds<Token>>,
+    pub listing_revenue_list: Vec<Funds<Token>>,
+}
+
+#[cw_serde]
+pub struct QueryCollectionStatsResponse {
+    pub collection_address: Addr,
+    pub total_staked: Uint128,
+    pub unique_stakers: Uint128,
+    pub claimed_list: Vec<(Funds<Token>, EmissionType)>,
+    pub listing_revenue_list: Vec<Funds<Token>>,
+}
+
+#[cw_serde]
+pub struct QueryClaimHistoryResponse {
+    pub staker_address: Addr,
+    pub claimed_list: Vec<ClaimedRewardsItem>,
+    pub claim_list: Vec<ClaimItem>,
+}
+
+#[cw_serde]
+pub struct ClaimedRewardsItem {
+    pub collection_address: Addr,
+    pub funds_list: Vec<Funds<Token>>,
+}
+
+#[cw_serde]
+pub struct ClaimItem {
+    pub id: Uint128,
+    pub claim: ClaimRecord,
+}
+
+#[cw_serde]
+pub struct QueryStakedAtHeightResponse {
+    pub height: u64,
+    pub total: Uint128,
+    pub collection_and_amount_list: Vec<(Addr, Uint128)>,
+}
+
+#[cw_serde]
+pub struct QueryCollectionTotalAtHeightResponse {
+    pub height: u64,
+    pub total: Uint128,
+}
+
+#[cw_serde]
+pub struct InfoResponse {
+    pub info: cw2::ContractVersion,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {
     pub version: String,
+    /// Max items of a map processed by a migration step in one call. Unfinished migration
+    /// is continued by migrating again to the same code
+    pub limit: Option<u32>,
 }
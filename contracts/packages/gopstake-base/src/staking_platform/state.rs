@@ -1,20 +1,201 @@
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Decimal, Empty, Timestamp, Uint128};
+use cw_storage_plus::{
+    Index, IndexList, IndexedMap, Item, Map, MultiIndex, SnapshotItem, SnapshotMap, Strategy,
+};
 
 use crate::{
     assets::{Funds, Token},
-    staking_platform::types::{Collection, Config, Proposal, StakedCollectionInfo},
+    staking_platform::types::{
+        ActionPause, BonusPosition, Campaign, ClaimRecord, Collection, CollectionMigration,
+        CollectionStats, Config, Distribution, EmissionType, MigrationProgress, PlatformStats,
+        Proposal, Receipt, RestakeCooldown, RevenueSplit, RewardCurve, RewardsCheckpoint,
+        StakedCollectionInfo, StakingSet, Vesting, VestingPolicy,
+    },
 };
 
 pub const CONTRACT_NAME: &str = "crates.io:cryptogopniks-staking-platform";
 
-pub const IS_LOCKED: Item<bool> = Item::new("is_locked");
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const FUNDS: Item<Vec<Funds<Token>>> = Item::new("funds");
 pub const PROPOSAL_COUNTER: Item<u128> = Item::new("proposal counter");
+/// Contracts receiving StakeChangedHook messages
+pub const HOOKS: Item<Vec<Addr>> = Item::new("hooks");
+pub const REVENUE_SPLIT: Item<RevenueSplit<Addr>> = Item::new("revenue split");
+pub const DISTRIBUTION_COUNTER: Item<u128> = Item::new("distribution counter");
+pub const RECEIPT_COUNTER: Item<u128> = Item::new("receipt counter");
+pub const SET_COUNTER: Item<u128> = Item::new("set counter");
+pub const VESTING_COUNTER: Item<u128> = Item::new("vesting counter");
+pub const CLAIM_COUNTER: Item<u128> = Item::new("claim counter");
+pub const CAMPAIGN_COUNTER: Item<u128> = Item::new("campaign counter");
+/// Progress of unfinished contract migration
+pub const MIGRATION_PROGRESS: Item<MigrationProgress> = Item::new("migration progress");
 
-pub const PROPOSALS: Map<u128, Proposal<Addr, Token>> = Map::new("proposal by id");
+pub const PROPOSALS: IndexedMap<u128, Proposal<Addr, Token>, ProposalIndexes> = IndexedMap::new(
+    "proposal by id",
+    ProposalIndexes {
+        status: MultiIndex::new(
+            |_pk, proposal| proposal.get_status_name(),
+            "proposal by id",
+            "proposal by id__status",
+        ),
+        collection_address: MultiIndex::new(
+            |_pk, proposal| proposal.proposal_type.get_collection_address().to_owned(),
+            "proposal by id",
+            "proposal by id__collection_address",
+        ),
+        collection_owner: MultiIndex::new(
+            |_pk, proposal| proposal.proposal_type.get_collection_owner().to_owned(),
+            "proposal by id",
+            "proposal by id__collection_owner",
+        ),
+        proposal_kind: MultiIndex::new(
+            |_pk, proposal| proposal.proposal_type.get_kind().to_string(),
+            "proposal by id",
+            "proposal by id__proposal_kind",
+        ),
+    },
+);
 pub const COLLECTIONS: Map<&Addr, Collection<Addr, Token>> = Map::new("collection by address");
 pub const COLLECTIONS_BALANCES: Map<&Addr, Funds<Token>> =
     Map::new("collection balance by address");
 pub const STAKERS: Map<&Addr, Vec<StakedCollectionInfo<Addr>>> = Map::new("staker by address");
+pub const PAUSED_COLLECTIONS: Map<&Addr, Timestamp> = Map::new("pause date by collection address");
+/// Protocol fees overriding global one by collection address
+pub const PROTOCOL_FEES: Map<&Addr, Decimal> = Map::new("protocol fee by collection address");
+pub const VESTING_POLICIES: Map<&Addr, VestingPolicy> =
+    Map::new("vesting policy by collection address");
+/// Vesting schedules of claimed rewards by (staker address, vesting id)
+pub const VESTINGS: Map<(&Addr, u128), Vesting> = Map::new("vesting by staker and id");
+/// Paused action groups by (action group name, collection address), empty collection
+/// address means pause for all collections
+pub const PAUSED_ACTIONS: Map<(String, String), ActionPause> =
+    Map::new("action pause by group and collection address");
+/// Unfinished collection migrations by old collection address
+pub const COLLECTION_MIGRATIONS: Map<&Addr, CollectionMigration> =
+    Map::new("migration by old collection address");
+/// New token IDs of migrating collection by (old collection address, old token ID)
+pub const TOKEN_ID_MAPPINGS: Map<(&Addr, u128), Uint128> =
+    Map::new("new token id by old collection address and old token id");
+/// Old NFTs left on the platform by migrations by (old collection address, old token ID)
+pub const MIGRATED_TOKENS: Map<(&Addr, u128), Empty> =
+    Map::new("migrated token by old collection address and token id");
+/// Rewards of NFTs unstaked while claiming was paused by (staker address, collection address)
+pub const PENDING_REWARDS: Map<(&Addr, &Addr), (Funds<Token>, EmissionType)> =
+    Map::new("pending rewards by staker and collection address");
+/// Bonuses of sets broken while claiming was paused by (staker address, collection address)
+pub const PENDING_SET_BONUSES: Map<(&Addr, &Addr), (Funds<Token>, EmissionType)> =
+    Map::new("pending set bonus by staker and collection address");
+pub const DISTRIBUTIONS: Map<u128, Distribution> = Map::new("distribution by id");
+pub const CAMPAIGNS: Map<u128, Campaign> = Map::new("campaign by id");
+/// Claimed amounts by (campaign id, claimer address)
+pub const CAMPAIGN_CLAIMS: Map<(u128, &Addr), Uint128> =
+    Map::new("campaign claim by id and address");
+pub const RECEIPTS: Map<u128, Receipt> = Map::new("receipt by id");
+pub const RECEIPT_IDS: Map<(&Addr, u128), u128> =
+    Map::new("receipt id by collection address and token id");
+/// Restake cooldowns of unstaked NFTs by (collection address, token ID) indexed by staker
+pub const RESTAKE_COOLDOWNS: IndexedMap<(&Addr, u128), RestakeCooldown, RestakeCooldownIndexes> =
+    IndexedMap::new(
+        "restake cooldown by collection and token id",
+        RestakeCooldownIndexes {
+            staker: MultiIndex::new(
+                |_pk, cooldown| cooldown.staker.to_owned(),
+                "restake cooldown by collection and token id",
+                "restake cooldown by collection and token id__staker",
+            ),
+        },
+    );
+/// Height snapshots of staked NFTs amount used as voting power
+pub const STAKED_NFTS: SnapshotMap<&Addr, u128> = SnapshotMap::new(
+    "staked nfts by staker",
+    "staked nfts by staker__checkpoints",
+    "staked nfts by staker__changelog",
+    Strategy::EveryBlock,
+);
+pub const COLLECTION_STAKED_NFTS: SnapshotMap<(&Addr, &Addr), u128> = SnapshotMap::new(
+    "staked nfts by collection and staker",
+    "staked nfts by collection and staker__checkpoints",
+    "staked nfts by collection and staker__changelog",
+    Strategy::EveryBlock,
+);
+pub const TOTAL_STAKED_NFTS: SnapshotItem<u128> = SnapshotItem::new(
+    "total staked nfts",
+    "total staked nfts__checkpoints",
+    "total staked nfts__changelog",
+    Strategy::EveryBlock,
+);
+pub const COLLECTION_TOTAL_STAKED_NFTS: SnapshotMap<&Addr, u128> = SnapshotMap::new(
+    "total staked nfts by collection",
+    "total staked nfts by collection__checkpoints",
+    "total staked nfts by collection__changelog",
+    Strategy::EveryBlock,
+);
+pub const SETS: Map<u128, StakingSet<Addr>> = Map::new("set by id");
+/// Bonus accrual start dates of sets completed by staker
+pub const ACTIVE_SETS: Map<(&Addr, u128), Timestamp> =
+    Map::new("bonus accrual start date by staker and set id");
+/// Stakers completed the set by (set id, staker address)
+pub const SET_STAKERS: Map<(u128, &Addr), Empty> = Map::new("staker by set id");
+/// Sets including the collection by (collection address, set id)
+pub const COLLECTION_SETS: Map<(&Addr, u128), Empty> = Map::new("set by collection address and id");
+/// Addresses accepted the proposal requiring multiple approvals
+pub const PROPOSAL_APPROVALS: Map<u128, Vec<Addr>> = Map::new("approvals by proposal id");
+/// Running counters updated on staking, claiming and accepting proposals
+pub const PLATFORM_STATS: Item<PlatformStats> = Item::new("platform stats");
+pub const COLLECTION_STATS: Map<&Addr, CollectionStats> = Map::new("stats by collection address");
+/// Cumulative rewards claimed by (staker address, collection address)
+pub const CLAIMED_REWARDS: Map<(&Addr, &Addr), Vec<Funds<Token>>> =
+    Map::new("claimed rewards by staker and collection address");
+/// Recent claims by (staker address, claim id)
+pub const CLAIMS: Map<(&Addr, u128), ClaimRecord> = Map::new("claim by staker and id");
+/// Cumulative bonuses per staked NFT by collection address
+pub const BONUS_INDEXES: Map<&Addr, Vec<(Token, Decimal)>> =
+    Map::new("bonus index by collection address");
+/// Bonus accrual positions by (staker address, collection address)
+pub const BONUS_POSITIONS: Map<(&Addr, &Addr), BonusPosition> =
+    Map::new("bonus position by staker and collection address");
+
+/// Fixed pool and reward curve checkpoints by (collection address, date in nanoseconds)
+pub const REWARDS_CHECKPOINTS: Map<(&Addr, u64), RewardsCheckpoint> =
+    Map::new("rewards checkpoint by collection address and date");
+/// Reward curves referenced by checkpoints by (collection address, version date in nanoseconds)
+pub const REWARD_CURVES: Map<(&Addr, u64), RewardCurve> =
+    Map::new("reward curve by collection address and version");
+/// Amount of NFTs of collection with reward curve queried from cw721 contract
+pub const COLLECTION_SUPPLIES: Map<&Addr, Uint128> = Map::new("supply by collection address");
+/// Amount of tokens minted by hybrid emission by collection address
+pub const HYBRID_MINTED: Map<&Addr, Uint128> = Map::new("hybrid minted by collection address");
+
+pub struct ProposalIndexes<'a> {
+    pub status: MultiIndex<'a, String, Proposal<Addr, Token>, u128>,
+    pub collection_address: MultiIndex<'a, Addr, Proposal<Addr, Token>, u128>,
+    pub collection_owner: MultiIndex<'a, Addr, Proposal<Addr, Token>, u128>,
+    pub proposal_kind: MultiIndex<'a, String, Proposal<Addr, Token>, u128>,
+}
+
+impl<'a> IndexList<Proposal<Addr, Token>> for ProposalIndexes<'a> {
+    fn get_indexes(
+        &'_ self,
+    ) -> Box<dyn Iterator<Item = &'_ dyn Index<Proposal<Addr, Token>>> + '_> {
+        let v: Vec<&dyn Index<Proposal<Addr, Token>>> = vec![
+            &self.status,
+            &self.collection_address,
+            &self.collection_owner,
+            &self.proposal_kind,
+        ];
+
+        Box::new(v.into_iter())
+    }
+}
+
+pub struct RestakeCooldownIndexes<'a> {
+    pub staker: MultiIndex<'a, Addr, RestakeCooldown, (&'a Addr, u128)>,
+}
+
+impl<'a> IndexList<RestakeCooldown> for RestakeCooldownIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<RestakeCooldown>> + '_> {
+        let v: Vec<&dyn Index<RestakeCooldown>> = vec![&self.staker];
+
+        Box::new(v.into_iter())
+    }
+}
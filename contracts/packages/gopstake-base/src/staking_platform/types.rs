@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Deps, StdResult, Timestamp, Uint128};
 
 use crate::assets::{Currency, Funds, Token};
 
@@ -17,6 +17,67 @@ pub struct Collection<A: ToString, T: From<Token>> {
     /// Need to specify owner address to prevent accepting proposals with wrong parameters
     /// by bad actors
     pub owner: A,
+    /// Max amount of NFTs staked by single address. Positions moved to receipt holders
+    /// and migrated positions aren't limited
+    pub max_staked_per_address: Option<Uint128>,
+    /// Max amount of staked NFTs of the collection. Migrated positions aren't limited
+    pub max_total_staked: Option<Uint128>,
+    /// Time in seconds after staking before rewards accrue and unstaking is allowed
+    pub min_stake_duration: Option<u64>,
+    /// Time in seconds after unstaking before the token can be staked again
+    pub restake_cooldown: Option<u64>,
+    /// Fixed amount of rewards per epoch shared among staked NFTs instead of
+    /// daily_rewards per NFT, daily_rewards are still used for set bonuses
+    pub fixed_pool: Option<FixedPool>,
+    /// Spending collection can mint rewards with Minter when its balance is not enough,
+    /// the token must be created with Minter by collection owner
+    pub hybrid_emission: Option<HybridEmission>,
+    /// Rewards per staked NFT depend on staked fraction of collection supply instead of
+    /// constant daily_rewards, daily_rewards are still used for set bonuses
+    pub reward_curve: Option<RewardCurve>,
+}
+
+#[cw_serde]
+pub struct RewardCurve {
+    /// Breakpoints sorted by utilization, daily rewards are linearly interpolated
+    /// between them and kept constant outside of the curve
+    pub point_list: Vec<RewardCurvePoint>,
+}
+
+#[cw_serde]
+pub struct RewardCurvePoint {
+    /// Fraction of collection supply staked on the platform, from 0 to 1
+    pub utilization: Decimal,
+    /// Amount of tokens per 24 h for single staked NFT
+    pub daily_rewards: Decimal,
+}
+
+#[cw_serde]
+pub struct HybridEmission {
+    /// Max amount of tokens that can be minted, unlimited if None
+    pub mint_cap: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct FixedPool {
+    /// Amount of tokens emitted per epoch for all staked NFTs
+    pub epoch_rewards: Decimal,
+    /// Epoch duration in seconds
+    pub epoch_duration: u64,
+}
+
+/// Cumulative fixed pool or reward curve rewards per staked NFT at checkpoint date and
+/// parameters used for accrual until next checkpoint
+#[cw_serde]
+pub struct RewardsCheckpoint {
+    pub index: Decimal,
+    pub total_staked: u128,
+    /// None if collection is paused or fixed pool is disabled
+    pub fixed_pool: Option<FixedPool>,
+    /// Version of reward curve stored by collection, None if collection is paused or
+    /// reward curve is disabled
+    pub reward_curve_version: Option<u64>,
+    pub supply: u128,
 }
 
 #[cw_serde]
@@ -34,6 +95,17 @@ impl EmissionType {
     }
 }
 
+impl std::fmt::Display for EmissionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Spending => "spending",
+            Self::Minting => "minting",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
 #[cw_serde]
 pub struct Proposal<A: ToString, T: From<Token>> {
     pub proposal_status: Option<ProposalStatus>,
@@ -42,6 +114,16 @@ pub struct Proposal<A: ToString, T: From<Token>> {
     pub price: Funds<T>,
 }
 
+impl<A: ToString, T: From<Token>> Proposal<A, T> {
+    /// Proposal status name is used as key of secondary index
+    pub fn get_status_name(&self) -> String {
+        self.proposal_status
+            .as_ref()
+            .map(|x| x.to_string())
+            .unwrap_or_default()
+    }
+}
+
 #[cw_serde]
 pub enum ProposalStatus {
     Active,
@@ -49,17 +131,172 @@ pub enum ProposalStatus {
     Rejected,
 }
 
+impl std::fmt::Display for ProposalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Active => "active",
+            Self::Accepted => "accepted",
+            Self::Rejected => "rejected",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
 #[cw_serde]
 pub enum ProposalType<A: ToString, T: From<Token>> {
     AddCollection {
         collection_address: A,
         collection: Collection<A, T>,
     },
+    /// If new_collection_address is specified stakers positions are migrated by batches
+    /// with MigrateCollection message after accepting.
+    /// token_id_mapping (old ID, new ID) is used if project migrated its NFT contract,
+    /// new NFTs must be transferred to the platform before unstaking
     UpdateCollection {
         collection_address: A,
         new_collection_address: Option<A>,
+        token_id_mapping: Option<Vec<(Uint128, Uint128)>>,
         new_collection: Collection<A, T>,
     },
+    /// Must be accepted by both current and new collection owners.
+    /// Listing price is paid by new owner
+    TransferCollectionOwnership {
+        collection_address: A,
+        owner: A,
+        new_owner: A,
+    },
+    /// Stops rewards accrual and new stakes for the collection. Staked NFTs can be unstaked
+    PauseCollection {
+        collection_address: A,
+        owner: A,
+    },
+    ResumeCollection {
+        collection_address: A,
+        owner: A,
+    },
+}
+
+impl<A: ToString, T: From<Token>> ProposalType<A, T> {
+    pub fn get_collection_address(&self) -> &A {
+        match self {
+            Self::AddCollection {
+                collection_address, ..
+            } => collection_address,
+            Self::UpdateCollection {
+                collection_address, ..
+            } => collection_address,
+            Self::TransferCollectionOwnership {
+                collection_address, ..
+            } => collection_address,
+            Self::PauseCollection {
+                collection_address, ..
+            } => collection_address,
+            Self::ResumeCollection {
+                collection_address, ..
+            } => collection_address,
+        }
+    }
+
+    /// Returns address of collection owner who must accept the proposal
+    pub fn get_collection_owner(&self) -> &A {
+        match self {
+            Self::AddCollection { collection, .. } => &collection.owner,
+            Self::UpdateCollection { new_collection, .. } => &new_collection.owner,
+            Self::TransferCollectionOwnership { owner, .. } => owner,
+            Self::PauseCollection { owner, .. } => owner,
+            Self::ResumeCollection { owner, .. } => owner,
+        }
+    }
+
+    pub fn get_kind(&self) -> ProposalKind {
+        match self {
+            Self::AddCollection { .. } => ProposalKind::AddCollection,
+            Self::UpdateCollection { .. } => ProposalKind::UpdateCollection,
+            Self::TransferCollectionOwnership { .. } => ProposalKind::TransferCollectionOwnership,
+            Self::PauseCollection { .. } => ProposalKind::PauseCollection,
+            Self::ResumeCollection { .. } => ProposalKind::ResumeCollection,
+        }
+    }
+}
+
+/// Proposal type without payload to filter proposals
+#[cw_serde]
+pub enum ProposalKind {
+    AddCollection,
+    UpdateCollection,
+    TransferCollectionOwnership,
+    PauseCollection,
+    ResumeCollection,
+}
+
+impl std::fmt::Display for ProposalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::AddCollection => "add_collection",
+            Self::UpdateCollection => "update_collection",
+            Self::TransferCollectionOwnership => "transfer_collection_ownership",
+            Self::PauseCollection => "pause_collection",
+            Self::ResumeCollection => "resume_collection",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// All specified conditions must be met by proposal to be included in query response
+#[cw_serde]
+#[derive(Default)]
+pub struct ProposalFilter<A: ToString> {
+    pub proposal_status: Option<ProposalStatus>,
+    pub collection_address: Option<A>,
+    pub collection_owner: Option<A>,
+    pub proposal_kind: Option<ProposalKind>,
+}
+
+impl ProposalFilter<String> {
+    pub fn verify(&self, deps: &Deps) -> StdResult<ProposalFilter<Addr>> {
+        Ok(ProposalFilter {
+            proposal_status: self.proposal_status.to_owned(),
+            collection_address: self
+                .collection_address
+                .as_ref()
+                .map(|x| deps.api.addr_validate(x))
+                .transpose()?,
+            collection_owner: self
+                .collection_owner
+                .as_ref()
+                .map(|x| deps.api.addr_validate(x))
+                .transpose()?,
+            proposal_kind: self.proposal_kind.to_owned(),
+        })
+    }
+}
+
+impl ProposalFilter<Addr> {
+    pub fn is_matched(&self, proposal: &Proposal<Addr, Token>) -> bool {
+        let Proposal {
+            proposal_status,
+            proposal_type,
+            ..
+        } = proposal;
+
+        self.proposal_status
+            .as_ref()
+            .is_none_or(|x| proposal_status.as_ref() == Some(x))
+            && self
+                .collection_address
+                .as_ref()
+                .is_none_or(|x| proposal_type.get_collection_address() == x)
+            && self
+                .collection_owner
+                .as_ref()
+                .is_none_or(|x| proposal_type.get_collection_owner() == x)
+            && self
+                .proposal_kind
+                .as_ref()
+                .is_none_or(|x| &proposal_type.get_kind() == x)
+    }
 }
 
 #[cw_serde]
@@ -76,11 +313,245 @@ pub struct StakedTokenInfo {
     pub last_claim_date: Option<Timestamp>,
 }
 
+/// Group of actions which can be paused separately for all collections or for specified one
+#[cw_serde]
+pub enum ActionGroup {
+    /// Stake
+    Staking,
+    /// Unstake
+    Unstaking,
+    /// ClaimStakingRewards, ClaimCampaign, rewards on Unstake and MigrateCollection
+    Claiming,
+    /// CreateProposal, RejectProposal, AcceptProposal, RemoveCollection
+    Proposals,
+    /// DistributeFunds
+    Treasury,
+    /// DepositTokens, WithdrawTokens, DistributeBonus, CreateCampaign, ReclaimCampaign
+    Deposits,
+}
+
+impl std::fmt::Display for ActionGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Staking => "staking",
+            Self::Unstaking => "unstaking",
+            Self::Claiming => "claiming",
+            Self::Proposals => "proposals",
+            Self::Treasury => "treasury",
+            Self::Deposits => "deposits",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[cw_serde]
+pub struct ActionPause {
+    pub action_group: ActionGroup,
+    /// Actions are paused for all collections if it isn't specified
+    pub collection_address: Option<Addr>,
+    pub reason: String,
+    /// Pause is lifted automatically after expiration date
+    pub expiration_date: Option<Timestamp>,
+}
+
+impl ActionPause {
+    pub fn is_active(&self, block_time: Timestamp) -> bool {
+        self.expiration_date.is_none_or(|x| block_time < x)
+    }
+}
+
+/// Progress of moving staker positions from old collection address to new one
+#[cw_serde]
+pub struct CollectionMigration {
+    pub new_collection_address: Addr,
+    /// Last processed staker, migration is continued after it
+    pub last_staker: Option<Addr>,
+}
+
+/// Staked token position owned by holder of receipt NFT with the same ID
+#[cw_serde]
+pub struct Receipt {
+    pub collection_address: Addr,
+    pub token_id: Uint128,
+    /// Staker whose position includes the token. It's updated to receipt holder
+    /// when holder or previous staker interacts with the platform
+    pub staker: Addr,
+}
+
+/// Direction of staked tokens change reported to hooks
+#[cw_serde]
+pub enum StakeDirection {
+    Stake,
+    Unstake,
+    /// Position is received with receipt from another staker
+    TransferIn,
+    /// Position is sent with receipt to another staker
+    TransferOut,
+}
+
+#[cw_serde]
+pub struct StakeChangedHookMsg {
+    pub staker: Addr,
+    pub collection_address: Addr,
+    pub token_ids: Vec<Uint128>,
+    pub direction: StakeDirection,
+}
+
+/// Stored recipients of platform funds used by DistributeFunds
+#[cw_serde]
+pub struct RevenueSplit<A: ToString> {
+    pub address_and_weight_list: Vec<(A, Decimal)>,
+    /// Receives rounding dust, if it isn't specified dust is carried forward
+    /// to next distribution
+    pub dust_recipient: Option<A>,
+}
+
+impl RevenueSplit<String> {
+    pub fn verify(&self, deps: &Deps) -> StdResult<RevenueSplit<Addr>> {
+        Ok(RevenueSplit {
+            address_and_weight_list: self
+                .address_and_weight_list
+                .iter()
+                .map(|(address, weight)| -> StdResult<(Addr, Decimal)> {
+                    Ok((deps.api.addr_validate(address)?, weight.to_owned()))
+                })
+                .collect::<StdResult<Vec<(Addr, Decimal)>>>()?,
+            dust_recipient: self
+                .dust_recipient
+                .as_ref()
+                .map(|x| deps.api.addr_validate(x))
+                .transpose()?,
+        })
+    }
+}
+
+/// Set of collections granting bonus while one NFT of each collection is staked
+/// by the same address
+#[cw_serde]
+pub struct StakingSet<A: ToString> {
+    pub name: String,
+    pub collection_list: Vec<A>,
+    pub bonus: SetBonus<A>,
+}
+
+impl StakingSet<String> {
+    pub fn verify(&self, deps: &Deps) -> StdResult<StakingSet<Addr>> {
+        Ok(StakingSet {
+            name: self.name.to_owned(),
+            collection_list: self
+                .collection_list
+                .iter()
+                .map(|x| deps.api.addr_validate(x))
+                .collect::<StdResult<Vec<Addr>>>()?,
+            bonus: match &self.bonus {
+                SetBonus::Multiplier { value } => SetBonus::Multiplier {
+                    value: value.to_owned(),
+                },
+                SetBonus::Flat {
+                    collection_address,
+                    daily_rewards,
+                } => SetBonus::Flat {
+                    collection_address: deps.api.addr_validate(collection_address)?,
+                    daily_rewards: daily_rewards.to_owned(),
+                },
+            },
+        })
+    }
+}
+
+#[cw_serde]
+pub enum SetBonus<A: ToString> {
+    /// Multiplies staking rewards of one NFT of each set collection, e.g. 1.5 gives
+    /// additional 50% of daily rewards in currencies of set collections
+    Multiplier { value: Decimal },
+    /// Amount of tokens per 24 h paid in staking currency of specified set collection
+    /// from its balances
+    Flat {
+        collection_address: A,
+        daily_rewards: Decimal,
+    },
+}
+
+/// Claimed rewards are locked until cliff date and then are vested linearly
+/// until end of vesting duration. Set bonuses and bonus distributions aren't vested
+#[cw_serde]
+pub struct VestingPolicy {
+    /// Seconds from claim before any rewards are vested
+    pub cliff: u64,
+    /// Seconds from claim before all rewards are vested, it includes the cliff
+    pub duration: u64,
+}
+
+#[cw_serde]
+pub struct Vesting {
+    pub collection_address: Addr,
+    /// Claimed rewards after protocol fee held by the platform
+    pub funds: Funds<Token>,
+    pub start_date: Timestamp,
+    pub policy: VestingPolicy,
+    pub withdrawn_amount: Uint128,
+}
+
+impl Vesting {
+    pub fn get_vested_amount(&self, block_time: Timestamp) -> Uint128 {
+        let passed = block_time
+            .seconds()
+            .saturating_sub(self.start_date.seconds());
+
+        if passed < self.policy.cliff {
+            return Uint128::zero();
+        }
+
+        if passed >= self.policy.duration {
+            return self.funds.amount;
+        }
+
+        self.funds
+            .amount
+            .multiply_ratio(passed, self.policy.duration)
+    }
+
+    /// Vested amount which isn't withdrawn yet
+    pub fn get_withdrawable_amount(&self, block_time: Timestamp) -> Uint128 {
+        self.get_vested_amount(block_time) - self.withdrawn_amount
+    }
+}
+
+/// Rewards computed off-chain and claimable with merkle proofs until expiration date
+#[cw_serde]
+pub struct Campaign {
+    pub creator: Addr,
+    pub collection_address: Addr,
+    /// Hex encoded sha256 merkle root, leaves are sha256 hashes of
+    /// `{platform address}:{campaign id}:{address}:{amount}` strings, pairs are hashed
+    /// in sorted order. Each address may have at most one leaf as claims are tracked
+    /// by address
+    pub merkle_root: String,
+    pub funds: Funds<Token>,
+    pub claimed_amount: Uint128,
+    pub expiration_date: Timestamp,
+}
+
+#[cw_serde]
+pub struct Distribution {
+    pub distribution_date: Timestamp,
+    pub sender: Addr,
+    /// Sent funds by recipient including dust
+    pub recipient_and_funds_list: Vec<(Addr, Funds<Token>)>,
+}
+
 #[cw_serde]
 pub struct Config {
     pub admin: Addr,
     pub owner: Option<Addr>,
     pub minter: Option<Addr>,
+    /// Share of staking rewards accrued to platform funds if collection protocol fee
+    /// isn't specified
+    #[serde(default)]
+    pub protocol_fee: Decimal,
+    /// Platform-owned cw721 minting receipts of staked tokens
+    pub receipt_collection: Option<Addr>,
 }
 
 impl Config {
@@ -89,6 +560,70 @@ impl Config {
             admin: admin.to_owned(),
             owner: owner.to_owned(),
             minter: minter.to_owned(),
+            protocol_fee: Decimal::zero(),
+            receipt_collection: None,
         }
     }
 }
+
+#[cw_serde]
+pub struct MigrationProgress {
+    /// Name of current step
+    pub step: String,
+    /// Last processed key of the map migrated by current step
+    pub cursor: Option<Binary>,
+}
+
+#[cw_serde]
+pub struct RestakeCooldown {
+    /// Address unstaked the token
+    pub staker: Addr,
+    pub expiration_date: Timestamp,
+}
+
+#[cw_serde]
+pub struct TokenTimeLock {
+    pub collection_address: Addr,
+    pub token_id: Uint128,
+    /// Time in seconds until the lock expires
+    pub remaining_time: u64,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct PlatformStats {
+    /// Addresses having at least one staked NFT
+    pub unique_stakers: Uint128,
+    /// Rewards claimed by stakers including vested ones
+    pub claimed_list: Vec<(Funds<Token>, EmissionType)>,
+    /// Tokens minted via minter as staking rewards and protocol fees
+    pub minted_list: Vec<Funds<Token>>,
+    /// Proposal prices paid by collection owners
+    pub listing_revenue_list: Vec<Funds<Token>>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct CollectionStats {
+    pub unique_stakers: Uint128,
+    pub claimed_list: Vec<(Funds<Token>, EmissionType)>,
+    pub listing_revenue_list: Vec<Funds<Token>>,
+}
+
+#[cw_serde]
+pub struct ClaimRecord {
+    pub collection_address: Addr,
+    /// Claimed rewards after protocol fee
+    pub funds: Funds<Token>,
+    pub emission_type: EmissionType,
+    pub claim_date: Timestamp,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct BonusPosition {
+    /// Collection bonus indexes at last position update
+    pub index_list: Vec<(Token, Decimal)>,
+    /// Accrued bonuses waiting to be claimed
+    pub pending_list: Vec<(Token, Uint128)>,
+}
@@ -107,7 +107,7 @@ pub fn add_funds_to_exec_msg(
             // Case 2 `Swap` - only single cw20 token
             if (cw20_tokens.len() == 1) && native_tokens.is_empty() {
                 let (amount, token_address) =
-                    cw20_tokens.get(0).ok_or(ContractError::AssetIsNotFound)?;
+                    cw20_tokens.first().ok_or(ContractError::AssetIsNotFound)?;
 
                 return Ok(WasmMsg::Execute {
                     contract_addr: token_address.to_string(),
@@ -216,8 +216,8 @@ pub fn filter_by_address_list<T: Clone>(
 
         return Ok(list
             .iter()
-            .cloned()
             .filter(|(address, _)| address_list.contains(address))
+            .cloned()
             .collect::<Vec<(Addr, T)>>());
     }
 
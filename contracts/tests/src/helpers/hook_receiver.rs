@@ -0,0 +1,53 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+};
+use cw_storage_plus::Item;
+
+use gopstake_base::staking_platform::{msg::StakeChangedExecuteMsg, types::StakeChangedHookMsg};
+
+use crate::helpers::suite::core::Project;
+
+/// Hook messages received by the mock
+const HOOK_MSGS: Item<Vec<StakeChangedHookMsg>> = Item::new("hook msgs");
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    HOOK_MSGS.save(deps.storage, &vec![])?;
+
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: StakeChangedExecuteMsg,
+) -> StdResult<Response> {
+    let StakeChangedExecuteMsg::StakeChangedHook(hook_msg) = msg;
+
+    HOOK_MSGS.update(deps.storage, |mut x| -> StdResult<_> {
+        x.push(hook_msg);
+        Ok(x)
+    })?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&HOOK_MSGS.load(deps.storage)?)
+}
+
+pub trait HookReceiverExtension {
+    fn hook_receiver_query_msgs(&self, address: &Addr) -> StdResult<Vec<StakeChangedHookMsg>>;
+}
+
+impl HookReceiverExtension for Project {
+    #[track_caller]
+    fn hook_receiver_query_msgs(&self, address: &Addr) -> StdResult<Vec<StakeChangedHookMsg>> {
+        self.app.wrap().query_wasm_smart(address, &Empty {})
+    }
+}
@@ -0,0 +1,77 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::{Item, Map};
+
+use gopstake_base::{
+    assets::Token,
+    staking_platform::{
+        state::{PROPOSAL_COUNTER, STAKERS},
+        types::{Proposal, StakedCollectionInfo},
+    },
+};
+
+/// Staking platform config before adding protocol fee and receipts
+#[cw_serde]
+struct LegacyConfig {
+    admin: Addr,
+    owner: Option<Addr>,
+    minter: Option<Addr>,
+}
+
+/// Old-format state written by the fixture
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub contract_name: String,
+    pub version: String,
+    pub is_locked: bool,
+    pub proposal_list: Vec<Proposal<Addr, Token>>,
+    pub staker_list: Vec<(Addr, Vec<StakedCollectionInfo<Addr>>)>,
+}
+
+const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+const LEGACY_IS_LOCKED: Item<bool> = Item::new("is_locked");
+/// Proposals without secondary indexes
+const LEGACY_PROPOSALS: Map<u128, Proposal<Addr, Token>> = Map::new("proposal by id");
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    set_contract_version(deps.storage, msg.contract_name, msg.version)?;
+
+    LEGACY_CONFIG.save(
+        deps.storage,
+        &LegacyConfig {
+            admin: info.sender,
+            owner: None,
+            minter: None,
+        },
+    )?;
+    LEGACY_IS_LOCKED.save(deps.storage, &msg.is_locked)?;
+    PROPOSAL_COUNTER.save(deps.storage, &(msg.proposal_list.len() as u128))?;
+
+    for (i, proposal) in msg.proposal_list.iter().enumerate() {
+        LEGACY_PROPOSALS.save(deps.storage, i as u128 + 1, proposal)?;
+    }
+
+    for (staker, collection_list) in msg.staker_list {
+        STAKERS.save(deps.storage, &staker, &collection_list)?;
+    }
+
+    Ok(Response::new())
+}
+
+pub fn execute(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Err(StdError::generic_err(
+        "Legacy fixture has no execute messages!",
+    ))
+}
+
+pub fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    Err(StdError::generic_err("Legacy fixture has no queries!"))
+}
@@ -5,7 +5,7 @@ use gopstake_base::{
     error::parse_err,
     minter::{
         msg::{ExecuteMsg, QueryMsg},
-        types::{Config, Metadata, QueryDenomsFromCreatorResponse},
+        types::{Config, Cw20TokenInfo, Metadata, QueryDenomsFromCreatorResponse},
     },
 };
 
@@ -23,6 +23,13 @@ pub trait MinterExtension {
         payment: (u128, ProjectCoin),
     ) -> StdResult<AppResponse>;
 
+    fn minter_try_create_cw20(
+        &mut self,
+        sender: ProjectAccount,
+        token_owner: ProjectAccount,
+        symbol: &str,
+    ) -> StdResult<AppResponse>;
+
     fn minter_try_mint_tokens(
         &mut self,
         sender: ProjectAccount,
@@ -81,6 +88,31 @@ impl MinterExtension for Project {
             .map_err(parse_err)
     }
 
+    #[track_caller]
+    fn minter_try_create_cw20(
+        &mut self,
+        sender: ProjectAccount,
+        token_owner: ProjectAccount,
+        symbol: &str,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_minter_address(),
+                &ExecuteMsg::CreateCw20 {
+                    token_owner: token_owner.to_string(),
+                    cw20_code_id: self.get_cw20_base_code_id(),
+                    token_info: Cw20TokenInfo {
+                        name: format!("cw20-base token {}", symbol),
+                        symbol: symbol.to_string(),
+                        decimals: 6,
+                    },
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
     #[track_caller]
     fn minter_try_mint_tokens(
         &mut self,
@@ -1,4 +1,4 @@
-use cosmwasm_std::{StdResult, Uint128};
+use cosmwasm_std::{Addr, StdResult, Timestamp, Uint128};
 use cw_multi_test::{AppResponse, Executor};
 
 use gopstake_base::{
@@ -7,11 +7,19 @@ use gopstake_base::{
     error::parse_err,
     staking_platform::{
         msg::{
-            BalancesResponseItem, ExecuteMsg, QueryCollectionsBalancesResponseItem,
-            QueryCollectionsResponseItem, QueryMsg, QueryProposalsResponseItem,
-            QueryStakersResponseItem,
+            BalancesResponseItem, ExecuteMsg, QueryActiveSetsResponseItem,
+            QueryCampaignsResponseItem, QueryClaimHistoryResponse,
+            QueryCollectionMigrationsResponseItem, QueryCollectionStatsResponse,
+            QueryCollectionTotalAtHeightResponse, QueryCollectionsBalancesResponseItem,
+            QueryCollectionsResponseItem, QueryDistributionsResponseItem, QueryMsg,
+            QueryPlatformStatsResponse, QueryProposalsResponseItem, QuerySetsResponseItem,
+            QueryStakedAtHeightResponse, QueryStakerPortfolioResponse, QueryStakersResponseItem,
+            QueryVestingsResponseItem, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+        },
+        types::{
+            ActionGroup, ActionPause, Config, Proposal, ProposalFilter, Receipt, RevenueSplit,
+            StakedCollectionInfo, StakingSet, VestingPolicy,
         },
-        types::{Config, Proposal, StakedCollectionInfo},
     },
 };
 
@@ -27,6 +35,12 @@ pub trait StakingPlatformExtension {
         collections_to_stake: &[StakedCollectionInfo<String>],
     ) -> StdResult<AppResponse>;
 
+    fn staking_platform_try_stake_with_receipts(
+        &mut self,
+        sender: ProjectAccount,
+        collections_to_stake: &[StakedCollectionInfo<String>],
+    ) -> StdResult<AppResponse>;
+
     fn staking_platform_try_unstake(
         &mut self,
         sender: ProjectAccount,
@@ -44,16 +58,57 @@ pub trait StakingPlatformExtension {
         sender: ProjectAccount,
         owner: &Option<A>,
         minter: &Option<A>,
+        protocol_fee: Option<&str>,
+        receipt_collection: &Option<A>,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_add_hook(
+        &mut self,
+        sender: ProjectAccount,
+        address: &Addr,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_remove_hook(
+        &mut self,
+        sender: ProjectAccount,
+        address: &Addr,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_update_collection_protocol_fee(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+        protocol_fee: Option<&str>,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_pause_actions(
+        &mut self,
+        sender: ProjectAccount,
+        action_groups: &[ActionGroup],
+        collection_address: Option<ProjectNft>,
+        reason: &str,
+        expiration_date: Option<Timestamp>,
     ) -> StdResult<AppResponse>;
 
-    fn staking_platform_try_lock(&mut self, sender: ProjectAccount) -> StdResult<AppResponse>;
+    fn staking_platform_try_resume_actions(
+        &mut self,
+        sender: ProjectAccount,
+        action_groups: &[ActionGroup],
+        collection_address: Option<ProjectNft>,
+    ) -> StdResult<AppResponse>;
 
-    fn staking_platform_try_unlock(&mut self, sender: ProjectAccount) -> StdResult<AppResponse>;
+    fn staking_platform_try_update_revenue_split(
+        &mut self,
+        sender: ProjectAccount,
+        address_and_weight_list: &[(ProjectAccount, &str)],
+        dust_recipient: Option<ProjectAccount>,
+    ) -> StdResult<AppResponse>;
 
     fn staking_platform_try_distribute_funds(
         &mut self,
         sender: ProjectAccount,
-        address_and_weight_list: &[(impl ToString, &str)],
+        address_and_weight_list: Option<&[(ProjectAccount, &str)]>,
+        token_and_amount_list: Option<&[(ProjectAsset, Option<u128>)]>,
     ) -> StdResult<AppResponse>;
 
     fn staking_platform_try_remove_collection(
@@ -97,6 +152,53 @@ pub trait StakingPlatformExtension {
         amount: impl Into<Uint128>,
     ) -> StdResult<AppResponse>;
 
+    fn staking_platform_try_migrate_collection(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+        limit: Option<u32>,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_return_migrated_nfts(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+        token_ids: &[u128],
+        recipient: ProjectAccount,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_distribute_bonus(
+        &mut self,
+        sender: ProjectAccount,
+        collection: ProjectNft,
+        amount: impl Into<Uint128>,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_create_campaign(
+        &mut self,
+        sender: ProjectAccount,
+        collection: ProjectNft,
+        merkle_root: &str,
+        expiration_date: Timestamp,
+        amount: impl Into<Uint128>,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_claim_campaign(
+        &mut self,
+        sender: ProjectAccount,
+        id: u128,
+        amount: impl Into<Uint128>,
+        proof: &[String],
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_reclaim_campaign(
+        &mut self,
+        sender: ProjectAccount,
+        id: u128,
+    ) -> StdResult<AppResponse>;
+
     fn staking_platform_query_config(&self) -> StdResult<Config>;
 
     fn staking_platform_query_funds(&self) -> StdResult<Vec<Funds<Token>>>;
@@ -127,6 +229,13 @@ pub trait StakingPlatformExtension {
         last_amount: Option<u128>,
     ) -> StdResult<Vec<QueryProposalsResponseItem>>;
 
+    fn staking_platform_query_proposals_by_filter(
+        &self,
+        filter: &ProposalFilter<String>,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<QueryProposalsResponseItem>>;
+
     fn staking_platform_query_collections(
         &self,
         addresses: &Option<Vec<ProjectNft>>,
@@ -136,6 +245,128 @@ pub trait StakingPlatformExtension {
         &self,
         addresses: &Option<Vec<ProjectNft>>,
     ) -> StdResult<Vec<QueryCollectionsBalancesResponseItem>>;
+
+    fn staking_platform_query_collection_migrations(
+        &self,
+    ) -> StdResult<Vec<QueryCollectionMigrationsResponseItem>>;
+
+    fn staking_platform_query_paused_actions(&self) -> StdResult<Vec<ActionPause>>;
+
+    fn staking_platform_query_voting_power_at_height(
+        &self,
+        address: ProjectAccount,
+        height: Option<u64>,
+        collection: Option<ProjectNft>,
+    ) -> StdResult<VotingPowerAtHeightResponse>;
+
+    fn staking_platform_query_total_power_at_height(
+        &self,
+        height: Option<u64>,
+        collection: Option<ProjectNft>,
+    ) -> StdResult<TotalPowerAtHeightResponse>;
+
+    fn staking_platform_query_dao(&self) -> StdResult<Addr>;
+
+    fn staking_platform_query_hooks(&self) -> StdResult<Vec<Addr>>;
+
+    fn staking_platform_query_receipt(&self, id: u128) -> StdResult<Receipt>;
+
+    fn staking_platform_query_revenue_split(&self) -> StdResult<Option<RevenueSplit<Addr>>>;
+
+    fn staking_platform_query_distributions(
+        &self,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<QueryDistributionsResponseItem>>;
+
+    fn staking_platform_query_staked_at_height(
+        &self,
+        address: ProjectAccount,
+        height: Option<u64>,
+    ) -> StdResult<QueryStakedAtHeightResponse>;
+
+    fn staking_platform_query_collection_total_at_height(
+        &self,
+        collection: ProjectNft,
+        height: Option<u64>,
+    ) -> StdResult<QueryCollectionTotalAtHeightResponse>;
+
+    fn staking_platform_try_create_set(
+        &mut self,
+        sender: ProjectAccount,
+        set: &StakingSet<String>,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_remove_set(
+        &mut self,
+        sender: ProjectAccount,
+        id: u128,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_query_sets(&self) -> StdResult<Vec<QuerySetsResponseItem>>;
+
+    fn staking_platform_try_update_collection_vesting(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+        vesting_policy: Option<VestingPolicy>,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_refresh_collection_supply(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_try_withdraw_vested_rewards(
+        &mut self,
+        sender: ProjectAccount,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<AppResponse>;
+
+    fn staking_platform_query_vestings(
+        &self,
+        address: ProjectAccount,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<QueryVestingsResponseItem>>;
+
+    fn staking_platform_query_active_sets(
+        &self,
+        address: ProjectAccount,
+    ) -> StdResult<Vec<QueryActiveSetsResponseItem>>;
+
+    fn staking_platform_query_staker_portfolio(
+        &self,
+        address: ProjectAccount,
+    ) -> StdResult<QueryStakerPortfolioResponse>;
+
+    fn staking_platform_query_platform_stats(&self) -> StdResult<QueryPlatformStatsResponse>;
+
+    fn staking_platform_query_collection_stats(
+        &self,
+        collection: ProjectNft,
+    ) -> StdResult<QueryCollectionStatsResponse>;
+
+    fn staking_platform_query_claim_history(
+        &self,
+        address: ProjectAccount,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<QueryClaimHistoryResponse>;
+
+    fn staking_platform_query_campaigns(
+        &self,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<QueryCampaignsResponseItem>>;
+
+    fn staking_platform_query_campaign_claim(
+        &self,
+        id: u128,
+        address: ProjectAccount,
+    ) -> StdResult<Option<Uint128>>;
 }
 
 impl StakingPlatformExtension for Project {
@@ -151,6 +382,26 @@ impl StakingPlatformExtension for Project {
                 self.get_staking_platform_address(),
                 &ExecuteMsg::Stake {
                     collections_to_stake: collections_to_stake.to_owned(),
+                    mint_receipts: None,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_stake_with_receipts(
+        &mut self,
+        sender: ProjectAccount,
+        collections_to_stake: &[StakedCollectionInfo<String>],
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::Stake {
+                    collections_to_stake: collections_to_stake.to_owned(),
+                    mint_receipts: Some(true),
                 },
                 &[],
             )
@@ -199,6 +450,8 @@ impl StakingPlatformExtension for Project {
         sender: ProjectAccount,
         owner: &Option<A>,
         minter: &Option<A>,
+        protocol_fee: Option<&str>,
+        receipt_collection: &Option<A>,
     ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
@@ -207,6 +460,44 @@ impl StakingPlatformExtension for Project {
                 &ExecuteMsg::UpdateConfig {
                     owner: owner.as_ref().map(|x| x.to_string()),
                     minter: minter.as_ref().map(|x| x.to_string()),
+                    protocol_fee: protocol_fee.map(str_to_dec),
+                    receipt_collection: receipt_collection.as_ref().map(|x| x.to_string()),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_add_hook(
+        &mut self,
+        sender: ProjectAccount,
+        address: &Addr,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::AddHook {
+                    address: address.to_string(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_remove_hook(
+        &mut self,
+        sender: ProjectAccount,
+        address: &Addr,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::RemoveHook {
+                    address: address.to_string(),
                 },
                 &[],
             )
@@ -214,44 +505,119 @@ impl StakingPlatformExtension for Project {
     }
 
     #[track_caller]
-    fn staking_platform_try_lock(&mut self, sender: ProjectAccount) -> StdResult<AppResponse> {
+    fn staking_platform_try_update_collection_protocol_fee(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+        protocol_fee: Option<&str>,
+    ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 sender.into(),
                 self.get_staking_platform_address(),
-                &ExecuteMsg::Lock {},
+                &ExecuteMsg::UpdateCollectionProtocolFee {
+                    collection_address: collection_address.to_string(),
+                    protocol_fee: protocol_fee.map(str_to_dec),
+                },
                 &[],
             )
             .map_err(parse_err)
     }
 
     #[track_caller]
-    fn staking_platform_try_unlock(&mut self, sender: ProjectAccount) -> StdResult<AppResponse> {
+    fn staking_platform_try_pause_actions(
+        &mut self,
+        sender: ProjectAccount,
+        action_groups: &[ActionGroup],
+        collection_address: Option<ProjectNft>,
+        reason: &str,
+        expiration_date: Option<Timestamp>,
+    ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 sender.into(),
                 self.get_staking_platform_address(),
-                &ExecuteMsg::Unlock {},
+                &ExecuteMsg::PauseActions {
+                    action_groups: action_groups.to_owned(),
+                    collection_address: collection_address.map(|x| x.to_string()),
+                    reason: reason.to_string(),
+                    expiration_date,
+                },
                 &[],
             )
             .map_err(parse_err)
     }
 
     #[track_caller]
-    fn staking_platform_try_distribute_funds(
+    fn staking_platform_try_resume_actions(
         &mut self,
         sender: ProjectAccount,
-        address_and_weight_list: &[(impl ToString, &str)],
+        action_groups: &[ActionGroup],
+        collection_address: Option<ProjectNft>,
     ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 sender.into(),
                 self.get_staking_platform_address(),
-                &ExecuteMsg::DistributeFunds {
+                &ExecuteMsg::ResumeActions {
+                    action_groups: action_groups.to_owned(),
+                    collection_address: collection_address.map(|x| x.to_string()),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_update_revenue_split(
+        &mut self,
+        sender: ProjectAccount,
+        address_and_weight_list: &[(ProjectAccount, &str)],
+        dust_recipient: Option<ProjectAccount>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::UpdateRevenueSplit {
                     address_and_weight_list: address_and_weight_list
                         .iter()
                         .map(|(address, weight)| (address.to_string(), str_to_dec(weight)))
                         .collect(),
+                    dust_recipient: dust_recipient.map(|x| x.to_string()),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_distribute_funds(
+        &mut self,
+        sender: ProjectAccount,
+        address_and_weight_list: Option<&[(ProjectAccount, &str)]>,
+        token_and_amount_list: Option<&[(ProjectAsset, Option<u128>)]>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::DistributeFunds {
+                    address_and_weight_list: address_and_weight_list.map(|x| {
+                        x.iter()
+                            .map(|(address, weight)| (address.to_string(), str_to_dec(weight)))
+                            .collect()
+                    }),
+                    token_and_amount_list: token_and_amount_list.map(|x| {
+                        x.iter()
+                            .map(|(asset, amount)| {
+                                (
+                                    Token::from(asset.to_owned()).into(),
+                                    amount.map(Uint128::new),
+                                )
+                            })
+                            .collect()
+                    }),
                 },
                 &[],
             )
@@ -321,6 +687,13 @@ impl StakingPlatformExtension for Project {
         let contract_address = &self.get_staking_platform_address();
         let msg = &ExecuteMsg::AcceptProposal { id: id.into() };
 
+        if amount == 0 {
+            return self
+                .app
+                .execute_contract(sender.into(), contract_address.to_owned(), msg, &[])
+                .map_err(parse_err);
+        }
+
         add_funds_to_exec_msg(self, sender, contract_address, msg, amount, asset)
     }
 
@@ -361,29 +734,147 @@ impl StakingPlatformExtension for Project {
     }
 
     #[track_caller]
-    fn staking_platform_query_config(&self) -> StdResult<Config> {
-        self.app.wrap().query_wasm_smart(
-            self.get_staking_platform_address(),
-            &QueryMsg::QueryConfig {},
-        )
-    }
-
-    #[track_caller]
-    fn staking_platform_query_funds(&self) -> StdResult<Vec<Funds<Token>>> {
-        self.app.wrap().query_wasm_smart(
-            self.get_staking_platform_address(),
-            &QueryMsg::QueryFunds {},
-        )
+    fn staking_platform_try_migrate_collection(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+        limit: Option<u32>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::MigrateCollection {
+                    collection_address: collection_address.to_string(),
+                    limit,
+                },
+                &[],
+            )
+            .map_err(parse_err)
     }
 
     #[track_caller]
-    fn staking_platform_query_stakers(
-        &self,
-        addresses: &Option<Vec<ProjectAccount>>,
-    ) -> StdResult<Vec<QueryStakersResponseItem>> {
-        let addresses = addresses
-            .as_ref()
-            .map(|x| x.iter().map(|y| y.to_string()).collect());
+    fn staking_platform_try_return_migrated_nfts(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+        token_ids: &[u128],
+        recipient: ProjectAccount,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::ReturnMigratedNfts {
+                    collection_address: collection_address.to_string(),
+                    token_ids: token_ids.iter().map(|x| Uint128::new(*x)).collect(),
+                    recipient: recipient.to_string(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_distribute_bonus(
+        &mut self,
+        sender: ProjectAccount,
+        collection: ProjectNft,
+        amount: impl Into<Uint128>,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse> {
+        let contract_address = &self.get_staking_platform_address();
+        let msg = &ExecuteMsg::DistributeBonus {
+            collection: collection.to_string(),
+        };
+
+        add_funds_to_exec_msg(self, sender, contract_address, msg, amount, asset)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_create_campaign(
+        &mut self,
+        sender: ProjectAccount,
+        collection: ProjectNft,
+        merkle_root: &str,
+        expiration_date: Timestamp,
+        amount: impl Into<Uint128>,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse> {
+        let contract_address = &self.get_staking_platform_address();
+        let msg = &ExecuteMsg::CreateCampaign {
+            collection_address: collection.to_string(),
+            merkle_root: merkle_root.to_string(),
+            expiration_date,
+        };
+
+        add_funds_to_exec_msg(self, sender, contract_address, msg, amount, asset)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_claim_campaign(
+        &mut self,
+        sender: ProjectAccount,
+        id: u128,
+        amount: impl Into<Uint128>,
+        proof: &[String],
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::ClaimCampaign {
+                    id: Uint128::new(id),
+                    amount: amount.into(),
+                    proof: proof.to_owned(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_reclaim_campaign(
+        &mut self,
+        sender: ProjectAccount,
+        id: u128,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::ReclaimCampaign {
+                    id: Uint128::new(id),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_query_config(&self) -> StdResult<Config> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryConfig {},
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_funds(&self) -> StdResult<Vec<Funds<Token>>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryFunds {},
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_stakers(
+        &self,
+        addresses: &Option<Vec<ProjectAccount>>,
+    ) -> StdResult<Vec<QueryStakersResponseItem>> {
+        let addresses = addresses
+            .as_ref()
+            .map(|x| x.iter().map(|y| y.to_string()).collect());
 
         self.app.wrap().query_wasm_smart(
             self.get_staking_platform_address(),
@@ -445,6 +936,23 @@ impl StakingPlatformExtension for Project {
         )
     }
 
+    #[track_caller]
+    fn staking_platform_query_proposals_by_filter(
+        &self,
+        filter: &ProposalFilter<String>,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<QueryProposalsResponseItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryProposalsByFilter {
+                filter: filter.to_owned(),
+                start_after: start_after.map(Into::<Uint128>::into),
+                limit,
+            },
+        )
+    }
+
     #[track_caller]
     fn staking_platform_query_collections(
         &self,
@@ -474,4 +982,342 @@ impl StakingPlatformExtension for Project {
             &QueryMsg::QueryCollectionsBalances { addresses },
         )
     }
+
+    #[track_caller]
+    fn staking_platform_query_collection_migrations(
+        &self,
+    ) -> StdResult<Vec<QueryCollectionMigrationsResponseItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryCollectionMigrations {},
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_paused_actions(&self) -> StdResult<Vec<ActionPause>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryPausedActions {},
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_voting_power_at_height(
+        &self,
+        address: ProjectAccount,
+        height: Option<u64>,
+        collection: Option<ProjectNft>,
+    ) -> StdResult<VotingPowerAtHeightResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::VotingPowerAtHeight {
+                address: address.to_string(),
+                height,
+                collection: collection.map(|x| x.to_string()),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_total_power_at_height(
+        &self,
+        height: Option<u64>,
+        collection: Option<ProjectNft>,
+    ) -> StdResult<TotalPowerAtHeightResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::TotalPowerAtHeight {
+                height,
+                collection: collection.map(|x| x.to_string()),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_dao(&self) -> StdResult<Addr> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_staking_platform_address(), &QueryMsg::Dao {})
+    }
+
+    #[track_caller]
+    fn staking_platform_query_hooks(&self) -> StdResult<Vec<Addr>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryHooks {},
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_receipt(&self, id: u128) -> StdResult<Receipt> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryReceipt { id: id.into() },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_revenue_split(&self) -> StdResult<Option<RevenueSplit<Addr>>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryRevenueSplit {},
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_distributions(
+        &self,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<QueryDistributionsResponseItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryDistributions {
+                start_after: start_after.map(Into::<Uint128>::into),
+                limit,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_staked_at_height(
+        &self,
+        address: ProjectAccount,
+        height: Option<u64>,
+    ) -> StdResult<QueryStakedAtHeightResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryStakedAtHeight {
+                address: address.to_string(),
+                height,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_collection_total_at_height(
+        &self,
+        collection: ProjectNft,
+        height: Option<u64>,
+    ) -> StdResult<QueryCollectionTotalAtHeightResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryCollectionTotalAtHeight {
+                collection: collection.to_string(),
+                height,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_try_create_set(
+        &mut self,
+        sender: ProjectAccount,
+        set: &StakingSet<String>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::CreateSet {
+                    set: set.to_owned(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_remove_set(
+        &mut self,
+        sender: ProjectAccount,
+        id: u128,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::RemoveSet {
+                    id: Uint128::new(id),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_update_collection_vesting(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+        vesting_policy: Option<VestingPolicy>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::UpdateCollectionVesting {
+                    collection_address: collection_address.to_string(),
+                    vesting_policy,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_refresh_collection_supply(
+        &mut self,
+        sender: ProjectAccount,
+        collection_address: ProjectNft,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::RefreshCollectionSupply {
+                    collection_address: collection_address.to_string(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_try_withdraw_vested_rewards(
+        &mut self,
+        sender: ProjectAccount,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_staking_platform_address(),
+                &ExecuteMsg::WithdrawVestedRewards {
+                    start_after: start_after.map(Uint128::new),
+                    limit,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn staking_platform_query_vestings(
+        &self,
+        address: ProjectAccount,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<QueryVestingsResponseItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryVestings {
+                address: address.to_string(),
+                start_after: start_after.map(Uint128::new),
+                limit,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_sets(&self) -> StdResult<Vec<QuerySetsResponseItem>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_staking_platform_address(), &QueryMsg::QuerySets {})
+    }
+
+    #[track_caller]
+    fn staking_platform_query_active_sets(
+        &self,
+        address: ProjectAccount,
+    ) -> StdResult<Vec<QueryActiveSetsResponseItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryActiveSets {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_staker_portfolio(
+        &self,
+        address: ProjectAccount,
+    ) -> StdResult<QueryStakerPortfolioResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryStakerPortfolio {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_platform_stats(&self) -> StdResult<QueryPlatformStatsResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryPlatformStats {},
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_collection_stats(
+        &self,
+        collection: ProjectNft,
+    ) -> StdResult<QueryCollectionStatsResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryCollectionStats {
+                address: collection.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_claim_history(
+        &self,
+        address: ProjectAccount,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<QueryClaimHistoryResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryClaimHistory {
+                address: address.to_string(),
+                start_after: start_after.map(Uint128::new),
+                limit,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_campaigns(
+        &self,
+        start_after: Option<u128>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<QueryCampaignsResponseItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryCampaigns {
+                start_after: start_after.map(Uint128::new),
+                limit,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn staking_platform_query_campaign_claim(
+        &self,
+        id: u128,
+        address: ProjectAccount,
+    ) -> StdResult<Option<Uint128>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_staking_platform_address(),
+            &QueryMsg::QueryCampaignClaim {
+                id: Uint128::new(id),
+                address: address.to_string(),
+            },
+        )
+    }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, StdResult, Uint128};
+use cosmwasm_std::{Addr, Empty, StdResult, Uint128};
 use cw_multi_test::{AppResponse, ContractWrapper, Executor};
 
 use serde::Serialize;
@@ -6,9 +6,12 @@ use strum::IntoEnumIterator;
 
 use gopstake_base::error::parse_err;
 
-use crate::helpers::suite::{
-    core::Project,
-    types::{GetDecimals, ProjectAccount, ProjectNft, ProjectToken},
+use crate::helpers::{
+    hook_receiver, legacy_staking_platform,
+    suite::{
+        core::Project,
+        types::{GetDecimals, ProjectAccount, ProjectNft, ProjectToken},
+    },
 };
 
 pub trait WithCodes {
@@ -16,6 +19,8 @@ pub trait WithCodes {
     fn store_cw20_base_code(&mut self) -> u64;
     fn store_cw721_base_code(&mut self) -> u64;
     fn store_minter_code(&mut self) -> u64;
+    fn store_hook_receiver_code(&mut self) -> u64;
+    fn store_legacy_staking_platform_code(&mut self) -> u64;
 
     // store contracts
     fn store_staking_platform_code(&mut self) -> u64;
@@ -30,6 +35,13 @@ pub trait WithCodes {
         staking_platform: &Option<Addr>,
     ) -> Addr;
 
+    fn instantiate_hook_receiver(&mut self, code_id: u64) -> Addr;
+    fn instantiate_legacy_staking_platform(
+        &mut self,
+        code_id: u64,
+        init_msg: &legacy_staking_platform::InstantiateMsg,
+    ) -> Addr;
+
     // instantiate contracts
     fn instantiate_staking_platform(
         &mut self,
@@ -76,6 +88,22 @@ impl WithCodes for Project {
         ))
     }
 
+    fn store_hook_receiver_code(&mut self) -> u64 {
+        self.app.store_code(Box::new(ContractWrapper::new(
+            hook_receiver::execute,
+            hook_receiver::instantiate,
+            hook_receiver::query,
+        )))
+    }
+
+    fn store_legacy_staking_platform_code(&mut self) -> u64 {
+        self.app.store_code(Box::new(ContractWrapper::new(
+            legacy_staking_platform::execute,
+            legacy_staking_platform::instantiate,
+            legacy_staking_platform::query,
+        )))
+    }
+
     // store contracts
     fn store_staking_platform_code(&mut self) -> u64 {
         self.app.store_code(Box::new(
@@ -163,6 +191,18 @@ impl WithCodes for Project {
         )
     }
 
+    fn instantiate_hook_receiver(&mut self, code_id: u64) -> Addr {
+        self.instantiate_contract(code_id, "hook_receiver", &Empty {})
+    }
+
+    fn instantiate_legacy_staking_platform(
+        &mut self,
+        code_id: u64,
+        init_msg: &legacy_staking_platform::InstantiateMsg,
+    ) -> Addr {
+        self.instantiate_contract(code_id, "legacy_staking_platform", init_msg)
+    }
+
     fn instantiate_staking_platform(
         &mut self,
         staking_platform_code_id: u64,
@@ -27,7 +27,7 @@ pub struct Project {
     contract_counter: u16,
 
     // package code id
-    // cw20_base_code_id: u64,
+    cw20_base_code_id: u64,
     // cw721_base_code_id: u64,
     minter_code_id: u64,
 
@@ -52,7 +52,7 @@ impl Project {
             logs: WrappedResponse::Execute(Ok(AppResponse::default())),
             contract_counter: 0,
 
-            // cw20_base_code_id: 0,
+            cw20_base_code_id: 0,
             // cw721_base_code_id: 0,
             minter_code_id: 0,
 
@@ -115,7 +115,7 @@ impl Project {
             project.instantiate_staking_platform(staking_platform_code_id, &None, &None);
 
         project = Self {
-            // cw20_base_code_id,
+            cw20_base_code_id,
             minter_code_id,
             staking_platform_code_id,
 
@@ -134,6 +134,8 @@ impl Project {
                 ProjectAccount::Admin,
                 &None,
                 &Some(minter_address.clone()),
+                None,
+                &None,
             )
             .unwrap();
 
@@ -163,6 +165,10 @@ impl Project {
     }
 
     // code id getters
+    pub fn get_cw20_base_code_id(&self) -> u64 {
+        self.cw20_base_code_id
+    }
+
     pub fn get_minter_code_id(&self) -> u64 {
         self.minter_code_id
     }
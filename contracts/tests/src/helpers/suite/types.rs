@@ -173,6 +173,15 @@ impl From<ProjectToken> for ProjectAsset {
     }
 }
 
+impl From<ProjectAsset> for Token {
+    fn from(project_asset: ProjectAsset) -> Self {
+        match project_asset {
+            ProjectAsset::Coin(project_coin) => project_coin.into(),
+            ProjectAsset::Token(project_token) => project_token.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, EnumIter)]
 pub enum ProjectPair {
     AtomLuna,
@@ -2,6 +2,8 @@
 pub mod staking_platform;
 
 pub mod helpers {
+    pub mod hook_receiver;
+    pub mod legacy_staking_platform;
     pub mod minter;
     pub mod staking_platform;
 
@@ -1,32 +1,44 @@
-use cosmwasm_std::{StdResult, Uint128};
+use cosmwasm_std::{coin, Addr, Binary, Empty, StdResult, Uint128};
+use cw_multi_test::{AppResponse, Executor};
+use sha2::{Digest, Sha256};
 
 use speculoos::assert_that;
 
 use gopstake_base::{
     assets::{Currency, Funds, Token, TokenUnverified},
-    constants::{MINS_PER_DAY, NANOS_PER_MIN},
+    constants::{CLAIM_LOG_MAX_LENGTH, MINS_PER_DAY, NANOS_PER_MIN, PAGINATION_MAX_LIMIT},
     converters::str_to_dec,
-    error::ContractError,
-    minter::types::{DenomUnit, Metadata},
+    error::{parse_err, ContractError},
+    minter::types::{DenomUnit, Metadata, QueryDenomsFromCreatorResponse},
     staking_platform::{
+        events::{
+            ATTR_AMOUNT, ATTR_COLLECTION_ADDRESS, ATTR_EMISSION_TYPE, ATTR_PROPOSAL_ID,
+            ATTR_STAKER, ATTR_STATUS_FROM, ATTR_STATUS_TO, ATTR_TOKEN, ATTR_TOKEN_IDS, EMPTY_VALUE,
+            EVENT_DEPOSIT, EVENT_PROPOSAL, EVENT_REWARDS, EVENT_STAKE, EVENT_UPDATE_CONFIG,
+            PREVIOUS_VALUE_PREFIX,
+        },
         msg::{
-            QueryCollectionsBalancesResponseItem, QueryCollectionsResponseItem,
-            QueryProposalsResponseItem,
+            ClaimedRewardsItem, QueryCollectionStatsResponse, QueryCollectionsBalancesResponseItem,
+            QueryCollectionsResponseItem, QueryPlatformStatsResponse, QueryProposalsResponseItem,
         },
         types::{
-            Collection, EmissionType, Proposal, ProposalStatus, ProposalType, StakedCollectionInfo,
-            StakedTokenInfo,
+            ActionGroup, ActionPause, Collection, EmissionType, FixedPool, HybridEmission,
+            Proposal, ProposalFilter, ProposalKind, ProposalStatus, ProposalType, RewardCurve,
+            RewardCurvePoint, SetBonus, StakeChangedHookMsg, StakeDirection, StakedCollectionInfo,
+            StakedTokenInfo, StakingSet, TokenTimeLock, VestingPolicy,
         },
     },
 };
 
 use crate::helpers::{
+    hook_receiver::HookReceiverExtension,
+    legacy_staking_platform::InstantiateMsg as LegacyInstantiateMsg,
     minter::MinterExtension,
     staking_platform::StakingPlatformExtension,
     suite::{
         codes::WithCodes,
         core::{assert_error, Project},
-        types::{GetDecimals, ProjectAccount, ProjectCoin, ProjectNft, ProjectToken},
+        types::{GetDecimals, ProjectAccount, ProjectAsset, ProjectCoin, ProjectNft, ProjectToken},
     },
 };
 
@@ -57,6 +69,13 @@ fn create_proposal_default_and_query_last_proposals() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -80,6 +99,13 @@ fn create_proposal_default_and_query_last_proposals() -> StdResult<()> {
                     daily_rewards: str_to_dec("86400000000000"),
                     emission_type: EmissionType::Spending,
                     owner: ProjectAccount::Owner.into(),
+                    max_staked_per_address: None,
+                    max_total_staked: None,
+                    min_stake_duration: None,
+                    restake_cooldown: None,
+                    fixed_pool: None,
+                    hybrid_emission: None,
+                    reward_curve: None,
                 },
             },
         },
@@ -105,6 +131,198 @@ fn create_proposal_default_and_query_last_proposals() -> StdResult<()> {
     Ok(())
 }
 
+#[test]
+fn create_proposals_and_query_by_filter() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("86400000000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            collection: Collection {
+                name: ProjectNft::Pinjeons.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("86400000000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Alice.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_c: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::UpdateCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            new_collection_address: None,
+            token_id_mapping: None,
+            new_collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("86400"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+    project.staking_platform_try_reject_proposal(ProjectAccount::Admin, 2)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_c)?;
+
+    let get_ids = |proposals: Vec<QueryProposalsResponseItem>| -> Vec<u128> {
+        proposals.iter().map(|x| x.id.u128()).collect()
+    };
+
+    // all proposals
+    let proposals = project.staking_platform_query_proposals_by_filter(
+        &ProposalFilter::default(),
+        None,
+        None,
+    )?;
+    assert_that(&get_ids(proposals)).is_equal_to(vec![1, 2, 3, 4]);
+
+    // by status
+    let proposals = project.staking_platform_query_proposals_by_filter(
+        &ProposalFilter {
+            proposal_status: Some(ProposalStatus::Active),
+            ..ProposalFilter::default()
+        },
+        None,
+        None,
+    )?;
+    assert_that(&get_ids(proposals)).is_equal_to(vec![3, 4]);
+
+    // by collection
+    let proposals = project.staking_platform_query_proposals_by_filter(
+        &ProposalFilter {
+            collection_address: Some(ProjectNft::Gopniks.to_string()),
+            ..ProposalFilter::default()
+        },
+        None,
+        None,
+    )?;
+    assert_that(&get_ids(proposals)).is_equal_to(vec![1, 4]);
+
+    // by kind
+    let proposals = project.staking_platform_query_proposals_by_filter(
+        &ProposalFilter {
+            proposal_kind: Some(ProposalKind::AddCollection),
+            ..ProposalFilter::default()
+        },
+        None,
+        None,
+    )?;
+    assert_that(&get_ids(proposals)).is_equal_to(vec![1, 2, 3]);
+
+    // active proposals of owner
+    let proposals = project.staking_platform_query_proposals_by_filter(
+        &ProposalFilter {
+            proposal_status: Some(ProposalStatus::Active),
+            collection_owner: Some(ProjectAccount::Owner.to_string()),
+            ..ProposalFilter::default()
+        },
+        None,
+        None,
+    )?;
+    assert_that(&get_ids(proposals)).is_equal_to(vec![4]);
+
+    // pagination
+    let proposals = project.staking_platform_query_proposals_by_filter(
+        &ProposalFilter {
+            collection_owner: Some(ProjectAccount::Alice.to_string()),
+            ..ProposalFilter::default()
+        },
+        None,
+        Some(1),
+    )?;
+    assert_that(&get_ids(proposals)).is_equal_to(vec![2]);
+
+    let proposals = project.staking_platform_query_proposals_by_filter(
+        &ProposalFilter {
+            collection_owner: Some(ProjectAccount::Alice.to_string()),
+            ..ProposalFilter::default()
+        },
+        Some(2),
+        Some(1),
+    )?;
+    assert_that(&get_ids(proposals)).is_equal_to(vec![3]);
+
+    Ok(())
+}
+
 #[test]
 fn create_proposal_add_same_collection_twice() -> StdResult<()> {
     let mut project = Project::new();
@@ -129,6 +347,13 @@ fn create_proposal_add_same_collection_twice() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -153,6 +378,13 @@ fn create_proposal_add_same_collection_twice() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -177,6 +409,13 @@ fn create_proposal_add_same_collection_twice() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -234,6 +473,13 @@ fn create_proposal_update_collection_default() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -251,6 +497,7 @@ fn create_proposal_update_collection_default() -> StdResult<()> {
         proposal_type: ProposalType::UpdateCollection {
             collection_address: ProjectNft::Gopniks.to_string(),
             new_collection_address: None,
+            token_id_mapping: None,
             new_collection: Collection {
                 name: ProjectNft::Gopniks.to_string(),
                 staking_currency: Currency::new(
@@ -260,6 +507,13 @@ fn create_proposal_update_collection_default() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400"),
                 emission_type: EmissionType::Minting,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -308,6 +562,13 @@ fn create_proposal_update_collection_to_replace_it() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -333,6 +594,13 @@ fn create_proposal_update_collection_to_replace_it() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -350,6 +618,7 @@ fn create_proposal_update_collection_to_replace_it() -> StdResult<()> {
         proposal_type: ProposalType::UpdateCollection {
             collection_address: ProjectNft::Gopniks.to_string(),
             new_collection_address: Some(ProjectNft::Pinjeons.to_string()),
+            token_id_mapping: None,
             new_collection: Collection {
                 name: ProjectNft::Pinjeons.to_string(),
                 staking_currency: Currency::new(
@@ -359,6 +628,13 @@ fn create_proposal_update_collection_to_replace_it() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -411,6 +687,13 @@ fn create_proposal_authorization() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -434,6 +717,13 @@ fn create_proposal_authorization() -> StdResult<()> {
                     daily_rewards: str_to_dec("86400000000000"),
                     emission_type: EmissionType::Spending,
                     owner: ProjectAccount::Owner.into(),
+                    max_staked_per_address: None,
+                    max_total_staked: None,
+                    min_stake_duration: None,
+                    restake_cooldown: None,
+                    fixed_pool: None,
+                    hybrid_emission: None,
+                    reward_curve: None,
                 },
             },
         },
@@ -449,6 +739,8 @@ fn create_proposal_authorization() -> StdResult<()> {
             ProjectAccount::Alice,
             &Some(ProjectAccount::Alice),
             &None,
+            None,
+            &None,
         )
         .unwrap_err();
     assert_error(&res, ContractError::Unauthorized);
@@ -457,6 +749,8 @@ fn create_proposal_authorization() -> StdResult<()> {
         ProjectAccount::Admin,
         &Some(ProjectAccount::Alice),
         &None,
+        None,
+        &None,
     )?;
 
     project.staking_platform_try_create_proposal(ProjectAccount::Alice, proposal)?;
@@ -491,6 +785,13 @@ fn create_proposal_unowned_minter_token() -> StdResult<()> {
                 daily_rewards: str_to_dec("1000000"),
                 emission_type: EmissionType::Minting,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -539,6 +840,13 @@ fn reject_proposal_unauth_default_twice() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -562,6 +870,13 @@ fn reject_proposal_unauth_default_twice() -> StdResult<()> {
                     daily_rewards: str_to_dec("86400000000000"),
                     emission_type: EmissionType::Spending,
                     owner: ProjectAccount::Owner.into(),
+                    max_staked_per_address: None,
+                    max_total_staked: None,
+                    min_stake_duration: None,
+                    restake_cooldown: None,
+                    fixed_pool: None,
+                    hybrid_emission: None,
+                    reward_curve: None,
                 },
             },
         },
@@ -611,6 +926,13 @@ fn accept_proposal_unauth_underfunded_default_twice() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -621,6 +943,13 @@ fn accept_proposal_unauth_underfunded_default_twice() -> StdResult<()> {
         daily_rewards: str_to_dec("86400000000000"),
         emission_type: EmissionType::Spending,
         owner: ProjectAccount::Owner.into(),
+        max_staked_per_address: None,
+        max_total_staked: None,
+        min_stake_duration: None,
+        restake_cooldown: None,
+        fixed_pool: None,
+        hybrid_emission: None,
+        reward_curve: None,
     };
 
     let expected: Vec<QueryProposalsResponseItem> = vec![QueryProposalsResponseItem {
@@ -675,6 +1004,10 @@ fn accept_proposal_unauth_underfunded_default_twice() -> StdResult<()> {
     assert_that(&collections).is_equal_to(vec![QueryCollectionsResponseItem {
         address: ProjectNft::Gopniks.into(),
         collection,
+        pause_date: None,
+        protocol_fee: str_to_dec("0"),
+        vesting_policy: None,
+        total_staked: Uint128::zero(),
     }]);
 
     Ok(())
@@ -704,6 +1037,13 @@ fn accept_proposal_add_same_collection_twice() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -728,6 +1068,13 @@ fn accept_proposal_add_same_collection_twice() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -752,6 +1099,13 @@ fn accept_proposal_add_same_collection_twice() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -805,6 +1159,13 @@ fn accept_proposal_update_collection_to_replace_it() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -830,6 +1191,13 @@ fn accept_proposal_update_collection_to_replace_it() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -847,6 +1215,7 @@ fn accept_proposal_update_collection_to_replace_it() -> StdResult<()> {
         proposal_type: ProposalType::UpdateCollection {
             collection_address: ProjectNft::Gopniks.to_string(),
             new_collection_address: Some(ProjectNft::Pinjeons.to_string()),
+            token_id_mapping: None,
             new_collection: Collection {
                 name: ProjectNft::Pinjeons.to_string(),
                 staking_currency: Currency::new(
@@ -856,6 +1225,13 @@ fn accept_proposal_update_collection_to_replace_it() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -896,100 +1272,15 @@ fn accept_proposal_update_collection_to_replace_it() -> StdResult<()> {
 }
 
 #[test]
-fn accept_proposal_and_pay_with_cw20_tokens() -> StdResult<()> {
-    let mut project = Project::new();
-
-    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
-        proposal_status: None,
-        price: Funds::new(
-            100u128,
-            &Currency::new(
-                &TokenUnverified::new_cw20(&ProjectToken::Luna.to_string()),
-                6,
-            ),
-        ),
-        proposal_type: ProposalType::AddCollection {
-            collection_address: ProjectNft::Gopniks.to_string(),
-            collection: Collection {
-                name: ProjectNft::Gopniks.to_string(),
-                staking_currency: Currency::new(
-                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
-                    6,
-                ),
-                daily_rewards: str_to_dec("86400000000000"),
-                emission_type: EmissionType::Spending,
-                owner: ProjectAccount::Owner.to_string(),
-            },
-        },
-    };
-
-    let collection = Collection {
-        name: ProjectNft::Gopniks.to_string(),
-        staking_currency: Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6),
-        daily_rewards: str_to_dec("86400000000000"),
-        emission_type: EmissionType::Spending,
-        owner: ProjectAccount::Owner.into(),
-    };
-
-    let expected: Vec<QueryProposalsResponseItem> = vec![QueryProposalsResponseItem {
-        id: Uint128::new(1),
-        proposal: Proposal {
-            proposal_status: Some(ProposalStatus::Accepted),
-            price: Funds::new(
-                100u128,
-                &Currency::new(&Token::new_cw20(&ProjectToken::Luna.into()), 6),
-            ),
-            proposal_type: ProposalType::AddCollection {
-                collection_address: ProjectNft::Gopniks.into(),
-                collection: collection.clone(),
-            },
-        },
-    }];
-
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
-
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        1,
-        100,
-        ProjectToken::Luna,
-    )?;
-
-    let proposals = project.staking_platform_query_proposals(None)?;
-    assert_that(&proposals).is_equal_to(&expected);
-
-    let funds = project.staking_platform_query_funds()?;
-    assert_that(&funds).is_equal_to(vec![Funds::new(
-        100u128,
-        &Currency::new(&Token::new_cw20(&ProjectToken::Luna.into()), 6),
-    )]);
-
-    let collections = project.staking_platform_query_collections(&None)?;
-    assert_that(&collections).is_equal_to(vec![QueryCollectionsResponseItem {
-        address: ProjectNft::Gopniks.into(),
-        collection,
-    }]);
-
-    Ok(())
-}
-
-#[test]
-fn lock_unlock() -> StdResult<()> {
+fn accept_proposal_update_collection_migrate_stakers() -> StdResult<()> {
     let mut project = Project::new();
 
-    let res = project
-        .staking_platform_try_lock(ProjectAccount::Alice)
-        .unwrap_err();
-    assert_error(&res, ContractError::Unauthorized);
-
-    project.staking_platform_try_lock(ProjectAccount::Admin)?;
-
-    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
             &Currency::new(
-                &TokenUnverified::new_cw20(&ProjectToken::Luna.to_string()),
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
                 6,
             ),
         ),
@@ -1001,35 +1292,22 @@ fn lock_unlock() -> StdResult<()> {
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("86400000000000"),
+                daily_rewards: str_to_dec("100000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    let res = project
-        .staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)
-        .unwrap_err();
-    assert_error(&res, ContractError::ContractIsLocked);
-
-    let res = project
-        .staking_platform_try_unlock(ProjectAccount::Alice)
-        .unwrap_err();
-    assert_error(&res, ContractError::Unauthorized);
-
-    project.staking_platform_try_unlock(ProjectAccount::Admin)?;
-
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
-
-    Ok(())
-}
-
-#[test]
-fn distribute_funds_unauth_weights_default() -> StdResult<()> {
-    let mut project = Project::new();
-
-    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+    // project migrated its NFT contract from Gopniks to Pinjeons
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -1038,134 +1316,339 @@ fn distribute_funds_unauth_weights_default() -> StdResult<()> {
                 6,
             ),
         ),
-        proposal_type: ProposalType::AddCollection {
+        proposal_type: ProposalType::UpdateCollection {
             collection_address: ProjectNft::Gopniks.to_string(),
-            collection: Collection {
-                name: ProjectNft::Gopniks.to_string(),
-                staking_currency: Currency::new(
-                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
-                    6,
-                ),
-                daily_rewards: str_to_dec("86400000000000"),
-                emission_type: EmissionType::Spending,
-                owner: ProjectAccount::Owner.to_string(),
-            },
-        },
-    };
-
-    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
-        proposal_status: None,
-        price: Funds::new(
-            222u128,
-            &Currency::new(
-                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
-                18,
-            ),
-        ),
-        proposal_type: ProposalType::AddCollection {
-            collection_address: ProjectNft::Pinjeons.to_string(),
-            collection: Collection {
+            new_collection_address: Some(ProjectNft::Pinjeons.to_string()),
+            token_id_mapping: Some(vec![
+                (Uint128::new(1), Uint128::new(101)),
+                (Uint128::new(2), Uint128::new(102)),
+                (Uint128::new(4), Uint128::new(104)),
+            ]),
+            new_collection: Collection {
                 name: ProjectNft::Pinjeons.to_string(),
                 staking_currency: Currency::new(
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("86400000000000"),
+                daily_rewards: str_to_dec("200000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
     project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
-
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
         1,
         100,
         ProjectCoin::Denom,
     )?;
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        200_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    for staker in [ProjectAccount::Alice, ProjectAccount::Bob] {
+        project.increase_allowances_nft(
+            staker,
+            project.get_staking_platform_address(),
+            ProjectNft::Gopniks,
+        );
+    }
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![
+                StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                },
+                StakedTokenInfo {
+                    token_id: Uint128::new(2),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                },
+            ],
+        }],
+    )?;
+    project.staking_platform_try_stake(
+        ProjectAccount::Bob,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(4),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    // new NFTs must be owned by the platform
+    project.mint_nft(
+        ProjectAccount::Owner,
+        project.get_staking_platform_address(),
+        ProjectNft::Pinjeons,
+        &vec![101u128, 102, 104],
+    );
+
+    let hook_receiver_code_id = project.store_hook_receiver_code();
+    let hook_receiver = project.instantiate_hook_receiver(hook_receiver_code_id);
+    project.staking_platform_try_add_hook(ProjectAccount::Admin, &hook_receiver)?;
+
+    let delay = (12 * 60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
         2,
-        222u128,
-        ProjectToken::Inj,
+        100,
+        ProjectCoin::Denom,
     )?;
 
+    let migrations = project.staking_platform_query_collection_migrations()?;
+    assert_that(&migrations.len()).is_equal_to(1);
+    assert_that(&migrations[0].migration.last_staker).is_equal_to(None);
+
     let res = project
-        .staking_platform_try_distribute_funds(
-            ProjectAccount::Alice,
-            &[(ProjectAccount::Alice, "0.5"), (ProjectAccount::Bob, "0.5")],
+        .staking_platform_try_stake(
+            ProjectAccount::Bob,
+            &[StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(5),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            }],
         )
         .unwrap_err();
+    assert_error(&res, ContractError::CollectionMigrationInProgress);
+
+    let res = project
+        .staking_platform_try_remove_collection(ProjectAccount::Admin, ProjectNft::Gopniks)
+        .unwrap_err();
+    assert_error(&res, ContractError::CollectionMigrationInProgress);
+
+    let res = project
+        .staking_platform_try_migrate_collection(ProjectAccount::Alice, ProjectNft::Gopniks, None)
+        .unwrap_err();
     assert_error(&res, ContractError::Unauthorized);
 
+    let get_atom_balance = |project: &Project, account: ProjectAccount| -> u128 {
+        project
+            .query_all_balances(account)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let alice_atom_balance = get_atom_balance(&project, ProjectAccount::Alice);
+    let bob_atom_balance = get_atom_balance(&project, ProjectAccount::Bob);
+
+    // migrate single staker per batch
+    project.staking_platform_try_migrate_collection(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        Some(1),
+    )?;
+
+    let migrations = project.staking_platform_query_collection_migrations()?;
+    assert_that(&migrations[0].migration.last_staker)
+        .is_equal_to(Some(Addr::from(ProjectAccount::Alice)));
+
+    // 2 nft * 0.1 atom * 0.5 days
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Alice) - alice_atom_balance))
+        .is_equal_to(100_000);
+    assert_that(&get_atom_balance(&project, ProjectAccount::Bob)).is_equal_to(bob_atom_balance);
+
+    let alice_staked = &project
+        .staking_platform_query_stakers(&Some(vec![ProjectAccount::Alice]))?[0]
+        .staked_collection_info_list;
+    assert_that(&alice_staked.len()).is_equal_to(1);
+    assert_that(&alice_staked[0].collection_address)
+        .is_equal_to(Addr::unchecked(ProjectNft::Pinjeons.to_string()));
+    assert_that(
+        &alice_staked[0]
+            .staked_token_info_list
+            .iter()
+            .map(|x| x.token_id.u128())
+            .collect::<Vec<u128>>(),
+    )
+    .is_equal_to(vec![101, 102]);
+
+    project.staking_platform_try_migrate_collection(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        Some(1),
+    )?;
+    project.staking_platform_try_migrate_collection(
+        ProjectAccount::Admin,
+        ProjectNft::Gopniks,
+        Some(1),
+    )?;
+
+    // 1 nft * 0.1 atom * 0.5 days
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Bob) - bob_atom_balance))
+        .is_equal_to(50_000);
+
+    // hooks get migration as unstaking of old tokens and staking of new ones
+    let get_hook_msg = |staker: ProjectAccount,
+                        collection: ProjectNft,
+                        token_ids: &[u128],
+                        direction: StakeDirection| StakeChangedHookMsg {
+        staker: staker.into(),
+        collection_address: collection.into(),
+        token_ids: token_ids.iter().map(|x| Uint128::new(*x)).collect(),
+        direction,
+    };
+    assert_that(&project.hook_receiver_query_msgs(&hook_receiver)?).is_equal_to(vec![
+        get_hook_msg(
+            ProjectAccount::Alice,
+            ProjectNft::Gopniks,
+            &[1, 2],
+            StakeDirection::Unstake,
+        ),
+        get_hook_msg(
+            ProjectAccount::Alice,
+            ProjectNft::Pinjeons,
+            &[101, 102],
+            StakeDirection::Stake,
+        ),
+        get_hook_msg(
+            ProjectAccount::Bob,
+            ProjectNft::Gopniks,
+            &[4],
+            StakeDirection::Unstake,
+        ),
+        get_hook_msg(
+            ProjectAccount::Bob,
+            ProjectNft::Pinjeons,
+            &[104],
+            StakeDirection::Stake,
+        ),
+    ]);
+
+    // old collection is removed, unused funds are moved to new collection balances
+    assert_that(&project.staking_platform_query_collection_migrations()?).is_equal_to(vec![]);
+
+    let collections = project.staking_platform_query_collections(&None)?;
+    assert_that(&collections.len()).is_equal_to(1);
+    assert_that(&collections[0].address)
+        .is_equal_to(Addr::unchecked(ProjectNft::Pinjeons.to_string()));
+
+    let collections_balances = project.staking_platform_query_collections_balances(&None)?;
+    assert_that(&collections_balances.len()).is_equal_to(1);
+    assert_that(&collections_balances[0].funds.amount.u128()).is_equal_to(50_000);
+
     let res = project
-        .staking_platform_try_distribute_funds(
-            ProjectAccount::Admin,
-            &[(ProjectAccount::Alice, "1.5"), (ProjectAccount::Bob, "0.5")],
+        .staking_platform_try_migrate_collection(ProjectAccount::Owner, ProjectNft::Gopniks, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::CollectionMigrationIsNotFound);
+
+    // new NFTs are unstaked with rewards of new collection
+    project.wait(delay / 4);
+
+    project.staking_platform_try_unstake(
+        ProjectAccount::Bob,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(104),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    // 1 nft * 0.1 atom * 0.5 days + 1 nft * 0.2 atom * 0.125 days
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Bob) - bob_atom_balance))
+        .is_equal_to(75_000);
+
+    let bob_pinjeons = project
+        .query_all_nft(ProjectAccount::Bob)
+        .into_iter()
+        .find(|(collection, _)| collection.to_string() == ProjectNft::Pinjeons.to_string())
+        .unwrap()
+        .1
+        .tokens;
+    assert_that(&bob_pinjeons.contains(&"104".to_string())).is_equal_to(true);
+
+    // old NFTs left on the platform are returned to collection owner
+    let res = project
+        .staking_platform_try_return_migrated_nfts(
+            ProjectAccount::Alice,
+            ProjectNft::Gopniks,
+            &[1, 2, 4],
+            ProjectAccount::Alice,
         )
         .unwrap_err();
-    assert_error(&res, ContractError::WeightIsOutOfRange);
+    assert_error(&res, ContractError::Unauthorized);
 
     let res = project
-        .staking_platform_try_distribute_funds(
+        .staking_platform_try_return_migrated_nfts(
             ProjectAccount::Admin,
-            &[(ProjectAccount::Alice, "0.4"), (ProjectAccount::Bob, "0.5")],
+            ProjectNft::Pinjeons,
+            &[101],
+            ProjectAccount::Owner,
         )
         .unwrap_err();
-    assert_error(&res, ContractError::WeightsAreUnbalanced);
+    assert_error(&res, ContractError::CollectionDuplication);
 
-    project.staking_platform_try_distribute_funds(
+    project.staking_platform_try_return_migrated_nfts(
         ProjectAccount::Admin,
-        &[
-            (ProjectAccount::Alice, "0.33"),
-            (ProjectAccount::Bob, "0.67"),
-        ],
+        ProjectNft::Gopniks,
+        &[1, 2, 4],
+        ProjectAccount::Owner,
     )?;
 
-    let balances_alice = project.query_all_balances(ProjectAccount::Alice)?;
-    let balance_alice_denom = balances_alice
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-    let balance_alice_inj = balances_alice
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Inj.into()))
-        .unwrap()
-        .amount
-        .u128();
-
-    let balances_bob = project.query_all_balances(ProjectAccount::Bob)?;
-    let balance_bob_denom = balances_bob
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-    let balance_bob_inj = balances_bob
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Inj.into()))
+    let owner_gopniks = project
+        .query_all_nft(ProjectAccount::Owner)
+        .into_iter()
+        .find(|(collection, _)| collection.to_string() == ProjectNft::Gopniks.to_string())
         .unwrap()
-        .amount
-        .u128();
+        .1
+        .tokens;
+    assert_that(&owner_gopniks).is_equal_to(vec![
+        "1".to_string(),
+        "2".to_string(),
+        "4".to_string(),
+    ]);
 
-    assert_that(&balance_alice_denom).is_equal_to(1000033);
-    assert_that(&balance_alice_inj).is_equal_to(1000000000000000073);
-    assert_that(&balance_bob_denom).is_equal_to(1000067);
-    assert_that(&balance_bob_inj).is_equal_to(1000000000000000148);
+    let res = project
+        .staking_platform_try_return_migrated_nfts(
+            ProjectAccount::Admin,
+            ProjectNft::Gopniks,
+            &[1],
+            ProjectAccount::Owner,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::MigratedTokenIsNotFound);
 
     Ok(())
 }
 
 #[test]
-fn remove_collection_unauth_default() -> StdResult<()> {
+fn accept_proposal_transfer_collection_ownership() -> StdResult<()> {
     let mut project = Project::new();
 
-    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -1185,12 +1668,43 @@ fn remove_collection_unauth_default() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::TransferCollectionOwnership {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            owner: ProjectAccount::Owner.to_string(),
+            new_owner: ProjectAccount::Bob.to_string(),
+        },
+    };
+
+    let proposal_c: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_type: ProposalType::TransferCollectionOwnership {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            owner: ProjectAccount::Alice.to_string(),
+            new_owner: ProjectAccount::Bob.to_string(),
+        },
+        ..proposal_b.to_owned()
+    };
 
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
         1,
@@ -1199,23 +1713,87 @@ fn remove_collection_unauth_default() -> StdResult<()> {
     )?;
 
     let res = project
-        .staking_platform_try_remove_collection(ProjectAccount::Alice, ProjectNft::Gopniks)
+        .staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_c)
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongCollectionOwner);
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+
+    let res = project
+        .staking_platform_try_accept_proposal(ProjectAccount::Alice, 2, 100, ProjectCoin::Denom)
         .unwrap_err();
     assert_error(&res, ContractError::Unauthorized);
 
-    project.staking_platform_try_remove_collection(ProjectAccount::Admin, ProjectNft::Gopniks)?;
+    // new owner must pay listing price
+    let res = project
+        .staking_platform_try_accept_proposal(ProjectAccount::Bob, 2, 0, ProjectCoin::Denom)
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongFundsCombination);
+
+    // current owner approves without payment
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        0,
+        ProjectCoin::Denom,
+    )?;
+
+    let res = project
+        .staking_platform_try_accept_proposal(ProjectAccount::Owner, 2, 0, ProjectCoin::Denom)
+        .unwrap_err();
+    assert_error(&res, ContractError::ProposalIsAlreadyApproved);
+
+    let proposals = project.staking_platform_query_proposals(Some(1))?;
+    assert_that(&proposals[0].proposal.proposal_status).is_equal_to(Some(ProposalStatus::Active));
 
     let collections = project.staking_platform_query_collections(&None)?;
-    assert_that(&collections).is_equal_to(vec![]);
+    assert_that(&collections[0].collection.owner).is_equal_to(Addr::from(ProjectAccount::Owner));
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Bob,
+        2,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    let proposals = project.staking_platform_query_proposals(Some(1))?;
+    assert_that(&proposals[0].proposal.proposal_status).is_equal_to(Some(ProposalStatus::Accepted));
+
+    let collections = project.staking_platform_query_collections(&None)?;
+    assert_that(&collections[0].collection.owner).is_equal_to(Addr::from(ProjectAccount::Bob));
+
+    let funds = project.staking_platform_query_funds()?;
+    assert_that(&funds).is_equal_to(vec![Funds::new(
+        200u128,
+        &Currency::new(&Token::new_native(&ProjectCoin::Denom.to_string()), 6),
+    )]);
+
+    // only new owner can manage collection balance
+    let res = project
+        .staking_platform_try_deposit_tokens(
+            ProjectAccount::Owner,
+            ProjectNft::Gopniks,
+            1_000u128,
+            ProjectToken::Atom,
+        )
+        .is_err();
+    assert_that(&res).is_equal_to(true);
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Bob,
+        ProjectNft::Gopniks,
+        1_000u128,
+        ProjectToken::Atom,
+    )?;
 
     Ok(())
 }
 
 #[test]
-fn deposit_tokens_unauth_default() -> StdResult<()> {
+fn accept_proposal_pause_and_resume_collection() -> StdResult<()> {
     let mut project = Project::new();
 
-    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -1232,68 +1810,152 @@ fn deposit_tokens_unauth_default() -> StdResult<()> {
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("86400000000000"),
+                daily_rewards: str_to_dec("1440000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
-
-    project.staking_platform_try_accept_proposal(
+    // pausing is free
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            0u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::PauseCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            owner: ProjectAccount::Owner.to_string(),
+        },
+    };
+
+    let proposal_c: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_type: ProposalType::ResumeCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            owner: ProjectAccount::Owner.to_string(),
+        },
+        ..proposal_b.to_owned()
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
         1,
         100,
         ProjectCoin::Denom,
     )?;
 
-    let res = project
-        .staking_platform_try_deposit_tokens(
-            ProjectAccount::Admin,
-            ProjectNft::Gopniks,
-            1_000u128,
-            ProjectToken::Atom,
-        )
-        .is_err();
-    assert_that(&res).is_equal_to(true);
-
     project.staking_platform_try_deposit_tokens(
         ProjectAccount::Owner,
         ProjectNft::Gopniks,
-        1_000u128,
+        500_000u128,
         ProjectToken::Atom,
     )?;
 
-    let collections_balances = project.staking_platform_query_collections_balances(&None)?;
-    assert_that(&collections_balances).is_equal_to(vec![QueryCollectionsBalancesResponseItem {
-        address: ProjectNft::Gopniks.into(),
-        funds: Funds::new(
-            1_000u128,
-            &Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6),
-        ),
-    }]);
+    let res = project
+        .staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_c)
+        .unwrap_err();
+    assert_error(&res, ContractError::CollectionIsNotPaused);
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    let delay = (60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        0,
+        ProjectCoin::Denom,
+    )?;
+
+    let collections = project.staking_platform_query_collections(&None)?;
+    assert_that(&collections[0].pause_date).is_equal_to(Some(project.get_timestamp()));
+
+    // rewards aren't accumulated during pause
+    project.wait(delay);
+
+    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    assert_that(&alice_rewards.funds_list[0].amount.u128()).is_equal_to(60_000);
+
+    let res = project
+        .staking_platform_try_stake(
+            ProjectAccount::Alice,
+            &[StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(2),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            }],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::CollectionIsPaused);
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_c)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        3,
+        0,
+        ProjectCoin::Denom,
+    )?;
+
+    let collections = project.staking_platform_query_collections(&None)?;
+    assert_that(&collections[0].pause_date).is_equal_to(None);
+
+    project.wait(delay / 2);
+
+    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    assert_that(&alice_rewards.funds_list[0].amount.u128()).is_equal_to(90_000);
+
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+
+    let alice_associated_balances =
+        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
+    assert_that(&alice_associated_balances.funds_list[0].amount.u128()).is_equal_to(1_090_000);
 
     Ok(())
 }
 
 #[test]
-fn deposit_tokens_improper_emission_type() -> StdResult<()> {
+fn accept_proposal_and_pay_with_cw20_tokens() -> StdResult<()> {
     let mut project = Project::new();
 
-    project.minter_try_create_denom(
-        ProjectAccount::Admin,
-        ProjectAccount::Owner,
-        ProjectCoin::Noria,
-        (1, ProjectCoin::Denom),
-    )?;
-
     let proposal: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
             &Currency::new(
-                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                &TokenUnverified::new_cw20(&ProjectToken::Luna.to_string()),
                 6,
             ),
         ),
@@ -1302,48 +1964,121 @@ fn deposit_tokens_improper_emission_type() -> StdResult<()> {
             collection: Collection {
                 name: ProjectNft::Gopniks.to_string(),
                 staking_currency: Currency::new(
-                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
                 daily_rewards: str_to_dec("86400000000000"),
-                emission_type: EmissionType::Minting,
+                emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
+    let collection = Collection {
+        name: ProjectNft::Gopniks.to_string(),
+        staking_currency: Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6),
+        daily_rewards: str_to_dec("86400000000000"),
+        emission_type: EmissionType::Spending,
+        owner: ProjectAccount::Owner.into(),
+        max_staked_per_address: None,
+        max_total_staked: None,
+        min_stake_duration: None,
+        restake_cooldown: None,
+        fixed_pool: None,
+        hybrid_emission: None,
+        reward_curve: None,
+    };
+
+    let expected: Vec<QueryProposalsResponseItem> = vec![QueryProposalsResponseItem {
+        id: Uint128::new(1),
+        proposal: Proposal {
+            proposal_status: Some(ProposalStatus::Accepted),
+            price: Funds::new(
+                100u128,
+                &Currency::new(&Token::new_cw20(&ProjectToken::Luna.into()), 6),
+            ),
+            proposal_type: ProposalType::AddCollection {
+                collection_address: ProjectNft::Gopniks.into(),
+                collection: collection.clone(),
+            },
+        },
+    }];
+
     project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
         1,
         100,
-        ProjectCoin::Denom,
+        ProjectToken::Luna,
     )?;
 
-    let res = project
-        .staking_platform_try_deposit_tokens(
-            ProjectAccount::Owner,
-            ProjectNft::Gopniks,
-            1_000u128,
-            ProjectCoin::Noria,
-        )
-        .unwrap_err();
-    assert_error(&res, ContractError::ActionByEmissionType);
+    let proposals = project.staking_platform_query_proposals(None)?;
+    assert_that(&proposals).is_equal_to(&expected);
+
+    let funds = project.staking_platform_query_funds()?;
+    assert_that(&funds).is_equal_to(vec![Funds::new(
+        100u128,
+        &Currency::new(&Token::new_cw20(&ProjectToken::Luna.into()), 6),
+    )]);
+
+    let collections = project.staking_platform_query_collections(&None)?;
+    assert_that(&collections).is_equal_to(vec![QueryCollectionsResponseItem {
+        address: ProjectNft::Gopniks.into(),
+        collection,
+        pause_date: None,
+        protocol_fee: str_to_dec("0"),
+        vesting_policy: None,
+        total_staked: Uint128::zero(),
+    }]);
 
     Ok(())
 }
 
 #[test]
-fn withdraw_tokens_unauth_default() -> StdResult<()> {
+fn pause_resume_actions() -> StdResult<()> {
     let mut project = Project::new();
 
+    let res = project
+        .staking_platform_try_pause_actions(
+            ProjectAccount::Alice,
+            &[ActionGroup::Proposals],
+            None,
+            "incident",
+            None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    project.staking_platform_try_pause_actions(
+        ProjectAccount::Admin,
+        &[ActionGroup::Proposals],
+        None,
+        "incident",
+        None,
+    )?;
+
+    let paused_actions = project.staking_platform_query_paused_actions()?;
+    assert_that(&paused_actions).is_equal_to(vec![ActionPause {
+        action_group: ActionGroup::Proposals,
+        collection_address: None,
+        reason: "incident".to_string(),
+        expiration_date: None,
+    }]);
+
     let proposal: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
             &Currency::new(
-                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                &TokenUnverified::new_cw20(&ProjectToken::Luna.to_string()),
                 6,
             ),
         ),
@@ -1358,64 +2093,49 @@ fn withdraw_tokens_unauth_default() -> StdResult<()> {
                 daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
-
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        1,
-        100,
-        ProjectCoin::Denom,
-    )?;
-
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Gopniks,
-        1_000u128,
-        ProjectToken::Atom,
-    )?;
+    let res = project
+        .staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)
+        .unwrap_err();
+    assert_error(
+        &res,
+        ContractError::ActionIsPaused {
+            reason: "incident".to_string(),
+        },
+    );
 
     let res = project
-        .staking_platform_try_withdraw_tokens(ProjectAccount::Admin, ProjectNft::Gopniks, 500u128)
+        .staking_platform_try_resume_actions(ProjectAccount::Alice, &[ActionGroup::Proposals], None)
         .unwrap_err();
     assert_error(&res, ContractError::Unauthorized);
 
-    project.staking_platform_try_withdraw_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Gopniks,
-        111u128,
+    project.staking_platform_try_resume_actions(
+        ProjectAccount::Admin,
+        &[ActionGroup::Proposals],
+        None,
     )?;
+    assert_that(&project.staking_platform_query_paused_actions()?).is_equal_to(vec![]);
 
-    let collections_balances = project.staking_platform_query_collections_balances(&None)?;
-    assert_that(&collections_balances).is_equal_to(vec![QueryCollectionsBalancesResponseItem {
-        address: ProjectNft::Gopniks.into(),
-        funds: Funds::new(
-            889u128,
-            &Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6),
-        ),
-    }]);
-
-    let owner_balances = project.query_all_balances(ProjectAccount::Owner)?;
-    let balance_owner_atom = owner_balances
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
-
-    assert_that(&balance_owner_atom).is_equal_to(999111);
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
 
     Ok(())
 }
 
 #[test]
-fn stake_2_users_2_collections() -> StdResult<()> {
+fn pause_claiming_without_trapping_nfts() -> StdResult<()> {
     let mut project = Project::new();
 
-    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -1432,64 +2152,33 @@ fn stake_2_users_2_collections() -> StdResult<()> {
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("1000000"),
+                daily_rewards: str_to_dec("100000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
-        proposal_status: None,
-        price: Funds::new(
-            222u128,
-            &Currency::new(
-                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
-                18,
-            ),
-        ),
-        proposal_type: ProposalType::AddCollection {
-            collection_address: ProjectNft::Pinjeons.to_string(),
-            collection: Collection {
-                name: ProjectNft::Pinjeons.to_string(),
-                staking_currency: Currency::new(
-                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
-                    6,
-                ),
-                daily_rewards: str_to_dec("500000"),
-                emission_type: EmissionType::Spending,
-                owner: ProjectAccount::Owner.to_string(),
-            },
-        },
-    };
-
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
-
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
         1,
         100,
         ProjectCoin::Denom,
     )?;
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        2,
-        222u128,
-        ProjectToken::Inj,
-    )?;
 
     project.staking_platform_try_deposit_tokens(
         ProjectAccount::Owner,
         ProjectNft::Gopniks,
-        1_000_000u128,
-        ProjectToken::Atom,
-    )?;
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Pinjeons,
         500_000u128,
-        ProjectCoin::Noria,
+        ProjectToken::Atom,
     )?;
 
     project.increase_allowances_nft(
@@ -1497,65 +2186,11 @@ fn stake_2_users_2_collections() -> StdResult<()> {
         project.get_staking_platform_address(),
         ProjectNft::Gopniks,
     );
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Pinjeons,
-    );
-    project.increase_allowances_nft(
-        ProjectAccount::Bob,
-        project.get_staking_platform_address(),
-        ProjectNft::Gopniks,
-    );
-    project.increase_allowances_nft(
-        ProjectAccount::Bob,
-        project.get_staking_platform_address(),
-        ProjectNft::Pinjeons,
-    );
 
     project.staking_platform_try_stake(
         ProjectAccount::Alice,
         &[StakedCollectionInfo {
             collection_address: ProjectNft::Gopniks.to_string(),
-            staked_token_info_list: vec![StakedTokenInfo {
-                token_id: Uint128::new(1),
-                staking_start_date: None,
-                last_claim_date: None,
-            }],
-        }],
-    )?;
-
-    let delay = (10 * MINS_PER_DAY * NANOS_PER_MIN) as u64;
-    project.wait(delay);
-
-    project.staking_platform_try_stake(
-        ProjectAccount::Bob,
-        &[
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(4),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            },
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(5),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            },
-        ],
-    )?;
-
-    project.wait(delay);
-
-    project.staking_platform_try_stake(
-        ProjectAccount::Alice,
-        &[StakedCollectionInfo {
-            collection_address: ProjectNft::Pinjeons.to_string(),
             staked_token_info_list: vec![
                 StakedTokenInfo {
                     token_id: Uint128::new(1),
@@ -1571,99 +2206,106 @@ fn stake_2_users_2_collections() -> StdResult<()> {
         }],
     )?;
 
-    project.wait(delay);
+    let day = (MINS_PER_DAY * NANOS_PER_MIN) as u64;
+    let expiration_date = project.get_timestamp().plus_nanos(day);
 
-    project.staking_platform_try_stake(
-        ProjectAccount::Bob,
-        &[StakedCollectionInfo {
-            collection_address: ProjectNft::Pinjeons.to_string(),
-            staked_token_info_list: vec![
-                StakedTokenInfo {
-                    token_id: Uint128::new(4),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                },
-                StakedTokenInfo {
-                    token_id: Uint128::new(5),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                },
-                StakedTokenInfo {
-                    token_id: Uint128::new(6),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                },
-            ],
-        }],
+    let res = project
+        .staking_platform_try_pause_actions(
+            ProjectAccount::Admin,
+            &[ActionGroup::Claiming],
+            Some(ProjectNft::Gopniks),
+            "incident",
+            Some(project.get_timestamp()),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperExpirationDate);
+
+    project.staking_platform_try_pause_actions(
+        ProjectAccount::Admin,
+        &[ActionGroup::Claiming],
+        Some(ProjectNft::Gopniks),
+        "incident",
+        Some(expiration_date),
     )?;
 
-    project.wait(delay);
+    project.wait(day / 2);
+
+    let get_atom_balance = |project: &Project| -> u128 {
+        project
+            .query_all_balances(ProjectAccount::Alice)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let alice_atom_balance = get_atom_balance(&project);
 
-    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
-    let alice_rewards_atom = alice_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
-    let alice_rewards_noria = alice_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
-        .unwrap()
-        .amount
-        .u128();
+    let res = project
+        .staking_platform_try_claim_staking_rewards(
+            ProjectAccount::Alice,
+            &Some(ProjectNft::Gopniks),
+        )
+        .unwrap_err();
+    assert_error(
+        &res,
+        ContractError::ActionIsPaused {
+            reason: "incident".to_string(),
+        },
+    );
 
-    let bob_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Bob)?;
-    let bob_rewards_atom = bob_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
-    let bob_rewards_noria = bob_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+    // paused collection is skipped
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&get_atom_balance(&project)).is_equal_to(alice_atom_balance);
+
+    // NFT is unstaked, its rewards are kept until claiming is resumed
+    project.staking_platform_try_unstake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+    assert_that(&get_atom_balance(&project)).is_equal_to(alice_atom_balance);
+
+    let alice_gopniks = project
+        .query_all_nft(ProjectAccount::Alice)
+        .into_iter()
+        .find(|(collection, _)| collection.to_string() == ProjectNft::Gopniks.to_string())
         .unwrap()
-        .amount
-        .u128();
+        .1
+        .tokens;
+    assert_that(&alice_gopniks.contains(&"1".to_string())).is_equal_to(true);
 
-    // 1 nft * 1 atom * 40 days
-    assert_that(&alice_rewards_atom).is_equal_to(40_000_000);
-    // 2 nft * 0.5 noria * 20 days
-    assert_that(&alice_rewards_noria).is_equal_to(20_000_000);
-    // 2 nft * 1 atom * 30 days
-    assert_that(&bob_rewards_atom).is_equal_to(60_000_000);
-    // 3 nft * 0.5 noria * 10 days
-    assert_that(&bob_rewards_noria).is_equal_to(15_000_000);
+    // pause expires automatically
+    project.wait(day / 2);
+    assert_that(&project.staking_platform_query_paused_actions()?).is_equal_to(vec![]);
 
-    let gopniks_rewards = project.staking_platform_query_staking_rewards_per_collection(
-        ProjectAccount::Bob,
-        ProjectNft::Gopniks,
-    )?;
-    let pinjeons_rewards = project.staking_platform_query_staking_rewards_per_collection(
-        ProjectAccount::Bob,
-        ProjectNft::Pinjeons,
+    project.staking_platform_try_claim_staking_rewards(
+        ProjectAccount::Alice,
+        &Some(ProjectNft::Gopniks),
     )?;
 
-    assert_that(&gopniks_rewards.funds_list).is_equal_to(vec![Funds::new(
-        60_000_000u128,
-        &Currency::new(&ProjectToken::Atom.into(), 6),
-    )]);
+    // 1 nft * 0.1 atom * 1 day + 1 unstaked nft * 0.1 atom * 0.5 days
+    assert_that(&(get_atom_balance(&project) - alice_atom_balance)).is_equal_to(150_000);
 
-    assert_that(&pinjeons_rewards.funds_list).is_equal_to(vec![Funds::new(
-        15_000_000u128,
-        &Currency::new(&ProjectCoin::Noria.into(), 6),
-    )]);
+    // pending rewards are claimed once
+    project.staking_platform_try_claim_staking_rewards(
+        ProjectAccount::Alice,
+        &Some(ProjectNft::Gopniks),
+    )?;
+    assert_that(&(get_atom_balance(&project) - alice_atom_balance)).is_equal_to(150_000);
 
     Ok(())
 }
 
 #[test]
-fn stake_unlisted_collection() -> StdResult<()> {
+fn distribute_funds_unauth_weights_default() -> StdResult<()> {
     let mut project = Project::new();
 
     let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
@@ -1683,69 +2325,53 @@ fn stake_unlisted_collection() -> StdResult<()> {
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("1000000"),
+                daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
-
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Gopniks,
-    );
-
-    let res = project
-        .staking_platform_try_stake(
-            ProjectAccount::Alice,
-            &[StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(1),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            }],
-        )
-        .unwrap_err();
-
-    assert_error(&res, ContractError::CollectionIsNotFound);
-
-    Ok(())
-}
-
-#[test]
-fn stake_unallowed_unfunded_delayed() -> StdResult<()> {
-    let mut project = Project::new();
-
-    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
-            100u128,
+            222u128,
             &Currency::new(
-                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
-                6,
+                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
+                18,
             ),
         ),
         proposal_type: ProposalType::AddCollection {
-            collection_address: ProjectNft::Gopniks.to_string(),
+            collection_address: ProjectNft::Pinjeons.to_string(),
             collection: Collection {
-                name: ProjectNft::Gopniks.to_string(),
+                name: ProjectNft::Pinjeons.to_string(),
                 staking_currency: Currency::new(
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("1000000"),
+                daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
     project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
@@ -1753,68 +2379,87 @@ fn stake_unallowed_unfunded_delayed() -> StdResult<()> {
         100,
         ProjectCoin::Denom,
     )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        222u128,
+        ProjectToken::Inj,
+    )?;
 
     let res = project
-        .staking_platform_try_stake(
+        .staking_platform_try_distribute_funds(
             ProjectAccount::Alice,
-            &[StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(1),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            }],
+            Some(&[(ProjectAccount::Alice, "0.5"), (ProjectAccount::Bob, "0.5")]),
+            None,
         )
-        .is_err();
-    assert_that(&res).is_equal_to(true);
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
 
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Gopniks,
-    );
-
-    let delay = (10 * MINS_PER_DAY * NANOS_PER_MIN) as u64;
+    let res = project
+        .staking_platform_try_distribute_funds(
+            ProjectAccount::Admin,
+            Some(&[(ProjectAccount::Alice, "1.5"), (ProjectAccount::Bob, "0.5")]),
+            None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WeightIsOutOfRange);
 
-    project.staking_platform_try_stake(
-        ProjectAccount::Alice,
-        &[StakedCollectionInfo {
-            collection_address: ProjectNft::Gopniks.to_string(),
-            staked_token_info_list: vec![StakedTokenInfo {
-                token_id: Uint128::new(1),
-                staking_start_date: Some(project.get_timestamp().plus_nanos(delay)),
-                last_claim_date: Some(project.get_timestamp().plus_nanos(delay)),
-            }],
-        }],
-    )?;
+    let res = project
+        .staking_platform_try_distribute_funds(
+            ProjectAccount::Admin,
+            Some(&[(ProjectAccount::Alice, "0.4"), (ProjectAccount::Bob, "0.5")]),
+            None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WeightsAreUnbalanced);
 
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Gopniks,
-        1_000_000u128,
-        ProjectToken::Atom,
+    project.staking_platform_try_distribute_funds(
+        ProjectAccount::Admin,
+        Some(&[
+            (ProjectAccount::Alice, "0.33"),
+            (ProjectAccount::Bob, "0.67"),
+        ]),
+        None,
     )?;
 
-    project.wait(delay);
+    let balances_alice = project.query_all_balances(ProjectAccount::Alice)?;
+    let balance_alice_denom = balances_alice
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+    let balance_alice_inj = balances_alice
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Inj.into()))
+        .unwrap()
+        .amount
+        .u128();
 
-    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
-    let alice_rewards_atom = alice_rewards
-        .funds_list
+    let balances_bob = project.query_all_balances(ProjectAccount::Bob)?;
+    let balance_bob_denom = balances_bob
         .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+    let balance_bob_inj = balances_bob
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Inj.into()))
         .unwrap()
         .amount
         .u128();
 
-    // 1 nft * 1 atom * 10 days
-    assert_that(&alice_rewards_atom).is_equal_to(10_000_000);
+    assert_that(&balance_alice_denom).is_equal_to(1000033);
+    assert_that(&balance_alice_inj).is_equal_to(1000000000000000073);
+    assert_that(&balance_bob_denom).is_equal_to(1000067);
+    assert_that(&balance_bob_inj).is_equal_to(1000000000000000148);
 
     Ok(())
 }
 
 #[test]
-fn claim_staking_rewards_default() -> StdResult<()> {
+fn distribute_funds_with_revenue_split() -> StdResult<()> {
     let mut project = Project::new();
 
     let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
@@ -1834,14 +2479,53 @@ fn claim_staking_rewards_default() -> StdResult<()> {
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("1000000"),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            222u128,
+            &Currency::new(
+                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
+                18,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            collection: Collection {
+                name: ProjectNft::Pinjeons.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
     project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
@@ -1849,82 +2533,175 @@ fn claim_staking_rewards_default() -> StdResult<()> {
         100,
         ProjectCoin::Denom,
     )?;
-
-    project.staking_platform_try_deposit_tokens(
+    project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
-        ProjectNft::Gopniks,
-        500_000u128,
-        ProjectToken::Atom,
+        2,
+        222u128,
+        ProjectToken::Inj,
     )?;
 
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Gopniks,
-    );
+    let res = project
+        .staking_platform_try_distribute_funds(ProjectAccount::Admin, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::RevenueSplitIsNotFound);
 
-    project.staking_platform_try_stake(
-        ProjectAccount::Alice,
-        &[StakedCollectionInfo {
-            collection_address: ProjectNft::Gopniks.to_string(),
-            staked_token_info_list: vec![StakedTokenInfo {
-                token_id: Uint128::new(1),
-                staking_start_date: None,
-                last_claim_date: None,
-            }],
-        }],
+    let res = project
+        .staking_platform_try_update_revenue_split(
+            ProjectAccount::Alice,
+            &[
+                (ProjectAccount::Alice, "0.33"),
+                (ProjectAccount::Bob, "0.67"),
+            ],
+            None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = project
+        .staking_platform_try_update_revenue_split(
+            ProjectAccount::Admin,
+            &[
+                (ProjectAccount::Alice, "0.33"),
+                (ProjectAccount::Bob, "0.6"),
+            ],
+            None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WeightsAreUnbalanced);
+
+    project.staking_platform_try_update_revenue_split(
+        ProjectAccount::Admin,
+        &[
+            (ProjectAccount::Alice, "0.33"),
+            (ProjectAccount::Bob, "0.67"),
+        ],
+        None,
     )?;
 
-    let delay = (12 * 60 * NANOS_PER_MIN) as u64;
-    project.wait(delay);
+    let revenue_split = project.staking_platform_query_revenue_split()?.unwrap();
+    assert_that(&revenue_split.address_and_weight_list).is_equal_to(vec![
+        (ProjectAccount::Alice.into(), str_to_dec("0.33")),
+        (ProjectAccount::Bob.into(), str_to_dec("0.67")),
+    ]);
 
-    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    let res = project
+        .staking_platform_try_distribute_funds(
+            ProjectAccount::Admin,
+            None,
+            Some(&[(ProjectCoin::Denom.into(), Some(101))]),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::InsufficientFunds);
 
-    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
-    let alice_rewards_atom = alice_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
+    let res = project
+        .staking_platform_try_distribute_funds(
+            ProjectAccount::Admin,
+            None,
+            Some(&[(ProjectToken::Luna.into(), None)]),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::AssetIsNotFound);
 
-    // 1 nft * 1 atom * 0.5 days
-    assert_that(&alice_rewards_atom).is_equal_to(0);
+    let get_balance = |project: &Project, account: ProjectAccount, asset: ProjectAsset| -> u128 {
+        project
+            .query_all_balances(account)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::from(asset))
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let denom: ProjectAsset = ProjectCoin::Denom.into();
+    let inj: ProjectAsset = ProjectToken::Inj.into();
+    let alice_denom_balance = get_balance(&project, ProjectAccount::Alice, denom);
+    let bob_denom_balance = get_balance(&project, ProjectAccount::Bob, denom);
 
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Gopniks,
-        200_000u128,
-        ProjectToken::Atom,
+    // distribute a part of single currency, dust is carried forward
+    project.staking_platform_try_distribute_funds(
+        ProjectAccount::Admin,
+        None,
+        Some(&[(denom, Some(50))]),
     )?;
 
-    let collections_balances = project.staking_platform_query_collections_balances(&None)?;
-    let gopniks_balance = collections_balances
+    assert_that(&(get_balance(&project, ProjectAccount::Alice, denom) - alice_denom_balance))
+        .is_equal_to(16);
+    assert_that(&(get_balance(&project, ProjectAccount::Bob, denom) - bob_denom_balance))
+        .is_equal_to(33);
+
+    let funds: Vec<u128> = project
+        .staking_platform_query_funds()?
         .iter()
-        .find(|x| x.address == ProjectNft::Gopniks.to_string())
-        .unwrap();
-    assert_that(&gopniks_balance.funds.amount.u128()).is_equal_to(200_000);
+        .map(|x| x.amount.u128())
+        .collect();
+    assert_that(&funds).is_equal_to(vec![51, 222]);
 
-    let alice_associated_balances =
-        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
-    let alice_atom_associated_balance = alice_associated_balances
-        .funds_list
+    // distribute everything sending dust to owner
+    project.staking_platform_try_update_revenue_split(
+        ProjectAccount::Admin,
+        &[
+            (ProjectAccount::Alice, "0.33"),
+            (ProjectAccount::Bob, "0.67"),
+        ],
+        Some(ProjectAccount::Owner),
+    )?;
+
+    let owner_denom_balance = get_balance(&project, ProjectAccount::Owner, denom);
+    let owner_inj_balance = get_balance(&project, ProjectAccount::Owner, inj);
+
+    project.staking_platform_try_distribute_funds(ProjectAccount::Admin, None, None)?;
+
+    assert_that(&(get_balance(&project, ProjectAccount::Owner, denom) - owner_denom_balance))
+        .is_equal_to(1);
+    assert_that(&(get_balance(&project, ProjectAccount::Owner, inj) - owner_inj_balance))
+        .is_equal_to(1);
+
+    let funds: Vec<u128> = project
+        .staking_platform_query_funds()?
         .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
-    assert_that(&alice_atom_associated_balance).is_equal_to(1_500_000);
+        .map(|x| x.amount.u128())
+        .collect();
+    assert_that(&funds).is_equal_to(vec![0, 0]);
+
+    // check history
+    let distributions = project.staking_platform_query_distributions(None, None)?;
+    let recipient_and_amount_list: Vec<Vec<(String, u128)>> = distributions
+        .iter()
+        .map(|x| {
+            x.distribution
+                .recipient_and_funds_list
+                .iter()
+                .map(|(recipient, funds)| (recipient.to_string(), funds.amount.u128()))
+                .collect()
+        })
+        .collect();
+    assert_that(&recipient_and_amount_list).is_equal_to(vec![
+        vec![
+            (ProjectAccount::Alice.to_string(), 16),
+            (ProjectAccount::Bob.to_string(), 33),
+        ],
+        vec![
+            (ProjectAccount::Alice.to_string(), 16),
+            (ProjectAccount::Bob.to_string(), 34),
+            (ProjectAccount::Owner.to_string(), 1),
+            (ProjectAccount::Alice.to_string(), 73),
+            (ProjectAccount::Bob.to_string(), 148),
+            (ProjectAccount::Owner.to_string(), 1),
+        ],
+    ]);
+
+    let distributions = project.staking_platform_query_distributions(Some(1), None)?;
+    assert_that(&distributions.len()).is_equal_to(1);
+    assert_that(&distributions[0].id.u128()).is_equal_to(2);
 
     Ok(())
 }
 
 #[test]
-fn unstake_improper_collection_and_id_default() -> StdResult<()> {
+fn remove_collection_unauth_default() -> StdResult<()> {
     let mut project = Project::new();
 
-    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -1941,14 +2718,21 @@ fn unstake_improper_collection_and_id_default() -> StdResult<()> {
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("1000000"),
+                daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
@@ -1957,118 +2741,122 @@ fn unstake_improper_collection_and_id_default() -> StdResult<()> {
         ProjectCoin::Denom,
     )?;
 
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Gopniks,
-        1_000_000u128,
-        ProjectToken::Atom,
-    )?;
-
     project.increase_allowances_nft(
         ProjectAccount::Alice,
         project.get_staking_platform_address(),
         ProjectNft::Gopniks,
     );
-
     project.staking_platform_try_stake(
         ProjectAccount::Alice,
         &[StakedCollectionInfo {
             collection_address: ProjectNft::Gopniks.to_string(),
-            staked_token_info_list: vec![
-                StakedTokenInfo {
-                    token_id: Uint128::new(1),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                },
-                StakedTokenInfo {
-                    token_id: Uint128::new(2),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                },
-            ],
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
         }],
     )?;
 
-    let delay = (6 * 60 * NANOS_PER_MIN) as u64;
-    project.wait(delay);
-
     let res = project
-        .staking_platform_try_unstake(
-            ProjectAccount::Alice,
-            &[StakedCollectionInfo {
-                collection_address: ProjectNft::Pinjeons.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(1),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            }],
-        )
+        .staking_platform_try_remove_collection(ProjectAccount::Alice, ProjectNft::Gopniks)
         .unwrap_err();
-    assert_error(&res, ContractError::CollectionIsNotFound);
+    assert_error(&res, ContractError::Unauthorized);
 
-    let res = project
-        .staking_platform_try_unstake(
-            ProjectAccount::Alice,
-            &[StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(3),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            }],
+    project.staking_platform_try_remove_collection(ProjectAccount::Admin, ProjectNft::Gopniks)?;
+
+    let collections = project.staking_platform_query_collections(&None)?;
+    assert_that(&collections).is_equal_to(vec![]);
+
+    // NFTs of stakers of removed collection can't be returned as migrated ones
+    let res = project
+        .staking_platform_try_return_migrated_nfts(
+            ProjectAccount::Admin,
+            ProjectNft::Gopniks,
+            &[1],
+            ProjectAccount::Admin,
         )
         .unwrap_err();
-    assert_error(&res, ContractError::AssetIsNotFound);
+    assert_error(&res, ContractError::MigratedTokenIsNotFound);
 
-    project.staking_platform_try_unstake(
-        ProjectAccount::Alice,
-        &[StakedCollectionInfo {
+    Ok(())
+}
+
+#[test]
+fn deposit_tokens_unauth_default() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
             collection_address: ProjectNft::Gopniks.to_string(),
-            staked_token_info_list: vec![StakedTokenInfo {
-                token_id: Uint128::new(1),
-                staking_start_date: None,
-                last_claim_date: None,
-            }],
-        }],
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("86400000000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
     )?;
 
-    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
-    let alice_rewards_atom = alice_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
+    let res = project
+        .staking_platform_try_deposit_tokens(
+            ProjectAccount::Admin,
+            ProjectNft::Gopniks,
+            1_000u128,
+            ProjectToken::Atom,
+        )
+        .is_err();
+    assert_that(&res).is_equal_to(true);
 
-    // 1 nft * 1 atom * 0.25 days
-    assert_that(&alice_rewards_atom).is_equal_to(250_000);
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        1_000u128,
+        ProjectToken::Atom,
+    )?;
 
     let collections_balances = project.staking_platform_query_collections_balances(&None)?;
-    let gopniks_balance = collections_balances
-        .iter()
-        .find(|x| x.address == ProjectNft::Gopniks.to_string())
-        .unwrap();
-    assert_that(&gopniks_balance.funds.amount.u128()).is_equal_to(750_000);
-
-    let alice_associated_balances =
-        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
-    let alice_atom_associated_balance = alice_associated_balances
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
-    assert_that(&alice_atom_associated_balance).is_equal_to(1_250_000);
+    assert_that(&collections_balances).is_equal_to(vec![QueryCollectionsBalancesResponseItem {
+        address: ProjectNft::Gopniks.into(),
+        funds: Funds::new(
+            1_000u128,
+            &Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6),
+        ),
+    }]);
 
     Ok(())
 }
 
 #[test]
-fn claim_staking_rewards_with_minter_default() -> StdResult<()> {
+fn deposit_tokens_improper_emission_type() -> StdResult<()> {
     let mut project = Project::new();
 
     project.minter_try_create_denom(
@@ -2078,7 +2866,7 @@ fn claim_staking_rewards_with_minter_default() -> StdResult<()> {
         (1, ProjectCoin::Denom),
     )?;
 
-    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -2095,14 +2883,21 @@ fn claim_staking_rewards_with_minter_default() -> StdResult<()> {
                     &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("1000000"),
+                daily_rewards: str_to_dec("86400000000000"),
                 emission_type: EmissionType::Minting,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
@@ -2111,54 +2906,107 @@ fn claim_staking_rewards_with_minter_default() -> StdResult<()> {
         ProjectCoin::Denom,
     )?;
 
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Gopniks,
-    );
+    let res = project
+        .staking_platform_try_deposit_tokens(
+            ProjectAccount::Owner,
+            ProjectNft::Gopniks,
+            1_000u128,
+            ProjectCoin::Noria,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ActionByEmissionType);
 
-    project.staking_platform_try_stake(
-        ProjectAccount::Alice,
-        &[StakedCollectionInfo {
+    Ok(())
+}
+
+#[test]
+fn withdraw_tokens_unauth_default() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
             collection_address: ProjectNft::Gopniks.to_string(),
-            staked_token_info_list: vec![StakedTokenInfo {
-                token_id: Uint128::new(1),
-                staking_start_date: None,
-                last_claim_date: None,
-            }],
-        }],
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("86400000000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
     )?;
 
-    let delay = (MINS_PER_DAY * NANOS_PER_MIN) as u64;
-    project.wait(delay);
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        1_000u128,
+        ProjectToken::Atom,
+    )?;
 
-    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    let res = project
+        .staking_platform_try_withdraw_tokens(ProjectAccount::Admin, ProjectNft::Gopniks, 500u128)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
 
-    let alice_associated_balances =
-        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
-    let alice_noria_associated_balance = alice_associated_balances
-        .funds_list
+    project.staking_platform_try_withdraw_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        111u128,
+    )?;
+
+    let collections_balances = project.staking_platform_query_collections_balances(&None)?;
+    assert_that(&collections_balances).is_equal_to(vec![QueryCollectionsBalancesResponseItem {
+        address: ProjectNft::Gopniks.into(),
+        funds: Funds::new(
+            889u128,
+            &Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6),
+        ),
+    }]);
+
+    let owner_balances = project.query_all_balances(ProjectAccount::Owner)?;
+    let balance_owner_atom = owner_balances
         .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
         .unwrap()
         .amount
         .u128();
-    assert_that(&alice_noria_associated_balance).is_equal_to(2_000_000);
+
+    assert_that(&balance_owner_atom).is_equal_to(999111);
 
     Ok(())
 }
 
 #[test]
-fn claim_staking_rewards_with_minter_and_empty_spender() -> StdResult<()> {
+fn stake_2_users_2_collections() -> StdResult<()> {
     let mut project = Project::new();
 
-    project.minter_try_create_denom(
-        ProjectAccount::Admin,
-        ProjectAccount::Owner,
-        ProjectCoin::Noria,
-        (1, ProjectCoin::Denom),
-    )?;
-
     let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
@@ -2179,6 +3027,13 @@ fn claim_staking_rewards_with_minter_and_empty_spender() -> StdResult<()> {
                 daily_rewards: str_to_dec("1000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -2201,8 +3056,15 @@ fn claim_staking_rewards_with_minter_and_empty_spender() -> StdResult<()> {
                     6,
                 ),
                 daily_rewards: str_to_dec("500000"),
-                emission_type: EmissionType::Minting,
+                emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
@@ -2226,9 +3088,15 @@ fn claim_staking_rewards_with_minter_and_empty_spender() -> StdResult<()> {
     project.staking_platform_try_deposit_tokens(
         ProjectAccount::Owner,
         ProjectNft::Gopniks,
-        100_000u128,
+        1_000_000u128,
         ProjectToken::Atom,
     )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Pinjeons,
+        500_000u128,
+        ProjectCoin::Noria,
+    )?;
 
     project.increase_allowances_nft(
         ProjectAccount::Alice,
@@ -2240,37 +3108,101 @@ fn claim_staking_rewards_with_minter_and_empty_spender() -> StdResult<()> {
         project.get_staking_platform_address(),
         ProjectNft::Pinjeons,
     );
+    project.increase_allowances_nft(
+        ProjectAccount::Bob,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+    project.increase_allowances_nft(
+        ProjectAccount::Bob,
+        project.get_staking_platform_address(),
+        ProjectNft::Pinjeons,
+    );
 
     project.staking_platform_try_stake(
         ProjectAccount::Alice,
-        &[
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(1),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            },
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    let delay = (10 * MINS_PER_DAY * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Bob,
+        &[
             StakedCollectionInfo {
-                collection_address: ProjectNft::Pinjeons.to_string(),
-                staked_token_info_list: vec![
-                    StakedTokenInfo {
-                        token_id: Uint128::new(1),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                    StakedTokenInfo {
-                        token_id: Uint128::new(2),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                ],
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(4),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            },
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(5),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
             },
         ],
     )?;
 
-    let delay = (12 * 60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            staked_token_info_list: vec![
+                StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                },
+                StakedTokenInfo {
+                    token_id: Uint128::new(2),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                },
+            ],
+        }],
+    )?;
+
+    project.wait(delay);
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Bob,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            staked_token_info_list: vec![
+                StakedTokenInfo {
+                    token_id: Uint128::new(4),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                },
+                StakedTokenInfo {
+                    token_id: Uint128::new(5),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                },
+                StakedTokenInfo {
+                    token_id: Uint128::new(6),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                },
+            ],
+        }],
+    )?;
+
     project.wait(delay);
 
     let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
@@ -2289,23 +3221,15 @@ fn claim_staking_rewards_with_minter_and_empty_spender() -> StdResult<()> {
         .amount
         .u128();
 
-    // 1 nft * 1 atom * 0.5 days
-    assert_that(&alice_rewards_atom).is_equal_to(500_000);
-    // 2 nft * 0.5 noria * 0.5 days
-    assert_that(&alice_rewards_noria).is_equal_to(500_000);
-
-    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
-
-    let alice_associated_balances =
-        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
-    let alice_atom_associated_balance = alice_associated_balances
+    let bob_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Bob)?;
+    let bob_rewards_atom = bob_rewards
         .funds_list
         .iter()
         .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
         .unwrap()
         .amount
         .u128();
-    let alice_noria_associated_balance = alice_associated_balances
+    let bob_rewards_noria = bob_rewards
         .funds_list
         .iter()
         .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
@@ -2313,23 +3237,41 @@ fn claim_staking_rewards_with_minter_and_empty_spender() -> StdResult<()> {
         .amount
         .u128();
 
-    assert_that(&alice_atom_associated_balance).is_equal_to(1_000_000);
-    assert_that(&alice_noria_associated_balance).is_equal_to(1_500_000);
+    // 1 nft * 1 atom * 40 days
+    assert_that(&alice_rewards_atom).is_equal_to(40_000_000);
+    // 2 nft * 0.5 noria * 20 days
+    assert_that(&alice_rewards_noria).is_equal_to(20_000_000);
+    // 2 nft * 1 atom * 30 days
+    assert_that(&bob_rewards_atom).is_equal_to(60_000_000);
+    // 3 nft * 0.5 noria * 10 days
+    assert_that(&bob_rewards_noria).is_equal_to(15_000_000);
+
+    let gopniks_rewards = project.staking_platform_query_staking_rewards_per_collection(
+        ProjectAccount::Bob,
+        ProjectNft::Gopniks,
+    )?;
+    let pinjeons_rewards = project.staking_platform_query_staking_rewards_per_collection(
+        ProjectAccount::Bob,
+        ProjectNft::Pinjeons,
+    )?;
+
+    assert_that(&gopniks_rewards.funds_list).is_equal_to(vec![Funds::new(
+        60_000_000u128,
+        &Currency::new(&ProjectToken::Atom.into(), 6),
+    )]);
+
+    assert_that(&pinjeons_rewards.funds_list).is_equal_to(vec![Funds::new(
+        15_000_000u128,
+        &Currency::new(&ProjectCoin::Noria.into(), 6),
+    )]);
 
     Ok(())
 }
 
 #[test]
-fn unstake_with_minter_and_empty_spender() -> StdResult<()> {
+fn stake_unlisted_collection() -> StdResult<()> {
     let mut project = Project::new();
 
-    project.minter_try_create_denom(
-        ProjectAccount::Admin,
-        ProjectAccount::Owner,
-        ProjectCoin::Noria,
-        (1, ProjectCoin::Denom),
-    )?;
-
     let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
@@ -2350,36 +3292,80 @@ fn unstake_with_minter_and_empty_spender() -> StdResult<()> {
                 daily_rewards: str_to_dec("1000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    let res = project
+        .staking_platform_try_stake(
+            ProjectAccount::Alice,
+            &[StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            }],
+        )
+        .unwrap_err();
+
+    assert_error(&res, ContractError::CollectionIsNotFound);
+
+    Ok(())
+}
+
+#[test]
+fn stake_unallowed_unfunded_delayed() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
-            222u128,
+            100u128,
             &Currency::new(
-                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
-                18,
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
             ),
         ),
         proposal_type: ProposalType::AddCollection {
-            collection_address: ProjectNft::Pinjeons.to_string(),
+            collection_address: ProjectNft::Gopniks.to_string(),
             collection: Collection {
-                name: ProjectNft::Pinjeons.to_string(),
+                name: ProjectNft::Gopniks.to_string(),
                 staking_currency: Currency::new(
-                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("500000"),
-                emission_type: EmissionType::Minting,
+                daily_rewards: str_to_dec("1000000"),
+                emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
     project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
@@ -2387,99 +3373,53 @@ fn unstake_with_minter_and_empty_spender() -> StdResult<()> {
         100,
         ProjectCoin::Denom,
     )?;
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        2,
-        222u128,
-        ProjectToken::Inj,
-    )?;
 
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Gopniks,
-        100_000u128,
-        ProjectToken::Atom,
-    )?;
+    let res = project
+        .staking_platform_try_stake(
+            ProjectAccount::Alice,
+            &[StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            }],
+        )
+        .is_err();
+    assert_that(&res).is_equal_to(true);
 
     project.increase_allowances_nft(
         ProjectAccount::Alice,
         project.get_staking_platform_address(),
         ProjectNft::Gopniks,
     );
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Pinjeons,
-    );
+
+    let delay = (10 * MINS_PER_DAY * NANOS_PER_MIN) as u64;
 
     project.staking_platform_try_stake(
-        ProjectAccount::Alice,
-        &[
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(1),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            },
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Pinjeons.to_string(),
-                staked_token_info_list: vec![
-                    StakedTokenInfo {
-                        token_id: Uint128::new(1),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                    StakedTokenInfo {
-                        token_id: Uint128::new(2),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                ],
-            },
-        ],
-    )?;
-
-    let delay = (12 * 60 * NANOS_PER_MIN) as u64;
-    project.wait(delay);
-
-    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
-    let alice_rewards_atom = alice_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
-    let alice_rewards_noria = alice_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-
-    // 1 nft * 1 atom * 0.5 days
-    assert_that(&alice_rewards_atom).is_equal_to(500_000);
-    // 2 nft * 0.5 noria * 0.5 days
-    assert_that(&alice_rewards_noria).is_equal_to(500_000);
-
-    project.staking_platform_try_unstake(
         ProjectAccount::Alice,
         &[StakedCollectionInfo {
             collection_address: ProjectNft::Gopniks.to_string(),
             staked_token_info_list: vec![StakedTokenInfo {
                 token_id: Uint128::new(1),
-                staking_start_date: None,
-                last_claim_date: None,
+                staking_start_date: Some(project.get_timestamp().plus_nanos(delay)),
+                last_claim_date: Some(project.get_timestamp().plus_nanos(delay)),
             }],
         }],
     )?;
 
-    let alice_associated_balances =
-        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
-    let alice_atom_associated_balance = alice_associated_balances
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        1_000_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    project.wait(delay);
+
+    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    let alice_rewards_atom = alice_rewards
         .funds_list
         .iter()
         .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
@@ -2487,22 +3427,16 @@ fn unstake_with_minter_and_empty_spender() -> StdResult<()> {
         .amount
         .u128();
 
-    assert_that(&alice_atom_associated_balance).is_equal_to(1_100_000);
+    // 1 nft * 1 atom * 10 days
+    assert_that(&alice_rewards_atom).is_equal_to(10_000_000);
 
     Ok(())
 }
 
 #[test]
-fn claim_staking_rewards_and_unstake_all() -> StdResult<()> {
+fn claim_staking_rewards_default() -> StdResult<()> {
     let mut project = Project::new();
 
-    project.minter_try_create_denom(
-        ProjectAccount::Admin,
-        ProjectAccount::Owner,
-        ProjectCoin::Noria,
-        (1, ProjectCoin::Denom),
-    )?;
-
     let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
@@ -2523,36 +3457,18 @@ fn claim_staking_rewards_and_unstake_all() -> StdResult<()> {
                 daily_rewards: str_to_dec("1000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
-            },
-        },
-    };
-
-    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
-        proposal_status: None,
-        price: Funds::new(
-            222u128,
-            &Currency::new(
-                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
-                18,
-            ),
-        ),
-        proposal_type: ProposalType::AddCollection {
-            collection_address: ProjectNft::Pinjeons.to_string(),
-            collection: Collection {
-                name: ProjectNft::Pinjeons.to_string(),
-                staking_currency: Currency::new(
-                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
-                    6,
-                ),
-                daily_rewards: str_to_dec("500000"),
-                emission_type: EmissionType::Minting,
-                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
     project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
@@ -2560,17 +3476,11 @@ fn claim_staking_rewards_and_unstake_all() -> StdResult<()> {
         100,
         ProjectCoin::Denom,
     )?;
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        2,
-        222u128,
-        ProjectToken::Inj,
-    )?;
 
     project.staking_platform_try_deposit_tokens(
         ProjectAccount::Owner,
         ProjectNft::Gopniks,
-        100_000u128,
+        500_000u128,
         ProjectToken::Atom,
     )?;
 
@@ -2579,44 +3489,24 @@ fn claim_staking_rewards_and_unstake_all() -> StdResult<()> {
         project.get_staking_platform_address(),
         ProjectNft::Gopniks,
     );
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Pinjeons,
-    );
 
     project.staking_platform_try_stake(
         ProjectAccount::Alice,
-        &[
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(1),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            },
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Pinjeons.to_string(),
-                staked_token_info_list: vec![
-                    StakedTokenInfo {
-                        token_id: Uint128::new(1),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                    StakedTokenInfo {
-                        token_id: Uint128::new(2),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                ],
-            },
-        ],
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
     )?;
 
     let delay = (12 * 60 * NANOS_PER_MIN) as u64;
     project.wait(delay);
 
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+
     let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
     let alice_rewards_atom = alice_rewards
         .funds_list
@@ -2625,50 +3515,24 @@ fn claim_staking_rewards_and_unstake_all() -> StdResult<()> {
         .unwrap()
         .amount
         .u128();
-    let alice_rewards_noria = alice_rewards
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
-        .unwrap()
-        .amount
-        .u128();
 
     // 1 nft * 1 atom * 0.5 days
-    assert_that(&alice_rewards_atom).is_equal_to(500_000);
-    // 2 nft * 0.5 noria * 0.5 days
-    assert_that(&alice_rewards_noria).is_equal_to(500_000);
-
-    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&alice_rewards_atom).is_equal_to(0);
 
-    project.staking_platform_try_unstake(
-        ProjectAccount::Alice,
-        &[
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
-                    token_id: Uint128::new(1),
-                    staking_start_date: None,
-                    last_claim_date: None,
-                }],
-            },
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Pinjeons.to_string(),
-                staked_token_info_list: vec![
-                    StakedTokenInfo {
-                        token_id: Uint128::new(1),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                    StakedTokenInfo {
-                        token_id: Uint128::new(2),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                ],
-            },
-        ],
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        200_000u128,
+        ProjectToken::Atom,
     )?;
 
+    let collections_balances = project.staking_platform_query_collections_balances(&None)?;
+    let gopniks_balance = collections_balances
+        .iter()
+        .find(|x| x.address == ProjectNft::Gopniks.to_string())
+        .unwrap();
+    assert_that(&gopniks_balance.funds.amount.u128()).is_equal_to(200_000);
+
     let alice_associated_balances =
         project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
     let alice_atom_associated_balance = alice_associated_balances
@@ -2678,35 +3542,15 @@ fn claim_staking_rewards_and_unstake_all() -> StdResult<()> {
         .unwrap()
         .amount
         .u128();
-    let alice_noria_associated_balance = alice_associated_balances
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-
-    assert_that(&alice_atom_associated_balance).is_equal_to(1_100_000);
-    assert_that(&alice_noria_associated_balance).is_equal_to(1_500_000);
-
-    let staker = project.staking_platform_query_stakers(&Some(vec![ProjectAccount::Alice]))?;
-    assert_that(&(staker.len() == 1 && staker[0].staked_collection_info_list.is_empty()))
-        .is_equal_to(true);
+    assert_that(&alice_atom_associated_balance).is_equal_to(1_500_000);
 
     Ok(())
 }
 
 #[test]
-fn claim_staking_rewards_for_specified_collection() -> StdResult<()> {
+fn unstake_improper_collection_and_id_default() -> StdResult<()> {
     let mut project = Project::new();
 
-    project.minter_try_create_denom(
-        ProjectAccount::Admin,
-        ProjectAccount::Owner,
-        ProjectCoin::Noria,
-        (1, ProjectCoin::Denom),
-    )?;
-
     let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
@@ -2724,39 +3568,21 @@ fn claim_staking_rewards_for_specified_collection() -> StdResult<()> {
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("120000"),
-                emission_type: EmissionType::Spending,
-                owner: ProjectAccount::Owner.to_string(),
-            },
-        },
-    };
-
-    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
-        proposal_status: None,
-        price: Funds::new(
-            222u128,
-            &Currency::new(
-                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
-                18,
-            ),
-        ),
-        proposal_type: ProposalType::AddCollection {
-            collection_address: ProjectNft::Pinjeons.to_string(),
-            collection: Collection {
-                name: ProjectNft::Pinjeons.to_string(),
-                staking_currency: Currency::new(
-                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
-                    6,
-                ),
-                daily_rewards: str_to_dec("120000"),
+                daily_rewards: str_to_dec("1000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
     project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
@@ -2764,23 +3590,11 @@ fn claim_staking_rewards_for_specified_collection() -> StdResult<()> {
         100,
         ProjectCoin::Denom,
     )?;
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        2,
-        222,
-        ProjectToken::Inj,
-    )?;
 
     project.staking_platform_try_deposit_tokens(
         ProjectAccount::Owner,
         ProjectNft::Gopniks,
-        100_000u128,
-        ProjectToken::Atom,
-    )?;
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Pinjeons,
-        100_000u128,
+        1_000_000u128,
         ProjectToken::Atom,
     )?;
 
@@ -2789,52 +3603,73 @@ fn claim_staking_rewards_for_specified_collection() -> StdResult<()> {
         project.get_staking_platform_address(),
         ProjectNft::Gopniks,
     );
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Pinjeons,
-    );
 
     project.staking_platform_try_stake(
         ProjectAccount::Alice,
-        &[
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Gopniks.to_string(),
-                staked_token_info_list: vec![StakedTokenInfo {
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![
+                StakedTokenInfo {
                     token_id: Uint128::new(1),
                     staking_start_date: None,
                     last_claim_date: None,
-                }],
-            },
-            StakedCollectionInfo {
-                collection_address: ProjectNft::Pinjeons.to_string(),
-                staked_token_info_list: vec![
-                    StakedTokenInfo {
-                        token_id: Uint128::new(1),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                    StakedTokenInfo {
-                        token_id: Uint128::new(2),
-                        staking_start_date: None,
-                        last_claim_date: None,
-                    },
-                ],
-            },
-        ],
+                },
+                StakedTokenInfo {
+                    token_id: Uint128::new(2),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                },
+            ],
+        }],
     )?;
 
-    let delay = (60 * NANOS_PER_MIN) as u64;
+    let delay = (6 * 60 * NANOS_PER_MIN) as u64;
     project.wait(delay);
 
-    project.staking_platform_try_claim_staking_rewards(
+    let res = project
+        .staking_platform_try_unstake(
+            ProjectAccount::Alice,
+            &[StakedCollectionInfo {
+                collection_address: ProjectNft::Pinjeons.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            }],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::CollectionIsNotFound);
+
+    let res = project
+        .staking_platform_try_unstake(
+            ProjectAccount::Alice,
+            &[StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(3),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            }],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::AssetIsNotFound);
+
+    project.staking_platform_try_unstake(
         ProjectAccount::Alice,
-        &Some(ProjectNft::Gopniks),
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
     )?;
 
-    let alice_associated_balances =
-        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
-    let alice_atom_associated_balance = alice_associated_balances
+    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    let alice_rewards_atom = alice_rewards
         .funds_list
         .iter()
         .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
@@ -2842,12 +3677,15 @@ fn claim_staking_rewards_for_specified_collection() -> StdResult<()> {
         .amount
         .u128();
 
-    assert_that(&alice_atom_associated_balance).is_equal_to(1_005_000);
+    // 1 nft * 1 atom * 0.25 days
+    assert_that(&alice_rewards_atom).is_equal_to(250_000);
 
-    project.staking_platform_try_claim_staking_rewards(
-        ProjectAccount::Alice,
-        &Some(ProjectNft::Pinjeons),
-    )?;
+    let collections_balances = project.staking_platform_query_collections_balances(&None)?;
+    let gopniks_balance = collections_balances
+        .iter()
+        .find(|x| x.address == ProjectNft::Gopniks.to_string())
+        .unwrap();
+    assert_that(&gopniks_balance.funds.amount.u128()).is_equal_to(750_000);
 
     let alice_associated_balances =
         project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
@@ -2858,16 +3696,22 @@ fn claim_staking_rewards_for_specified_collection() -> StdResult<()> {
         .unwrap()
         .amount
         .u128();
-
-    assert_that(&alice_atom_associated_balance).is_equal_to(1_015_000);
+    assert_that(&alice_atom_associated_balance).is_equal_to(1_250_000);
 
     Ok(())
 }
 
 #[test]
-fn accept_proposal_update_collection_change_daily_rewards() -> StdResult<()> {
+fn claim_staking_rewards_with_minter_default() -> StdResult<()> {
     let mut project = Project::new();
 
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+
     let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
@@ -2886,40 +3730,20 @@ fn accept_proposal_update_collection_change_daily_rewards() -> StdResult<()> {
                     6,
                 ),
                 daily_rewards: str_to_dec("1000000"),
-                emission_type: EmissionType::Spending,
-                owner: ProjectAccount::Owner.to_string(),
-            },
-        },
-    };
-
-    // increase daily rewards 2x
-    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
-        proposal_status: None,
-        price: Funds::new(
-            100u128,
-            &Currency::new(
-                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
-                6,
-            ),
-        ),
-        proposal_type: ProposalType::UpdateCollection {
-            collection_address: ProjectNft::Gopniks.to_string(),
-            new_collection_address: None,
-            new_collection: Collection {
-                name: ProjectNft::Gopniks.to_string(),
-                staking_currency: Currency::new(
-                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
-                    6,
-                ),
-                daily_rewards: str_to_dec("2000000"),
-                emission_type: EmissionType::Spending,
+                emission_type: EmissionType::Minting,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
     project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
 
     project.staking_platform_try_accept_proposal(
         ProjectAccount::Owner,
@@ -2928,13 +3752,6 @@ fn accept_proposal_update_collection_change_daily_rewards() -> StdResult<()> {
         ProjectCoin::Denom,
     )?;
 
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Gopniks,
-        400_000u128,
-        ProjectCoin::Noria,
-    )?;
-
     project.increase_allowances_nft(
         ProjectAccount::Alice,
         project.get_staking_platform_address(),
@@ -2953,16 +3770,7 @@ fn accept_proposal_update_collection_change_daily_rewards() -> StdResult<()> {
         }],
     )?;
 
-    let delay = (3 * 60 * NANOS_PER_MIN) as u64;
-    project.wait(delay);
-
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        2,
-        100,
-        ProjectCoin::Denom,
-    )?;
-
+    let delay = (MINS_PER_DAY * NANOS_PER_MIN) as u64;
     project.wait(delay);
 
     project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
@@ -2976,43 +3784,22 @@ fn accept_proposal_update_collection_change_daily_rewards() -> StdResult<()> {
         .unwrap()
         .amount
         .u128();
-
-    let owner_associated_balances =
-        project.staking_platform_query_associated_balances(ProjectAccount::Owner)?;
-    let owner_noria_associated_balance = owner_associated_balances
-        .funds_list
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-
-    // 125 + 250
-    assert_that(&alice_noria_associated_balance).is_equal_to(1_375_000);
-    // 1000 - 400
-    assert_that(&owner_noria_associated_balance).is_equal_to(600_000);
+    assert_that(&alice_noria_associated_balance).is_equal_to(2_000_000);
 
     Ok(())
 }
 
 #[test]
-fn accept_proposal_update_collection_change_staking_currency() -> StdResult<()> {
+fn claim_staking_rewards_with_cw20_minter() -> StdResult<()> {
     let mut project = Project::new();
 
-    project.minter_try_create_denom(
-        ProjectAccount::Admin,
-        ProjectAccount::Owner,
-        ProjectCoin::Noria,
-        (1, ProjectCoin::Denom),
-    )?;
-    project.minter_try_create_denom(
-        ProjectAccount::Admin,
-        ProjectAccount::Owner,
-        ProjectCoin::Denom,
-        (1, ProjectCoin::Denom),
-    )?;
+    project.minter_try_create_cw20(ProjectAccount::Admin, ProjectAccount::Owner, "NORIA")?;
 
-    let proposal_a1: &Proposal<String, TokenUnverified> = &Proposal {
+    let QueryDenomsFromCreatorResponse { denoms } =
+        project.minter_query_denoms_by_creator(ProjectAccount::Owner)?;
+    let cw20_address = denoms[0].to_owned();
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -3025,18 +3812,76 @@ fn accept_proposal_update_collection_change_staking_currency() -> StdResult<()>
             collection_address: ProjectNft::Gopniks.to_string(),
             collection: Collection {
                 name: ProjectNft::Gopniks.to_string(),
-                staking_currency: Currency::new(
-                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
-                    6,
-                ),
+                staking_currency: Currency::new(&TokenUnverified::new_cw20(&cw20_address), 6),
                 daily_rewards: str_to_dec("1000000"),
                 emission_type: EmissionType::Minting,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    let proposal_a2: &Proposal<String, TokenUnverified> = &Proposal {
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    let delay = (MINS_PER_DAY * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+
+    let cw20::BalanceResponse { balance } = project.app.wrap().query_wasm_smart(
+        &cw20_address,
+        &cw20::Cw20QueryMsg::Balance {
+            address: ProjectAccount::Alice.to_string(),
+        },
+    )?;
+    assert_that(&balance.u128()).is_equal_to(1_000_000);
+
+    Ok(())
+}
+
+#[test]
+fn claim_staking_rewards_with_protocol_fee() -> StdResult<()> {
+    let mut project = Project::new();
+
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -3045,23 +3890,29 @@ fn accept_proposal_update_collection_change_staking_currency() -> StdResult<()>
                 6,
             ),
         ),
-        proposal_type: ProposalType::UpdateCollection {
+        proposal_type: ProposalType::AddCollection {
             collection_address: ProjectNft::Gopniks.to_string(),
-            new_collection_address: None,
-            new_collection: Collection {
+            collection: Collection {
                 name: ProjectNft::Gopniks.to_string(),
                 staking_currency: Currency::new(
-                    &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("1000000"),
-                emission_type: EmissionType::Minting,
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    let proposal_b1: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -3075,77 +3926,99 @@ fn accept_proposal_update_collection_change_staking_currency() -> StdResult<()>
             collection: Collection {
                 name: ProjectNft::Pinjeons.to_string(),
                 staking_currency: Currency::new(
-                    &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
                     6,
                 ),
                 daily_rewards: str_to_dec("1000000"),
-                emission_type: EmissionType::Spending,
+                emission_type: EmissionType::Minting,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    let proposal_b2: &Proposal<String, TokenUnverified> = &Proposal {
-        proposal_status: None,
-        price: Funds::new(
-            100u128,
-            &Currency::new(
-                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
-                6,
-            ),
-        ),
-        proposal_type: ProposalType::UpdateCollection {
-            collection_address: ProjectNft::Pinjeons.to_string(),
-            new_collection_address: None,
-            new_collection: Collection {
-                name: ProjectNft::Pinjeons.to_string(),
-                staking_currency: Currency::new(
-                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
-                    6,
-                ),
-                daily_rewards: str_to_dec("1000000"),
-                emission_type: EmissionType::Spending,
-                owner: ProjectAccount::Owner.to_string(),
-            },
-        },
-    };
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
 
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a1)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b1)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a2)?;
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b2)?;
+    for id in [1, 2] {
+        project.staking_platform_try_accept_proposal(
+            ProjectAccount::Owner,
+            id,
+            100,
+            ProjectCoin::Denom,
+        )?;
+    }
 
-    project.staking_platform_try_accept_proposal(
+    project.staking_platform_try_deposit_tokens(
         ProjectAccount::Owner,
-        1,
-        100,
-        ProjectCoin::Denom,
+        ProjectNft::Gopniks,
+        500_000u128,
+        ProjectToken::Atom,
     )?;
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        2,
-        100,
-        ProjectCoin::Denom,
+
+    // set global and collection protocol fees
+    let res = project
+        .staking_platform_try_update_config(
+            ProjectAccount::Alice,
+            &None::<ProjectAccount>,
+            &None,
+            Some("0.1"),
+            &None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = project
+        .staking_platform_try_update_config(
+            ProjectAccount::Admin,
+            &None::<ProjectAccount>,
+            &None,
+            Some("1.1"),
+            &None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ProtocolFeeIsOutOfRange);
+
+    project.staking_platform_try_update_config(
+        ProjectAccount::Admin,
+        &None::<ProjectAccount>,
+        &None,
+        Some("0.1"),
+        &None,
     )?;
 
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
+    let res = project
+        .staking_platform_try_update_collection_protocol_fee(
+            ProjectAccount::Owner,
+            ProjectNft::Pinjeons,
+            Some("0.2"),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    project.staking_platform_try_update_collection_protocol_fee(
+        ProjectAccount::Admin,
         ProjectNft::Pinjeons,
-        400_000u128,
-        ProjectToken::Inj,
+        Some("0.2"),
     )?;
 
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Gopniks,
-    );
+    let collections = project.staking_platform_query_collections(&None)?;
+    let protocol_fees: Vec<_> = collections.iter().map(|x| x.protocol_fee).collect();
+    assert_that(&protocol_fees).is_equal_to(vec![str_to_dec("0.1"), str_to_dec("0.2")]);
 
-    project.increase_allowances_nft(
-        ProjectAccount::Alice,
-        project.get_staking_platform_address(),
-        ProjectNft::Pinjeons,
-    );
+    for collection in [ProjectNft::Gopniks, ProjectNft::Pinjeons] {
+        project.increase_allowances_nft(
+            ProjectAccount::Alice,
+            project.get_staking_platform_address(),
+            collection,
+        );
+    }
 
     project.staking_platform_try_stake(
         ProjectAccount::Alice,
@@ -3169,128 +4042,81 @@ fn accept_proposal_update_collection_change_staking_currency() -> StdResult<()>
         ],
     )?;
 
-    let delay = (6 * 60 * NANOS_PER_MIN) as u64;
-    project.wait(delay);
-
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        3,
-        100,
-        ProjectCoin::Denom,
-    )?;
-    project.staking_platform_try_accept_proposal(
-        ProjectAccount::Owner,
-        4,
-        100,
-        ProjectCoin::Denom,
-    )?;
-
-    project.staking_platform_try_deposit_tokens(
-        ProjectAccount::Owner,
-        ProjectNft::Pinjeons,
-        400_000u128,
-        ProjectToken::Atom,
-    )?;
-
+    let delay = (MINS_PER_DAY * NANOS_PER_MIN) as u64;
     project.wait(delay);
 
-    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    let get_balance = |project: &Project, token: &Token| -> u128 {
+        project
+            .query_all_balances(ProjectAccount::Alice)
+            .unwrap()
+            .iter()
+            .find(|x| &x.currency.token == token)
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let atom = &Token::new_cw20(&ProjectToken::Atom.into());
+    let noria = &Token::new_native(&ProjectCoin::Noria.to_string());
+    let alice_atom_balance = get_balance(&project, atom);
+    let alice_noria_balance = get_balance(&project, noria);
 
-    let alice_balances = project.query_all_balances(ProjectAccount::Alice)?;
-    let alice_denom_balance = alice_balances
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-    let alice_noria_balance = alice_balances
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-    let alice_inj_balance = alice_balances
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Inj.into()))
-        .unwrap()
-        .amount
-        .u128();
-    let alice_atom_balance = alice_balances
+    // 1 nft * 0.1 atom * 1 day * (1 - 0.1), 1 nft * 1 noria * 1 day
+    // protocol fee of minting collection is minted on top of rewards
+    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    let alice_rewards: Vec<u128> = alice_rewards
+        .funds_list
         .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
+        .map(|x| x.amount.u128())
+        .collect();
+    assert_that(&alice_rewards).is_equal_to(vec![90_000, 1_000_000]);
+
+    // mock minter sends minted tokens from its balance
+    project
+        .app
+        .send_tokens(
+            ProjectAccount::Admin.into(),
+            project.get_minter_address(),
+            &[coin(200_000, ProjectCoin::Noria.to_string())],
+        )
+        .unwrap();
 
-    // 250
-    assert_that(&alice_denom_balance).is_equal_to(1_250_000);
-    // 250
-    assert_that(&alice_noria_balance).is_equal_to(1_250_000);
-    // 250
-    assert_that(&alice_inj_balance).is_equal_to(1_000_000_000_000_250_000);
-    // 250
-    assert_that(&alice_atom_balance).is_equal_to(1_250_000);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
 
-    let owner_balances = project.query_all_balances(ProjectAccount::Owner)?;
-    let owner_denom_balance = owner_balances
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-    let owner_noria_balance = owner_balances
-        .iter()
-        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
-        .unwrap()
-        .amount
-        .u128();
-    let owner_inj_balance = owner_balances
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Inj.into()))
-        .unwrap()
-        .amount
-        .u128();
-    let owner_atom_balance = owner_balances
-        .iter()
-        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
-        .unwrap()
-        .amount
-        .u128();
+    assert_that(&(get_balance(&project, atom) - alice_atom_balance)).is_equal_to(90_000);
+    assert_that(&(get_balance(&project, noria) - alice_noria_balance)).is_equal_to(1_000_000);
 
-    // 1_000_000 - 400
-    assert_that(&owner_denom_balance).is_equal_to(999_600);
-    // 1000
-    assert_that(&owner_noria_balance).is_equal_to(1_000_000);
+    // protocol fees are accrued to platform funds
+    let funds = project.staking_platform_query_funds()?;
+    let get_funds_amount = |token: &Token| -> u128 {
+        funds
+            .iter()
+            .find(|x| &x.currency.token == token)
+            .unwrap()
+            .amount
+            .u128()
+    };
+    assert_that(&get_funds_amount(atom)).is_equal_to(10_000);
+    assert_that(&get_funds_amount(noria)).is_equal_to(200_000);
 
-    // 1e18 - 400_000 + 150_000
-    assert_that(&owner_inj_balance).is_equal_to(999_999_999_999_750_000);
-    // 1000 - 400
-    assert_that(&owner_atom_balance).is_equal_to(600_000);
+    let collections_balances =
+        project.staking_platform_query_collections_balances(&Some(vec![ProjectNft::Gopniks]))?;
+    assert_that(&collections_balances[0].funds.amount.u128()).is_equal_to(400_000);
 
     Ok(())
 }
 
 #[test]
-fn migrate_minter_default() -> StdResult<()> {
+fn claim_staking_rewards_with_minter_and_empty_spender() -> StdResult<()> {
     let mut project = Project::new();
 
-    project.migrate_contract(
+    project.minter_try_create_denom(
         ProjectAccount::Admin,
-        project.get_minter_address(),
-        project.get_minter_code_id(),
-        gopstake_base::minter::msg::MigrateMsg {
-            version: "1.1.1".to_string(),
-        },
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
     )?;
 
-    Ok(())
-}
-
-#[test]
-fn migrate_staking_platform_default() -> StdResult<()> {
-    let mut project = Project::new();
-
-    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
         proposal_status: None,
         price: Funds::new(
             100u128,
@@ -3307,98 +4133,4539 @@ fn migrate_staking_platform_default() -> StdResult<()> {
                     &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
                     6,
                 ),
-                daily_rewards: str_to_dec("86400000000000"),
+                daily_rewards: str_to_dec("1000000"),
                 emission_type: EmissionType::Spending,
                 owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
     };
 
-    let expected: Vec<QueryProposalsResponseItem> = vec![QueryProposalsResponseItem {
-        id: Uint128::new(1),
-        proposal: Proposal {
-            proposal_status: Some(ProposalStatus::Active),
-            price: Funds::new(
-                100u128,
-                &Currency::new(&Token::new_native(&ProjectCoin::Denom.to_string()), 6),
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            222u128,
+            &Currency::new(
+                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
+                18,
             ),
-            proposal_type: ProposalType::AddCollection {
-                collection_address: ProjectNft::Gopniks.into(),
-                collection: Collection {
-                    name: ProjectNft::Gopniks.to_string(),
-                    staking_currency: Currency::new(
-                        &Token::new_cw20(&ProjectToken::Atom.into()),
-                        6,
-                    ),
-                    daily_rewards: str_to_dec("86400000000000"),
-                    emission_type: EmissionType::Spending,
-                    owner: ProjectAccount::Owner.into(),
-                },
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            collection: Collection {
+                name: ProjectNft::Pinjeons.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("500000"),
+                emission_type: EmissionType::Minting,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
             },
         },
-    }];
-
-    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    };
 
-    project.migrate_contract(
-        ProjectAccount::Admin,
-        project.get_staking_platform_address(),
-        project.get_staking_platform_code_id(),
-        gopstake_base::staking_platform::msg::MigrateMsg {
-            version: "1.3.1".to_string(),
-        },
-    )?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
 
-    let proposals = project.staking_platform_query_proposals(None)?;
-    assert_that(&proposals).is_equal_to(&expected);
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        222u128,
+        ProjectToken::Inj,
+    )?;
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        100_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Pinjeons,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            },
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Pinjeons.to_string(),
+                staked_token_info_list: vec![
+                    StakedTokenInfo {
+                        token_id: Uint128::new(1),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                    StakedTokenInfo {
+                        token_id: Uint128::new(2),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                ],
+            },
+        ],
+    )?;
+
+    let delay = (12 * 60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    let alice_rewards_atom = alice_rewards
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+    let alice_rewards_noria = alice_rewards
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+
+    // 1 nft * 1 atom * 0.5 days
+    assert_that(&alice_rewards_atom).is_equal_to(500_000);
+    // 2 nft * 0.5 noria * 0.5 days
+    assert_that(&alice_rewards_noria).is_equal_to(500_000);
+
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+
+    let alice_associated_balances =
+        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
+    let alice_atom_associated_balance = alice_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+    let alice_noria_associated_balance = alice_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+
+    assert_that(&alice_atom_associated_balance).is_equal_to(1_000_000);
+    assert_that(&alice_noria_associated_balance).is_equal_to(1_500_000);
+
+    Ok(())
+}
+
+#[test]
+fn unstake_with_minter_and_empty_spender() -> StdResult<()> {
+    let mut project = Project::new();
+
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("1000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            222u128,
+            &Currency::new(
+                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
+                18,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            collection: Collection {
+                name: ProjectNft::Pinjeons.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("500000"),
+                emission_type: EmissionType::Minting,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        222u128,
+        ProjectToken::Inj,
+    )?;
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        100_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Pinjeons,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            },
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Pinjeons.to_string(),
+                staked_token_info_list: vec![
+                    StakedTokenInfo {
+                        token_id: Uint128::new(1),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                    StakedTokenInfo {
+                        token_id: Uint128::new(2),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                ],
+            },
+        ],
+    )?;
+
+    let delay = (12 * 60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    let alice_rewards_atom = alice_rewards
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+    let alice_rewards_noria = alice_rewards
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+
+    // 1 nft * 1 atom * 0.5 days
+    assert_that(&alice_rewards_atom).is_equal_to(500_000);
+    // 2 nft * 0.5 noria * 0.5 days
+    assert_that(&alice_rewards_noria).is_equal_to(500_000);
+
+    project.staking_platform_try_unstake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    let alice_associated_balances =
+        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
+    let alice_atom_associated_balance = alice_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+
+    assert_that(&alice_atom_associated_balance).is_equal_to(1_100_000);
+
+    Ok(())
+}
+
+#[test]
+fn claim_staking_rewards_and_unstake_all() -> StdResult<()> {
+    let mut project = Project::new();
+
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("1000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            222u128,
+            &Currency::new(
+                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
+                18,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            collection: Collection {
+                name: ProjectNft::Pinjeons.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("500000"),
+                emission_type: EmissionType::Minting,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        222u128,
+        ProjectToken::Inj,
+    )?;
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        100_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Pinjeons,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            },
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Pinjeons.to_string(),
+                staked_token_info_list: vec![
+                    StakedTokenInfo {
+                        token_id: Uint128::new(1),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                    StakedTokenInfo {
+                        token_id: Uint128::new(2),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                ],
+            },
+        ],
+    )?;
+
+    let delay = (12 * 60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    let alice_rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    let alice_rewards_atom = alice_rewards
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+    let alice_rewards_noria = alice_rewards
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+
+    // 1 nft * 1 atom * 0.5 days
+    assert_that(&alice_rewards_atom).is_equal_to(500_000);
+    // 2 nft * 0.5 noria * 0.5 days
+    assert_that(&alice_rewards_noria).is_equal_to(500_000);
+
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+
+    project.staking_platform_try_unstake(
+        ProjectAccount::Alice,
+        &[
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            },
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Pinjeons.to_string(),
+                staked_token_info_list: vec![
+                    StakedTokenInfo {
+                        token_id: Uint128::new(1),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                    StakedTokenInfo {
+                        token_id: Uint128::new(2),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                ],
+            },
+        ],
+    )?;
+
+    let alice_associated_balances =
+        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
+    let alice_atom_associated_balance = alice_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+    let alice_noria_associated_balance = alice_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+
+    assert_that(&alice_atom_associated_balance).is_equal_to(1_100_000);
+    assert_that(&alice_noria_associated_balance).is_equal_to(1_500_000);
+
+    let staker = project.staking_platform_query_stakers(&Some(vec![ProjectAccount::Alice]))?;
+    assert_that(&(staker.len() == 1 && staker[0].staked_collection_info_list.is_empty()))
+        .is_equal_to(true);
+
+    Ok(())
+}
+
+#[test]
+fn claim_staking_rewards_for_specified_collection() -> StdResult<()> {
+    let mut project = Project::new();
+
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("120000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            222u128,
+            &Currency::new(
+                &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
+                18,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            collection: Collection {
+                name: ProjectNft::Pinjeons.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("120000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        222,
+        ProjectToken::Inj,
+    )?;
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        100_000u128,
+        ProjectToken::Atom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Pinjeons,
+        100_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Pinjeons,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            },
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Pinjeons.to_string(),
+                staked_token_info_list: vec![
+                    StakedTokenInfo {
+                        token_id: Uint128::new(1),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                    StakedTokenInfo {
+                        token_id: Uint128::new(2),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    },
+                ],
+            },
+        ],
+    )?;
+
+    let delay = (60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    project.staking_platform_try_claim_staking_rewards(
+        ProjectAccount::Alice,
+        &Some(ProjectNft::Gopniks),
+    )?;
+
+    let alice_associated_balances =
+        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
+    let alice_atom_associated_balance = alice_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+
+    assert_that(&alice_atom_associated_balance).is_equal_to(1_005_000);
+
+    project.staking_platform_try_claim_staking_rewards(
+        ProjectAccount::Alice,
+        &Some(ProjectNft::Pinjeons),
+    )?;
+
+    let alice_associated_balances =
+        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
+    let alice_atom_associated_balance = alice_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+
+    assert_that(&alice_atom_associated_balance).is_equal_to(1_015_000);
+
+    Ok(())
+}
+
+#[test]
+fn accept_proposal_update_collection_change_daily_rewards() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("1000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    // increase daily rewards 2x
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::UpdateCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            new_collection_address: None,
+            token_id_mapping: None,
+            new_collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("2000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        400_000u128,
+        ProjectCoin::Noria,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    let delay = (3 * 60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    project.wait(delay);
+
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+
+    let alice_associated_balances =
+        project.staking_platform_query_associated_balances(ProjectAccount::Alice)?;
+    let alice_noria_associated_balance = alice_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+
+    let owner_associated_balances =
+        project.staking_platform_query_associated_balances(ProjectAccount::Owner)?;
+    let owner_noria_associated_balance = owner_associated_balances
+        .funds_list
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+
+    // 125 + 250
+    assert_that(&alice_noria_associated_balance).is_equal_to(1_375_000);
+    // 1000 - 400
+    assert_that(&owner_noria_associated_balance).is_equal_to(600_000);
+
+    Ok(())
+}
+
+#[test]
+fn accept_proposal_update_collection_change_staking_currency() -> StdResult<()> {
+    let mut project = Project::new();
+
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Denom,
+        (1, ProjectCoin::Denom),
+    )?;
+
+    let proposal_a1: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("1000000"),
+                emission_type: EmissionType::Minting,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_a2: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::UpdateCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            new_collection_address: None,
+            token_id_mapping: None,
+            new_collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("1000000"),
+                emission_type: EmissionType::Minting,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_b1: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            collection: Collection {
+                name: ProjectNft::Pinjeons.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Inj.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("1000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_b2: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::UpdateCollection {
+            collection_address: ProjectNft::Pinjeons.to_string(),
+            new_collection_address: None,
+            token_id_mapping: None,
+            new_collection: Collection {
+                name: ProjectNft::Pinjeons.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("1000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a1)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b1)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a2)?;
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b2)?;
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Pinjeons,
+        400_000u128,
+        ProjectToken::Inj,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Pinjeons,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            },
+            StakedCollectionInfo {
+                collection_address: ProjectNft::Pinjeons.to_string(),
+                staked_token_info_list: vec![StakedTokenInfo {
+                    token_id: Uint128::new(1),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                }],
+            },
+        ],
+    )?;
+
+    let delay = (6 * 60 * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        3,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        4,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Pinjeons,
+        400_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    project.wait(delay);
+
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+
+    let alice_balances = project.query_all_balances(ProjectAccount::Alice)?;
+    let alice_denom_balance = alice_balances
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+    let alice_noria_balance = alice_balances
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+    let alice_inj_balance = alice_balances
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Inj.into()))
+        .unwrap()
+        .amount
+        .u128();
+    let alice_atom_balance = alice_balances
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+
+    // 250
+    assert_that(&alice_denom_balance).is_equal_to(1_250_000);
+    // 250
+    assert_that(&alice_noria_balance).is_equal_to(1_250_000);
+    // 250
+    assert_that(&alice_inj_balance).is_equal_to(1_000_000_000_000_250_000);
+    // 250
+    assert_that(&alice_atom_balance).is_equal_to(1_250_000);
+
+    let owner_balances = project.query_all_balances(ProjectAccount::Owner)?;
+    let owner_denom_balance = owner_balances
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+    let owner_noria_balance = owner_balances
+        .iter()
+        .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+        .unwrap()
+        .amount
+        .u128();
+    let owner_inj_balance = owner_balances
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Inj.into()))
+        .unwrap()
+        .amount
+        .u128();
+    let owner_atom_balance = owner_balances
+        .iter()
+        .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+        .unwrap()
+        .amount
+        .u128();
+
+    // 1_000_000 - 400
+    assert_that(&owner_denom_balance).is_equal_to(999_600);
+    // 1000
+    assert_that(&owner_noria_balance).is_equal_to(1_000_000);
+
+    // 1e18 - 400_000 + 150_000
+    assert_that(&owner_inj_balance).is_equal_to(999_999_999_999_750_000);
+    // 1000 - 400
+    assert_that(&owner_atom_balance).is_equal_to(600_000);
+
+    Ok(())
+}
+
+#[test]
+fn migrate_minter_default() -> StdResult<()> {
+    let mut project = Project::new();
+
+    project.migrate_contract(
+        ProjectAccount::Admin,
+        project.get_minter_address(),
+        project.get_minter_code_id(),
+        gopstake_base::minter::msg::MigrateMsg {
+            version: "1.1.1".to_string(),
+        },
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn migrate_staking_platform_default() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("86400000000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let expected: Vec<QueryProposalsResponseItem> = vec![QueryProposalsResponseItem {
+        id: Uint128::new(1),
+        proposal: Proposal {
+            proposal_status: Some(ProposalStatus::Active),
+            price: Funds::new(
+                100u128,
+                &Currency::new(&Token::new_native(&ProjectCoin::Denom.to_string()), 6),
+            ),
+            proposal_type: ProposalType::AddCollection {
+                collection_address: ProjectNft::Gopniks.into(),
+                collection: Collection {
+                    name: ProjectNft::Gopniks.to_string(),
+                    staking_currency: Currency::new(
+                        &Token::new_cw20(&ProjectToken::Atom.into()),
+                        6,
+                    ),
+                    daily_rewards: str_to_dec("86400000000000"),
+                    emission_type: EmissionType::Spending,
+                    owner: ProjectAccount::Owner.into(),
+                    max_staked_per_address: None,
+                    max_total_staked: None,
+                    min_stake_duration: None,
+                    restake_cooldown: None,
+                    fixed_pool: None,
+                    hybrid_emission: None,
+                    reward_curve: None,
+                },
+            },
+        },
+    }];
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+
+    project.migrate_contract(
+        ProjectAccount::Admin,
+        project.get_staking_platform_address(),
+        project.get_staking_platform_code_id(),
+        gopstake_base::staking_platform::msg::MigrateMsg {
+            version: "1.4.0".to_string(),
+            limit: None,
+        },
+    )?;
+
+    let proposals = project.staking_platform_query_proposals(None)?;
+    assert_that(&proposals).is_equal_to(&expected);
+
+    Ok(())
+}
+
+#[test]
+fn migrate_staking_platform_from_legacy_state() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal = |proposal_status: ProposalStatus, collection: ProjectNft| Proposal {
+        proposal_status: Some(proposal_status),
+        price: Funds::new(
+            100u128,
+            &Currency::new(&Token::new_native(&ProjectCoin::Denom.to_string()), 6),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: collection.into(),
+            collection: Collection {
+                name: collection.to_string(),
+                staking_currency: Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.into(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let staked = |token_id_list: &[u128]| {
+        vec![StakedCollectionInfo {
+            collection_address: Addr::from(ProjectNft::Gopniks),
+            staked_token_info_list: token_id_list
+                .iter()
+                .map(|x| StakedTokenInfo {
+                    token_id: Uint128::new(*x),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        }]
+    };
+
+    let legacy_code_id = project.store_legacy_staking_platform_code();
+    let contract_address = project.instantiate_legacy_staking_platform(
+        legacy_code_id,
+        &LegacyInstantiateMsg {
+            contract_name: gopstake_base::staking_platform::state::CONTRACT_NAME.to_string(),
+            version: "1.3.1".to_string(),
+            is_locked: true,
+            proposal_list: vec![
+                proposal(ProposalStatus::Accepted, ProjectNft::Gopniks),
+                proposal(ProposalStatus::Rejected, ProjectNft::Pinjeons),
+                proposal(ProposalStatus::Active, ProjectNft::Pinjeons),
+            ],
+            staker_list: vec![
+                (ProjectAccount::Alice.into(), staked(&[1, 2])),
+                (ProjectAccount::Bob.into(), staked(&[4])),
+            ],
+        },
+    );
+
+    let migrate = |project: &mut Project| -> StdResult<String> {
+        let res = project.migrate_contract(
+            ProjectAccount::Admin,
+            contract_address.clone(),
+            project.get_staking_platform_code_id(),
+            gopstake_base::staking_platform::msg::MigrateMsg {
+                version: "1.4.0".to_string(),
+                limit: Some(1),
+            },
+        )?;
+
+        let status = res
+            .events
+            .iter()
+            .flat_map(|x| &x.attributes)
+            .find(|x| x.key == "status")
+            .map(|x| x.value.to_owned())
+            .unwrap_or_default();
+
+        Ok(status)
+    };
+
+    // migration is paginated: 3 proposals and 2 stakers are processed one by one
+    assert_that(&migrate(&mut project)?).is_equal_to("in_progress".to_string());
+
+    let res = project
+        .app
+        .execute_contract(
+            ProjectAccount::Admin.into(),
+            contract_address.clone(),
+            &gopstake_base::staking_platform::msg::ExecuteMsg::ClaimStakingRewards {
+                collection: None,
+            },
+            &[],
+        )
+        .map_err(parse_err)
+        .unwrap_err();
+    assert_error(&res, ContractError::MigrationIsInProgress);
+
+    let mut calls: u8 = 1;
+    loop {
+        calls += 1;
+
+        if migrate(&mut project)? == "completed" {
+            break;
+        }
+    }
+    assert_that(&calls).is_equal_to(4);
+
+    let gopstake_base::staking_platform::msg::InfoResponse { info } =
+        project.app.wrap().query_wasm_smart(
+            &contract_address,
+            &gopstake_base::staking_platform::msg::QueryMsg::Info {},
+        )?;
+    assert_that(&info.version).is_equal_to("1.4.0".to_string());
+
+    // proposals are indexed
+    let proposals: Vec<QueryProposalsResponseItem> = project.app.wrap().query_wasm_smart(
+        &contract_address,
+        &gopstake_base::staking_platform::msg::QueryMsg::QueryProposalsByFilter {
+            filter: ProposalFilter {
+                collection_address: Some(ProjectNft::Pinjeons.to_string()),
+                ..ProposalFilter::default()
+            },
+            start_after: None,
+            limit: None,
+        },
+    )?;
+    let ids: Vec<u128> = proposals.iter().map(|x| x.id.u128()).collect();
+    assert_that(&ids).is_equal_to(vec![2, 3]);
+
+    // global lock is replaced with pauses
+    let paused_actions: Vec<ActionPause> = project.app.wrap().query_wasm_smart(
+        &contract_address,
+        &gopstake_base::staking_platform::msg::QueryMsg::QueryPausedActions {},
+    )?;
+    assert_that(&paused_actions.len()).is_equal_to(6);
+
+    // snapshots are filled
+    project.wait(5_000_000_000);
+    let gopstake_base::staking_platform::msg::TotalPowerAtHeightResponse { power, .. } =
+        project.app.wrap().query_wasm_smart(
+            &contract_address,
+            &gopstake_base::staking_platform::msg::QueryMsg::TotalPowerAtHeight {
+                height: None,
+                collection: None,
+            },
+        )?;
+    assert_that(&power.u128()).is_equal_to(3);
+
+    Ok(())
+}
+
+#[test]
+fn migrate_staking_platform_foreign_and_downgrade() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let legacy_code_id = project.store_legacy_staking_platform_code();
+
+    for (contract_name, version, error) in [
+        (
+            "crates.io:foreign-contract",
+            "1.3.1",
+            ContractError::WrongContractName,
+        ),
+        (
+            gopstake_base::staking_platform::state::CONTRACT_NAME,
+            "9.0.0",
+            ContractError::ContractDowngrade,
+        ),
+    ] {
+        let contract_address = project.instantiate_legacy_staking_platform(
+            legacy_code_id,
+            &LegacyInstantiateMsg {
+                contract_name: contract_name.to_string(),
+                version: version.to_string(),
+                is_locked: false,
+                proposal_list: vec![],
+                staker_list: vec![],
+            },
+        );
+
+        let res = project
+            .migrate_contract(
+                ProjectAccount::Admin,
+                contract_address,
+                project.get_staking_platform_code_id(),
+                gopstake_base::staking_platform::msg::MigrateMsg {
+                    version: "1.4.0".to_string(),
+                    limit: None,
+                },
+            )
+            .unwrap_err();
+        assert_error(&res, error);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn stake_with_limits() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: Some(Uint128::new(2)),
+                max_total_staked: Some(Uint128::new(3)),
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    for staker in [ProjectAccount::Alice, ProjectAccount::Bob] {
+        project.increase_allowances_nft(
+            staker,
+            project.get_staking_platform_address(),
+            ProjectNft::Gopniks,
+        );
+    }
+
+    let stake = |project: &mut Project, staker: ProjectAccount, token_id_list: &[u128]| {
+        project.staking_platform_try_stake(
+            staker,
+            &[StakedCollectionInfo {
+                collection_address: ProjectNft::Gopniks.to_string(),
+                staked_token_info_list: token_id_list
+                    .iter()
+                    .map(|x| StakedTokenInfo {
+                        token_id: Uint128::new(*x),
+                        staking_start_date: None,
+                        last_claim_date: None,
+                    })
+                    .collect(),
+            }],
+        )
+    };
+
+    stake(&mut project, ProjectAccount::Alice, &[1, 2])?;
+
+    let res = stake(&mut project, ProjectAccount::Alice, &[3]).unwrap_err();
+    assert_error(&res, ContractError::StakedPerAddressLimitExceeded);
+
+    let res = stake(&mut project, ProjectAccount::Bob, &[4, 5]).unwrap_err();
+    assert_error(&res, ContractError::TotalStakedLimitExceeded);
+
+    stake(&mut project, ProjectAccount::Bob, &[4])?;
+
+    let collections = project.staking_platform_query_collections(&None)?;
+    assert_that(&collections[0].total_staked).is_equal_to(Uint128::new(3));
+
+    Ok(())
+}
+
+#[test]
+fn stake_with_min_duration_and_restake_cooldown() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: Some(86_400),
+                restake_cooldown: Some(3_600),
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        500_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    let collection_info = |token_id_list: &[u128]| {
+        vec![StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: token_id_list
+                .iter()
+                .map(|x| StakedTokenInfo {
+                    token_id: Uint128::new(*x),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        }]
+    };
+
+    project.staking_platform_try_stake(ProjectAccount::Alice, &collection_info(&[1, 2]))?;
+
+    let stakers = project.staking_platform_query_stakers(&Some(vec![ProjectAccount::Alice]))?;
+    assert_that(&stakers[0].stake_lock_list).is_equal_to(vec![
+        TokenTimeLock {
+            collection_address: ProjectNft::Gopniks.into(),
+            token_id: Uint128::new(1),
+            remaining_time: 86_400,
+        },
+        TokenTimeLock {
+            collection_address: ProjectNft::Gopniks.into(),
+            token_id: Uint128::new(2),
+            remaining_time: 86_400,
+        },
+    ]);
+
+    // rewards don't accrue and unstaking isn't allowed before min stake duration is passed
+    let half_day = (MINS_PER_DAY * NANOS_PER_MIN / 2) as u64;
+    project.wait(half_day);
+
+    let res = project
+        .staking_platform_try_unstake(ProjectAccount::Alice, &collection_info(&[1]))
+        .unwrap_err();
+    assert_error(&res, ContractError::MinStakeDurationIsNotPassed);
+
+    let rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    assert_that(&rewards.funds_list[0].amount.u128()).is_equal_to(0);
+
+    project.wait(2 * half_day);
+
+    let rewards = project.staking_platform_query_staking_rewards(ProjectAccount::Alice)?;
+    assert_that(&rewards.funds_list[0].amount.u128()).is_equal_to(100_000);
+
+    project.staking_platform_try_unstake(ProjectAccount::Alice, &collection_info(&[1]))?;
+
+    let stakers = project.staking_platform_query_stakers(&Some(vec![ProjectAccount::Alice]))?;
+    assert_that(&stakers[0].stake_lock_list).is_equal_to(vec![]);
+    assert_that(&stakers[0].restake_cooldown_list).is_equal_to(vec![TokenTimeLock {
+        collection_address: ProjectNft::Gopniks.into(),
+        token_id: Uint128::new(1),
+        remaining_time: 3_600,
+    }]);
+
+    // unstaked token can't be staked again until cooldown is passed
+    let res = project
+        .staking_platform_try_stake(ProjectAccount::Alice, &collection_info(&[1]))
+        .unwrap_err();
+    assert_error(&res, ContractError::RestakeCooldownIsNotPassed);
+
+    project.wait(60 * NANOS_PER_MIN as u64);
+    project.staking_platform_try_stake(ProjectAccount::Alice, &collection_info(&[1]))?;
+
+    Ok(())
+}
+
+#[test]
+fn create_denom_and_set_metadata_unauth() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let res = project
+        .minter_try_create_denom(
+            ProjectAccount::Alice,
+            ProjectAccount::Owner,
+            ProjectCoin::Noria,
+            (1, ProjectCoin::Denom),
+        )
+        .unwrap_err();
+
+    assert_error(&res, ContractError::Unauthorized);
+
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+
+    let metadata = &Metadata {
+        description: "Noria token".to_string(),
+        denom_units: vec![DenomUnit {
+            denom: ProjectCoin::Noria.to_string(),
+            exponent: ProjectCoin::Noria.get_decimals() as u32,
+            aliases: vec![],
+        }],
+        base: ProjectCoin::Noria.to_string(),
+        display: "noria".to_string(),
+        name: "Noria token".to_string(),
+        symbol: "NORIA".to_string(),
+        uri: None,
+        uri_hash: None,
+    };
+
+    let res = project
+        .minter_try_set_metadata(ProjectAccount::Alice, metadata.to_owned())
+        .unwrap_err();
+
+    assert_error(&res, ContractError::Unauthorized);
+
+    project.minter_try_set_metadata(ProjectAccount::Owner, metadata.to_owned())?;
+
+    Ok(())
+}
+
+#[test]
+fn reject_proposal_refunds_transfer_collection_ownership_price() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("86400000000000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_type: ProposalType::TransferCollectionOwnership {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            owner: ProjectAccount::Owner.to_string(),
+            new_owner: ProjectAccount::Bob.to_string(),
+        },
+        ..proposal_a.to_owned()
+    };
+
+    let get_denom_balance = |project: &Project| -> u128 {
+        project
+            .query_all_balances(ProjectAccount::Bob)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Denom.to_string()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    // new owner pays listing price which is kept until the transfer is completed
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+    let bob_denom_balance = get_denom_balance(&project);
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Bob,
+        2,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    assert_that(&get_denom_balance(&project)).is_equal_to(bob_denom_balance - 100);
+
+    let funds = project.staking_platform_query_funds()?;
+    assert_that(&funds).is_equal_to(vec![Funds::new(
+        100u128,
+        &Currency::new(&Token::new_native(&ProjectCoin::Denom.to_string()), 6),
+    )]);
+
+    project.staking_platform_try_reject_proposal(ProjectAccount::Admin, 2)?;
+    assert_that(&get_denom_balance(&project)).is_equal_to(bob_denom_balance);
+
+    let collections = project.staking_platform_query_collections(&None)?;
+    assert_that(&collections[0].collection.owner).is_equal_to(Addr::from(ProjectAccount::Owner));
+
+    Ok(())
+}
+
+#[test]
+fn stake_with_receipts_and_transfer_position() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        500_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    let collections_to_stake = &[StakedCollectionInfo {
+        collection_address: ProjectNft::Gopniks.to_string(),
+        staked_token_info_list: vec![StakedTokenInfo {
+            token_id: Uint128::new(1),
+            staking_start_date: None,
+            last_claim_date: None,
+        }],
+    }];
+
+    let res = project
+        .staking_platform_try_stake_with_receipts(ProjectAccount::Alice, collections_to_stake)
+        .unwrap_err();
+    assert_error(
+        &res,
+        ContractError::ParameterIsNotFound {
+            value: "receipt_collection".to_string(),
+        },
+    );
+
+    // create receipt collection with the platform as minter
+    let cw721_base_code_id = project.store_cw721_base_code();
+    let receipt_collection = project.instantiate_contract(
+        cw721_base_code_id,
+        "receipts",
+        &cw721_base::msg::InstantiateMsg {
+            name: "Staked Gopniks".to_string(),
+            symbol: "SGOP".to_string(),
+            minter: project.get_staking_platform_address().to_string(),
+        },
+    );
+
+    project.staking_platform_try_update_config(
+        ProjectAccount::Admin,
+        &None,
+        &None,
+        None,
+        &Some(receipt_collection.clone()),
+    )?;
+
+    let res = project
+        .staking_platform_try_update_config(
+            ProjectAccount::Admin,
+            &None,
+            &None,
+            None,
+            &Some(receipt_collection.clone()),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ReceiptCollectionIsAlreadySet);
+
+    project
+        .staking_platform_try_stake_with_receipts(ProjectAccount::Alice, collections_to_stake)?;
+
+    let receipt = project.staking_platform_query_receipt(1)?;
+    assert_that(&receipt.staker).is_equal_to(Addr::from(ProjectAccount::Alice));
+    assert_that(&receipt.token_id).is_equal_to(Uint128::new(1));
+
+    let query_receipt_owner = |project: &Project| -> String {
+        let res: cw721::OwnerOfResponse = project
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &receipt_collection,
+                &cw721::Cw721QueryMsg::OwnerOf {
+                    token_id: "1".to_string(),
+                    include_expired: None,
+                },
+            )
+            .unwrap();
+        res.owner
+    };
+    assert_that(&query_receipt_owner(&project)).is_equal_to(ProjectAccount::Alice.to_string());
+
+    let delay = (MINS_PER_DAY * NANOS_PER_MIN) as u64;
+    project.wait(delay);
+
+    // sell the position
+    project
+        .app
+        .execute_contract(
+            ProjectAccount::Alice.into(),
+            receipt_collection.clone(),
+            &cw721_base::ExecuteMsg::TransferNft::<Empty, Empty> {
+                recipient: ProjectAccount::Bob.to_string(),
+                token_id: "1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let get_atom_balance = |project: &Project, account: ProjectAccount| -> u128 {
+        project
+            .query_all_balances(account)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let alice_atom_balance = get_atom_balance(&project, ProjectAccount::Alice);
+    let bob_atom_balance = get_atom_balance(&project, ProjectAccount::Bob);
+
+    // previous holder can't claim rewards of transferred position
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&get_atom_balance(&project, ProjectAccount::Alice)).is_equal_to(alice_atom_balance);
+
+    let stakers = project.staking_platform_query_stakers(&None)?;
+    let staker_addresses: Vec<String> = stakers
+        .iter()
+        .filter(|x| !x.staked_collection_info_list.is_empty())
+        .map(|x| x.staker_address.to_string())
+        .collect();
+    assert_that(&staker_addresses).is_equal_to(vec![ProjectAccount::Bob.to_string()]);
+
+    // unclaimed rewards follow the receipt
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Bob, &None)?;
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Bob) - bob_atom_balance))
+        .is_equal_to(100_000);
+
+    // receipt must be approved to be burned on unstake
+    let res = project.staking_platform_try_unstake(ProjectAccount::Bob, collections_to_stake);
+    assert_that(&res.is_err()).is_equal_to(true);
+
+    // or it can be sent to the platform to unstake its token
+    project
+        .app
+        .execute_contract(
+            ProjectAccount::Bob.into(),
+            receipt_collection.clone(),
+            &cw721_base::ExecuteMsg::SendNft::<Empty, Empty> {
+                contract: project.get_staking_platform_address().to_string(),
+                token_id: "1".to_string(),
+                msg: Binary::default(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let bob_nfts: Vec<String> = project
+        .query_all_nft(ProjectAccount::Bob)
+        .into_iter()
+        .find(|(collection, _)| collection.to_string() == ProjectNft::Gopniks.to_string())
+        .unwrap()
+        .1
+        .tokens;
+    assert_that(&bob_nfts.contains(&"1".to_string())).is_equal_to(true);
+
+    let res = project.staking_platform_query_receipt(1);
+    assert_that(&res.is_err()).is_equal_to(true);
+
+    Ok(())
+}
+
+#[test]
+fn update_collection_pays_receipt_holder() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let collection = Collection {
+        name: ProjectNft::Gopniks.to_string(),
+        staking_currency: Currency::new(
+            &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+            6,
+        ),
+        daily_rewards: str_to_dec("100000"),
+        emission_type: EmissionType::Spending,
+        owner: ProjectAccount::Owner.to_string(),
+        max_staked_per_address: None,
+        max_total_staked: None,
+        min_stake_duration: None,
+        restake_cooldown: None,
+        fixed_pool: None,
+        hybrid_emission: None,
+        reward_curve: None,
+    };
+
+    let proposal_a: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: collection.clone(),
+        },
+    };
+
+    let proposal_b: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_type: ProposalType::UpdateCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            new_collection_address: None,
+            token_id_mapping: None,
+            new_collection: Collection {
+                daily_rewards: str_to_dec("200000"),
+                ..collection
+            },
+        },
+        ..proposal_a.to_owned()
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_a)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        500_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    let cw721_base_code_id = project.store_cw721_base_code();
+    let receipt_collection = project.instantiate_contract(
+        cw721_base_code_id,
+        "receipts",
+        &cw721_base::msg::InstantiateMsg {
+            name: "Staked Gopniks".to_string(),
+            symbol: "SGOP".to_string(),
+            minter: project.get_staking_platform_address().to_string(),
+        },
+    );
+    project.staking_platform_try_update_config(
+        ProjectAccount::Admin,
+        &None,
+        &None,
+        None,
+        &Some(receipt_collection.clone()),
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+    project.staking_platform_try_stake_with_receipts(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    project.wait((MINS_PER_DAY * NANOS_PER_MIN) as u64);
+
+    project
+        .app
+        .execute_contract(
+            ProjectAccount::Alice.into(),
+            receipt_collection,
+            &cw721_base::ExecuteMsg::TransferNft::<Empty, Empty> {
+                recipient: ProjectAccount::Bob.to_string(),
+                token_id: "1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let get_atom_balance = |project: &Project, account: ProjectAccount| -> u128 {
+        project
+            .query_all_balances(account)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .map(|x| x.amount.u128())
+            .unwrap_or_default()
+    };
+    let alice_atom_balance = get_atom_balance(&project, ProjectAccount::Alice);
+    let bob_atom_balance = get_atom_balance(&project, ProjectAccount::Bob);
+
+    // rewards claimed on collection update are paid to receipt holder
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal_b)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    assert_that(&get_atom_balance(&project, ProjectAccount::Alice)).is_equal_to(alice_atom_balance);
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Bob) - bob_atom_balance))
+        .is_equal_to(100_000);
+    assert_that(&project.staking_platform_query_receipt(1)?.staker)
+        .is_equal_to(Addr::from(ProjectAccount::Bob));
+
+    Ok(())
+}
+
+#[test]
+fn query_voting_power_at_height() -> StdResult<()> {
+    let mut project = Project::new();
+
+    for (id, collection) in [ProjectNft::Gopniks, ProjectNft::Pinjeons]
+        .into_iter()
+        .enumerate()
+    {
+        let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+            proposal_status: None,
+            price: Funds::new(
+                100u128,
+                &Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                    6,
+                ),
+            ),
+            proposal_type: ProposalType::AddCollection {
+                collection_address: collection.to_string(),
+                collection: Collection {
+                    name: collection.to_string(),
+                    staking_currency: Currency::new(
+                        &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                        6,
+                    ),
+                    daily_rewards: str_to_dec("100000"),
+                    emission_type: EmissionType::Spending,
+                    owner: ProjectAccount::Owner.to_string(),
+                    max_staked_per_address: None,
+                    max_total_staked: None,
+                    min_stake_duration: None,
+                    restake_cooldown: None,
+                    fixed_pool: None,
+                    hybrid_emission: None,
+                    reward_curve: None,
+                },
+            },
+        };
+
+        project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+        project.staking_platform_try_accept_proposal(
+            ProjectAccount::Owner,
+            id as u128 + 1,
+            100,
+            ProjectCoin::Denom,
+        )?;
+
+        for staker in [ProjectAccount::Alice, ProjectAccount::Bob] {
+            project.increase_allowances_nft(
+                staker,
+                project.get_staking_platform_address(),
+                collection,
+            );
+        }
+    }
+
+    let get_collection_info =
+        |collection: ProjectNft, token_id_list: &[u128]| StakedCollectionInfo {
+            collection_address: collection.to_string(),
+            staked_token_info_list: token_id_list
+                .iter()
+                .map(|x| StakedTokenInfo {
+                    token_id: Uint128::new(*x),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        };
+
+    let height_before_staking = project.app.block_info().height;
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Gopniks, &[1, 2])],
+    )?;
+    project.staking_platform_try_stake(
+        ProjectAccount::Bob,
+        &[
+            get_collection_info(ProjectNft::Gopniks, &[4]),
+            get_collection_info(ProjectNft::Pinjeons, &[4]),
+        ],
+    )?;
+
+    // stakes are counted starting from next block
+    let res =
+        project.staking_platform_query_voting_power_at_height(ProjectAccount::Alice, None, None)?;
+    assert_that(&res.power.u128()).is_equal_to(0);
+
+    project.wait(10_000_000_000);
+    let height_after_staking = project.app.block_info().height;
+
+    let res = project.staking_platform_query_voting_power_at_height(
+        ProjectAccount::Alice,
+        Some(height_before_staking),
+        None,
+    )?;
+    assert_that(&res.power.u128()).is_equal_to(0);
+
+    let res =
+        project.staking_platform_query_voting_power_at_height(ProjectAccount::Alice, None, None)?;
+    assert_that(&res.power.u128()).is_equal_to(2);
+    assert_that(&res.height).is_equal_to(height_after_staking);
+
+    let res = project.staking_platform_query_voting_power_at_height(
+        ProjectAccount::Bob,
+        None,
+        Some(ProjectNft::Pinjeons),
+    )?;
+    assert_that(&res.power.u128()).is_equal_to(1);
+
+    let res = project.staking_platform_query_total_power_at_height(None, None)?;
+    assert_that(&res.power.u128()).is_equal_to(4);
+
+    let res =
+        project.staking_platform_query_total_power_at_height(None, Some(ProjectNft::Gopniks))?;
+    assert_that(&res.power.u128()).is_equal_to(3);
+
+    // unstaking doesn't change past voting power
+    project.staking_platform_try_unstake(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Gopniks, &[1])],
+    )?;
+    project.wait(10_000_000_000);
+
+    let res = project.staking_platform_query_voting_power_at_height(
+        ProjectAccount::Alice,
+        Some(height_after_staking),
+        None,
+    )?;
+    assert_that(&res.power.u128()).is_equal_to(2);
+
+    let res =
+        project.staking_platform_query_voting_power_at_height(ProjectAccount::Alice, None, None)?;
+    assert_that(&res.power.u128()).is_equal_to(1);
+
+    let res = project.staking_platform_query_total_power_at_height(None, None)?;
+    assert_that(&res.power.u128()).is_equal_to(3);
+
+    // historical staked amounts by collection
+    let res = project.staking_platform_query_staked_at_height(ProjectAccount::Bob, None)?;
+    assert_that(&res.total.u128()).is_equal_to(2);
+    assert_that(&res.collection_and_amount_list.len()).is_equal_to(2);
+
+    let res = project.staking_platform_query_staked_at_height(
+        ProjectAccount::Alice,
+        Some(height_after_staking),
+    )?;
+    assert_that(&res.total.u128()).is_equal_to(2);
+    assert_that(&res.collection_and_amount_list[0].0.to_string())
+        .is_equal_to(ProjectNft::Gopniks.to_string());
+    assert_that(&res.collection_and_amount_list[0].1.u128()).is_equal_to(2);
+
+    let res = project.staking_platform_query_staked_at_height(
+        ProjectAccount::Alice,
+        Some(height_before_staking),
+    )?;
+    assert_that(&res.collection_and_amount_list).is_equal_to(vec![]);
+
+    let res = project.staking_platform_query_collection_total_at_height(
+        ProjectNft::Gopniks,
+        Some(height_after_staking),
+    )?;
+    assert_that(&res.total.u128()).is_equal_to(3);
+
+    let res =
+        project.staking_platform_query_collection_total_at_height(ProjectNft::Gopniks, None)?;
+    assert_that(&res.total.u128()).is_equal_to(2);
+
+    // platform owner is used as DAO
+    let config = project.staking_platform_query_config()?;
+    assert_that(&project.staking_platform_query_dao()?)
+        .is_equal_to(config.owner.unwrap_or(config.admin));
+
+    Ok(())
+}
+
+#[test]
+fn stake_and_unstake_with_hooks() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    let hook_receiver_code_id = project.store_hook_receiver_code();
+    let hook_receiver = project.instantiate_hook_receiver(hook_receiver_code_id);
+
+    let res = project
+        .staking_platform_try_add_hook(ProjectAccount::Alice, &hook_receiver)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    project.staking_platform_try_add_hook(ProjectAccount::Admin, &hook_receiver)?;
+
+    let res = project
+        .staking_platform_try_add_hook(ProjectAccount::Admin, &hook_receiver)
+        .unwrap_err();
+    assert_error(&res, ContractError::HookIsAlreadyAdded);
+
+    assert_that(&project.staking_platform_query_hooks()?).is_equal_to(vec![hook_receiver.clone()]);
+
+    let get_collection_info = |token_id_list: &[u128]| StakedCollectionInfo {
+        collection_address: ProjectNft::Gopniks.to_string(),
+        staked_token_info_list: token_id_list
+            .iter()
+            .map(|x| StakedTokenInfo {
+                token_id: Uint128::new(*x),
+                staking_start_date: None,
+                last_claim_date: None,
+            })
+            .collect(),
+    };
+
+    project.staking_platform_try_stake(ProjectAccount::Alice, &[get_collection_info(&[1, 2])])?;
+    project.staking_platform_try_unstake(ProjectAccount::Alice, &[get_collection_info(&[1])])?;
+
+    let hook_msgs = project.hook_receiver_query_msgs(&hook_receiver)?;
+    assert_that(&hook_msgs).is_equal_to(vec![
+        StakeChangedHookMsg {
+            staker: ProjectAccount::Alice.into(),
+            collection_address: ProjectNft::Gopniks.into(),
+            token_ids: vec![Uint128::new(1), Uint128::new(2)],
+            direction: StakeDirection::Stake,
+        },
+        StakeChangedHookMsg {
+            staker: ProjectAccount::Alice.into(),
+            collection_address: ProjectNft::Gopniks.into(),
+            token_ids: vec![Uint128::new(1)],
+            direction: StakeDirection::Unstake,
+        },
+    ]);
+
+    // removed hook doesn't receive messages
+    project.staking_platform_try_remove_hook(ProjectAccount::Admin, &hook_receiver)?;
+
+    let res = project
+        .staking_platform_try_remove_hook(ProjectAccount::Admin, &hook_receiver)
+        .unwrap_err();
+    assert_error(&res, ContractError::HookIsNotFound);
+
+    project.staking_platform_try_stake(ProjectAccount::Alice, &[get_collection_info(&[3])])?;
+
+    let hook_msgs = project.hook_receiver_query_msgs(&hook_receiver)?;
+    assert_that(&hook_msgs.len()).is_equal_to(2);
+
+    Ok(())
+}
+
+#[test]
+fn stake_and_claim_with_events() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let get_attr = |res: &AppResponse, event_type: &str, key: &str| -> Option<String> {
+        res.events
+            .iter()
+            .find(|x| x.ty == format!("wasm-{}", event_type))
+            .and_then(|x| x.attributes.iter().find(|y| y.key == key))
+            .map(|x| x.value.to_owned())
+    };
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    let res = project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    assert_that(&get_attr(&res, EVENT_PROPOSAL, ATTR_STATUS_FROM))
+        .is_equal_to(Some(EMPTY_VALUE.to_string()));
+    assert_that(&get_attr(&res, EVENT_PROPOSAL, ATTR_STATUS_TO))
+        .is_equal_to(Some(ProposalStatus::Active.to_string()));
+
+    let res = project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    assert_that(&get_attr(&res, EVENT_PROPOSAL, ATTR_PROPOSAL_ID))
+        .is_equal_to(Some("1".to_string()));
+    assert_that(&get_attr(&res, EVENT_PROPOSAL, ATTR_STATUS_TO))
+        .is_equal_to(Some(ProposalStatus::Accepted.to_string()));
+
+    let res = project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        200_000u128,
+        ProjectToken::Atom,
+    )?;
+    assert_that(&get_attr(&res, EVENT_DEPOSIT, ATTR_TOKEN))
+        .is_equal_to(Some(ProjectToken::Atom.to_string()));
+    assert_that(&get_attr(&res, EVENT_DEPOSIT, ATTR_AMOUNT))
+        .is_equal_to(Some("200000".to_string()));
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    let res = project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: [1, 2]
+                .iter()
+                .map(|x| StakedTokenInfo {
+                    token_id: Uint128::new(*x),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        }],
+    )?;
+    assert_that(&get_attr(&res, EVENT_STAKE, ATTR_STAKER))
+        .is_equal_to(Some(ProjectAccount::Alice.to_string()));
+    assert_that(&get_attr(&res, EVENT_STAKE, ATTR_COLLECTION_ADDRESS))
+        .is_equal_to(Some(ProjectNft::Gopniks.to_string()));
+    assert_that(&get_attr(&res, EVENT_STAKE, ATTR_TOKEN_IDS)).is_equal_to(Some("1,2".to_string()));
+
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64 / 10);
+
+    let res = project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&get_attr(&res, EVENT_REWARDS, ATTR_TOKEN))
+        .is_equal_to(Some(ProjectToken::Atom.to_string()));
+    assert_that(&get_attr(&res, EVENT_REWARDS, ATTR_EMISSION_TYPE))
+        .is_equal_to(Some(EmissionType::Spending.to_string()));
+    assert_that(&get_attr(&res, EVENT_REWARDS, ATTR_AMOUNT)).is_equal_to(Some("20000".to_string()));
+
+    let res = project.staking_platform_try_update_config(
+        ProjectAccount::Admin,
+        &None::<String>,
+        &None,
+        Some("0.1"),
+        &None,
+    )?;
+    assert_that(&get_attr(&res, EVENT_UPDATE_CONFIG, "protocol_fee"))
+        .is_equal_to(Some("0.1".to_string()));
+    assert_that(&get_attr(
+        &res,
+        EVENT_UPDATE_CONFIG,
+        &format!("{}protocol_fee", PREVIOUS_VALUE_PREFIX),
+    ))
+    .is_equal_to(Some("0".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn stake_set_with_multiplier_bonus() -> StdResult<()> {
+    let mut project = Project::new();
+
+    for (id, collection) in [ProjectNft::Gopniks, ProjectNft::Pinjeons]
+        .into_iter()
+        .enumerate()
+    {
+        let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+            proposal_status: None,
+            price: Funds::new(
+                100u128,
+                &Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                    6,
+                ),
+            ),
+            proposal_type: ProposalType::AddCollection {
+                collection_address: collection.to_string(),
+                collection: Collection {
+                    name: collection.to_string(),
+                    staking_currency: Currency::new(
+                        &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                        6,
+                    ),
+                    daily_rewards: str_to_dec("100000"),
+                    emission_type: EmissionType::Spending,
+                    owner: ProjectAccount::Owner.to_string(),
+                    max_staked_per_address: None,
+                    max_total_staked: None,
+                    min_stake_duration: None,
+                    restake_cooldown: None,
+                    fixed_pool: None,
+                    hybrid_emission: None,
+                    reward_curve: None,
+                },
+            },
+        };
+
+        project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+        project.staking_platform_try_accept_proposal(
+            ProjectAccount::Owner,
+            id as u128 + 1,
+            100,
+            ProjectCoin::Denom,
+        )?;
+        project.staking_platform_try_deposit_tokens(
+            ProjectAccount::Owner,
+            collection,
+            300_000u128,
+            ProjectToken::Atom,
+        )?;
+        project.increase_allowances_nft(
+            ProjectAccount::Alice,
+            project.get_staking_platform_address(),
+            collection,
+        );
+    }
+
+    let set = StakingSet {
+        name: "Gopniks and Pinjeons".to_string(),
+        collection_list: vec![
+            ProjectNft::Gopniks.to_string(),
+            ProjectNft::Pinjeons.to_string(),
+        ],
+        bonus: SetBonus::Multiplier {
+            value: str_to_dec("1.5"),
+        },
+    };
+
+    let res = project
+        .staking_platform_try_create_set(ProjectAccount::Alice, &set)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = project
+        .staking_platform_try_create_set(
+            ProjectAccount::Admin,
+            &StakingSet {
+                collection_list: vec![ProjectNft::Gopniks.to_string()],
+                ..set.clone()
+            },
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongSetParameters);
+
+    project.staking_platform_try_create_set(ProjectAccount::Admin, &set)?;
+    assert_that(&project.staking_platform_query_sets()?.len()).is_equal_to(1);
+
+    let get_collection_info = |collection: ProjectNft| StakedCollectionInfo {
+        collection_address: collection.to_string(),
+        staked_token_info_list: vec![StakedTokenInfo {
+            token_id: Uint128::new(1),
+            staking_start_date: None,
+            last_claim_date: None,
+        }],
+    };
+    let get_atom_balance = |project: &Project| -> u128 {
+        project
+            .query_all_balances(ProjectAccount::Alice)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    // set is completed with second collection
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Gopniks)],
+    )?;
+    assert_that(
+        &project
+            .staking_platform_query_active_sets(ProjectAccount::Alice)?
+            .len(),
+    )
+    .is_equal_to(0);
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Pinjeons)],
+    )?;
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64);
+
+    let active_sets = project.staking_platform_query_active_sets(ProjectAccount::Alice)?;
+    assert_that(&active_sets.len()).is_equal_to(1);
+    assert_that(
+        &active_sets[0]
+            .bonus_list
+            .iter()
+            .map(|x| x.amount.u128())
+            .collect::<Vec<u128>>(),
+    )
+    .is_equal_to(vec![50_000, 50_000]);
+
+    // base rewards and set bonus
+    let balance = get_atom_balance(&project);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&(get_atom_balance(&project) - balance)).is_equal_to(300_000);
+
+    // breaking the set settles its bonus and stops accrual
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64 / 2);
+
+    let balance = get_atom_balance(&project);
+    project.staking_platform_try_unstake(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Pinjeons)],
+    )?;
+    assert_that(&(get_atom_balance(&project) - balance)).is_equal_to(100_000);
+    assert_that(
+        &project
+            .staking_platform_query_active_sets(ProjectAccount::Alice)?
+            .len(),
+    )
+    .is_equal_to(0);
+
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64 / 2);
+
+    let balance = get_atom_balance(&project);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&(get_atom_balance(&project) - balance)).is_equal_to(100_000);
+
+    // removing the set settles accrued bonuses of its stakers
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Pinjeons)],
+    )?;
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64 / 2);
+
+    let res = project
+        .staking_platform_try_remove_set(ProjectAccount::Alice, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    // collection of existing set can't be removed
+    let res = project
+        .staking_platform_try_remove_collection(ProjectAccount::Admin, ProjectNft::Gopniks)
+        .unwrap_err();
+    assert_error(&res, ContractError::CollectionIsInSet);
+
+    let balance = get_atom_balance(&project);
+    project.staking_platform_try_remove_set(ProjectAccount::Admin, 1)?;
+    assert_that(&(get_atom_balance(&project) - balance)).is_equal_to(50_000);
+    assert_that(&project.staking_platform_query_sets()?.len()).is_equal_to(0);
+    assert_that(
+        &project
+            .staking_platform_query_active_sets(ProjectAccount::Alice)?
+            .len(),
+    )
+    .is_equal_to(0);
+
+    let res = project
+        .staking_platform_try_remove_set(ProjectAccount::Admin, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::SetIsNotFound);
+
+    // new set is activated for stakers already completed it
+    project.staking_platform_try_create_set(ProjectAccount::Admin, &set)?;
+    assert_that(
+        &project
+            .staking_platform_query_active_sets(ProjectAccount::Alice)?
+            .len(),
+    )
+    .is_equal_to(1);
+
+    Ok(())
+}
+
+#[test]
+fn break_set_while_claiming_is_paused() -> StdResult<()> {
+    let mut project = Project::new();
+
+    for (id, collection) in [ProjectNft::Gopniks, ProjectNft::Pinjeons]
+        .into_iter()
+        .enumerate()
+    {
+        let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+            proposal_status: None,
+            price: Funds::new(
+                100u128,
+                &Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                    6,
+                ),
+            ),
+            proposal_type: ProposalType::AddCollection {
+                collection_address: collection.to_string(),
+                collection: Collection {
+                    name: collection.to_string(),
+                    staking_currency: Currency::new(
+                        &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                        6,
+                    ),
+                    daily_rewards: str_to_dec("100000"),
+                    emission_type: EmissionType::Spending,
+                    owner: ProjectAccount::Owner.to_string(),
+                    max_staked_per_address: None,
+                    max_total_staked: None,
+                    min_stake_duration: None,
+                    restake_cooldown: None,
+                    fixed_pool: None,
+                    hybrid_emission: None,
+                    reward_curve: None,
+                },
+            },
+        };
+
+        project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+        project.staking_platform_try_accept_proposal(
+            ProjectAccount::Owner,
+            id as u128 + 1,
+            100,
+            ProjectCoin::Denom,
+        )?;
+        project.staking_platform_try_deposit_tokens(
+            ProjectAccount::Owner,
+            collection,
+            300_000u128,
+            ProjectToken::Atom,
+        )?;
+        project.increase_allowances_nft(
+            ProjectAccount::Alice,
+            project.get_staking_platform_address(),
+            collection,
+        );
+    }
+
+    project.staking_platform_try_create_set(
+        ProjectAccount::Admin,
+        &StakingSet {
+            name: "Gopniks and Pinjeons".to_string(),
+            collection_list: vec![
+                ProjectNft::Gopniks.to_string(),
+                ProjectNft::Pinjeons.to_string(),
+            ],
+            bonus: SetBonus::Multiplier {
+                value: str_to_dec("1.5"),
+            },
+        },
+    )?;
+
+    let get_collection_info = |collection: ProjectNft| StakedCollectionInfo {
+        collection_address: collection.to_string(),
+        staked_token_info_list: vec![StakedTokenInfo {
+            token_id: Uint128::new(1),
+            staking_start_date: None,
+            last_claim_date: None,
+        }],
+    };
+    let get_atom_balance = |project: &Project| -> u128 {
+        project
+            .query_all_balances(ProjectAccount::Alice)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[
+            get_collection_info(ProjectNft::Gopniks),
+            get_collection_info(ProjectNft::Pinjeons),
+        ],
+    )?;
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64);
+
+    project.staking_platform_try_pause_actions(
+        ProjectAccount::Admin,
+        &[ActionGroup::Claiming],
+        Some(ProjectNft::Gopniks),
+        "incident",
+        None,
+    )?;
+
+    // set is broken, its bonus is kept until claiming is resumed
+    let balance = get_atom_balance(&project);
+    project.staking_platform_try_unstake(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Pinjeons)],
+    )?;
+    assert_that(&(get_atom_balance(&project) - balance)).is_equal_to(100_000);
+    assert_that(
+        &project
+            .staking_platform_query_active_sets(ProjectAccount::Alice)?
+            .len(),
+    )
+    .is_equal_to(0);
+
+    project.staking_platform_try_resume_actions(
+        ProjectAccount::Admin,
+        &[ActionGroup::Claiming],
+        Some(ProjectNft::Gopniks),
+    )?;
+
+    // base rewards of paused collection and set bonus
+    let balance = get_atom_balance(&project);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&(get_atom_balance(&project) - balance)).is_equal_to(200_000);
+
+    // pending set bonus is claimed once
+    let balance = get_atom_balance(&project);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&get_atom_balance(&project)).is_equal_to(balance);
+
+    Ok(())
+}
+
+#[test]
+fn update_collection_settles_set_bonus_of_moved_receipt() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let get_collection = |collection: ProjectNft| Collection {
+        name: collection.to_string(),
+        staking_currency: Currency::new(
+            &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+            6,
+        ),
+        daily_rewards: str_to_dec("100000"),
+        emission_type: EmissionType::Spending,
+        owner: ProjectAccount::Owner.to_string(),
+        max_staked_per_address: None,
+        max_total_staked: None,
+        min_stake_duration: None,
+        restake_cooldown: None,
+        fixed_pool: None,
+        hybrid_emission: None,
+        reward_curve: None,
+    };
+
+    for (id, collection) in [ProjectNft::Gopniks, ProjectNft::Pinjeons]
+        .into_iter()
+        .enumerate()
+    {
+        let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+            proposal_status: None,
+            price: Funds::new(
+                100u128,
+                &Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                    6,
+                ),
+            ),
+            proposal_type: ProposalType::AddCollection {
+                collection_address: collection.to_string(),
+                collection: get_collection(collection),
+            },
+        };
+
+        project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+        project.staking_platform_try_accept_proposal(
+            ProjectAccount::Owner,
+            id as u128 + 1,
+            100,
+            ProjectCoin::Denom,
+        )?;
+        project.staking_platform_try_deposit_tokens(
+            ProjectAccount::Owner,
+            collection,
+            300_000u128,
+            ProjectToken::Atom,
+        )?;
+        project.increase_allowances_nft(
+            ProjectAccount::Alice,
+            project.get_staking_platform_address(),
+            collection,
+        );
+    }
+
+    project.staking_platform_try_create_set(
+        ProjectAccount::Admin,
+        &StakingSet {
+            name: "Gopniks and Pinjeons".to_string(),
+            collection_list: vec![
+                ProjectNft::Gopniks.to_string(),
+                ProjectNft::Pinjeons.to_string(),
+            ],
+            bonus: SetBonus::Multiplier {
+                value: str_to_dec("1.5"),
+            },
+        },
+    )?;
+
+    let cw721_base_code_id = project.store_cw721_base_code();
+    let receipt_collection = project.instantiate_contract(
+        cw721_base_code_id,
+        "receipts",
+        &cw721_base::msg::InstantiateMsg {
+            name: "Staked Gopniks".to_string(),
+            symbol: "SGOP".to_string(),
+            minter: project.get_staking_platform_address().to_string(),
+        },
+    );
+    project.staking_platform_try_update_config(
+        ProjectAccount::Admin,
+        &None,
+        &None,
+        None,
+        &Some(receipt_collection.clone()),
+    )?;
+
+    let get_collection_info = |collection: ProjectNft| StakedCollectionInfo {
+        collection_address: collection.to_string(),
+        staked_token_info_list: vec![StakedTokenInfo {
+            token_id: Uint128::new(1),
+            staking_start_date: None,
+            last_claim_date: None,
+        }],
+    };
+    project.staking_platform_try_stake_with_receipts(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Gopniks)],
+    )?;
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[get_collection_info(ProjectNft::Pinjeons)],
+    )?;
+    project.wait((MINS_PER_DAY * NANOS_PER_MIN) as u64);
+
+    project
+        .app
+        .execute_contract(
+            ProjectAccount::Alice.into(),
+            receipt_collection,
+            &cw721_base::ExecuteMsg::TransferNft::<Empty, Empty> {
+                recipient: ProjectAccount::Bob.to_string(),
+                token_id: "1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let get_atom_balance = |project: &Project, account: ProjectAccount| -> u128 {
+        project
+            .query_all_balances(account)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .map(|x| x.amount.u128())
+            .unwrap_or_default()
+    };
+    let alice_atom_balance = get_atom_balance(&project, ProjectAccount::Alice);
+    let bob_atom_balance = get_atom_balance(&project, ProjectAccount::Bob);
+
+    // moving the receipt breaks the set and its bonus is paid from collection balances
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::UpdateCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            new_collection_address: None,
+            token_id_mapping: None,
+            new_collection: Collection {
+                daily_rewards: str_to_dec("200000"),
+                ..get_collection(ProjectNft::Gopniks)
+            },
+        },
+    };
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        3,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Alice) - alice_atom_balance))
+        .is_equal_to(100_000);
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Bob) - bob_atom_balance))
+        .is_equal_to(100_000);
+    assert_that(
+        &project
+            .staking_platform_query_active_sets(ProjectAccount::Alice)?
+            .len(),
+    )
+    .is_equal_to(0);
+
+    let collections_balances = project.staking_platform_query_collections_balances(&Some(vec![
+        ProjectNft::Gopniks,
+        ProjectNft::Pinjeons,
+    ]))?;
+    assert_that(
+        &collections_balances
+            .iter()
+            .map(|x| x.funds.amount.u128())
+            .collect::<Vec<u128>>(),
+    )
+    .is_equal_to(vec![150_000, 250_000]);
+
+    Ok(())
+}
+
+#[test]
+fn claim_staking_rewards_with_vesting() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        200_000u128,
+        ProjectToken::Atom,
+    )?;
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    let day = MINS_PER_DAY as u64 * NANOS_PER_MIN as u64;
+    let res = project
+        .staking_platform_try_update_collection_vesting(
+            ProjectAccount::Admin,
+            ProjectNft::Gopniks,
+            Some(VestingPolicy {
+                cliff: 2 * day / 1_000_000_000,
+                duration: day / 1_000_000_000,
+            }),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongVestingPolicy);
+
+    project.staking_platform_try_update_collection_vesting(
+        ProjectAccount::Admin,
+        ProjectNft::Gopniks,
+        Some(VestingPolicy {
+            cliff: day / 1_000_000_000,
+            duration: 4 * day / 1_000_000_000,
+        }),
+    )?;
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+    project.wait(day);
+
+    let get_atom_balance = |project: &Project| -> u128 {
+        project
+            .query_all_balances(ProjectAccount::Alice)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let balance = get_atom_balance(&project);
+
+    // claimed rewards are locked until cliff date
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&get_atom_balance(&project)).is_equal_to(balance);
+
+    let vestings = project.staking_platform_query_vestings(ProjectAccount::Alice, None, None)?;
+    assert_that(&vestings.len()).is_equal_to(1);
+    assert_that(&vestings[0].vesting.funds.amount.u128()).is_equal_to(100_000);
+    assert_that(&vestings[0].withdrawable_amount.u128()).is_equal_to(0);
+
+    let res = project
+        .staking_platform_try_withdraw_vested_rewards(ProjectAccount::Alice, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::VestedRewardsAreNotFound);
+
+    // rewards are vested linearly after the cliff, schedules are processed by pages
+    project.wait(2 * day);
+    assert_that(
+        &project
+            .staking_platform_query_vestings(ProjectAccount::Alice, Some(1), None)?
+            .len(),
+    )
+    .is_equal_to(0);
+
+    let res = project
+        .staking_platform_try_withdraw_vested_rewards(ProjectAccount::Alice, Some(1), None)
+        .unwrap_err();
+    assert_error(&res, ContractError::VestedRewardsAreNotFound);
+
+    project.staking_platform_try_withdraw_vested_rewards(ProjectAccount::Alice, None, None)?;
+    assert_that(&(get_atom_balance(&project) - balance)).is_equal_to(50_000);
+
+    project.wait(2 * day);
+    project.staking_platform_try_withdraw_vested_rewards(ProjectAccount::Alice, None, None)?;
+    assert_that(&(get_atom_balance(&project) - balance)).is_equal_to(100_000);
+    assert_that(
+        &project
+            .staking_platform_query_vestings(ProjectAccount::Alice, None, None)?
+            .len(),
+    )
+    .is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn query_staker_portfolio() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        500_000u128,
+        ProjectToken::Atom,
+    )?;
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: [1, 2]
+                .iter()
+                .map(|x| StakedTokenInfo {
+                    token_id: Uint128::new(*x),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        }],
+    )?;
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64);
+
+    let portfolio = project.staking_platform_query_staker_portfolio(ProjectAccount::Alice)?;
+    assert_that(&portfolio.total_staked.u128()).is_equal_to(2);
+    assert_that(&portfolio.collection_list.len()).is_equal_to(1);
+
+    let collection_item = &portfolio.collection_list[0];
+    assert_that(&collection_item.collection.name).is_equal_to(ProjectNft::Gopniks.to_string());
+    assert_that(
+        &collection_item
+            .token_list
+            .iter()
+            .map(|x| (x.staked_token_info.token_id.u128(), x.rewards.u128()))
+            .collect::<Vec<_>>(),
+    )
+    .is_equal_to(vec![(1, 100_000), (2, 100_000)]);
+    assert_that(&collection_item.rewards.amount.u128()).is_equal_to(200_000);
+
+    let atom = Token::new_cw20(&ProjectToken::Atom.into());
+    assert_that(&portfolio.rewards_list.len()).is_equal_to(1);
+    assert_that(&portfolio.rewards_list[0].0.currency.token).is_equal_to(&atom);
+    assert_that(&portfolio.rewards_list[0].0.amount.u128()).is_equal_to(200_000);
+    assert_that(&portfolio.rewards_list[0].1).is_equal_to(EmissionType::Spending);
+
+    let wallet_balance = project
+        .query_all_balances(ProjectAccount::Alice)?
+        .into_iter()
+        .find(|x| x.currency.token == atom)
+        .unwrap();
+    assert_that(&portfolio.balance_list.contains(&wallet_balance)).is_equal_to(true);
+
+    // unknown staker gets empty portfolio
+    let portfolio = project.staking_platform_query_staker_portfolio(ProjectAccount::Bob)?;
+    assert_that(&portfolio.total_staked.u128()).is_equal_to(0);
+    assert_that(&portfolio.collection_list.len()).is_equal_to(0);
+    assert_that(&portfolio.rewards_list.len()).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn query_platform_and_collection_stats() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        500_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    let collection_info = |token_id_list: &[u128]| {
+        vec![StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: token_id_list
+                .iter()
+                .map(|x| StakedTokenInfo {
+                    token_id: Uint128::new(*x),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        }]
+    };
+
+    for staker in [ProjectAccount::Alice, ProjectAccount::Bob] {
+        project.increase_allowances_nft(
+            staker,
+            project.get_staking_platform_address(),
+            ProjectNft::Gopniks,
+        );
+    }
+
+    project.staking_platform_try_stake(ProjectAccount::Alice, &collection_info(&[1, 2]))?;
+    project.staking_platform_try_stake(ProjectAccount::Bob, &collection_info(&[4]))?;
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    project.staking_platform_try_unstake(ProjectAccount::Bob, &collection_info(&[4]))?;
+
+    let atom = Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6);
+    let denom = Currency::new(&Token::new_native(&ProjectCoin::Denom.to_string()), 6);
+
+    let stats = project.staking_platform_query_platform_stats()?;
+    assert_that(&stats).is_equal_to(QueryPlatformStatsResponse {
+        total_staked: Uint128::new(2),
+        unique_stakers: Uint128::one(),
+        claimed_list: vec![(Funds::new(300_000u128, &atom), EmissionType::Spending)],
+        minted_list: vec![],
+        listing_revenue_list: vec![Funds::new(100u128, &denom)],
+    });
+
+    let stats = project.staking_platform_query_collection_stats(ProjectNft::Gopniks)?;
+    assert_that(&stats).is_equal_to(QueryCollectionStatsResponse {
+        collection_address: ProjectNft::Gopniks.into(),
+        total_staked: Uint128::new(2),
+        unique_stakers: Uint128::one(),
+        claimed_list: vec![(Funds::new(300_000u128, &atom), EmissionType::Spending)],
+        listing_revenue_list: vec![Funds::new(100u128, &denom)],
+    });
+
+    // collection without stakers has empty stats
+    let stats = project.staking_platform_query_collection_stats(ProjectNft::Pinjeons)?;
+    assert_that(&stats.unique_stakers).is_equal_to(Uint128::zero());
+    assert_that(&stats.claimed_list.len()).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn query_claim_history() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        1_000_000u128,
+        ProjectToken::Atom,
+    )?;
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: [1, 2]
+                .iter()
+                .map(|x| StakedTokenInfo {
+                    token_id: Uint128::new(*x),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        }],
+    )?;
+
+    let day = MINS_PER_DAY as u64 * NANOS_PER_MIN as u64;
+    project.wait(day);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    project.wait(day);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+
+    let atom = Currency::new(&Token::new_cw20(&ProjectToken::Atom.into()), 6);
+    let history =
+        project.staking_platform_query_claim_history(ProjectAccount::Alice, None, None)?;
+    assert_that(&history.claimed_list).is_equal_to(vec![ClaimedRewardsItem {
+        collection_address: ProjectNft::Gopniks.into(),
+        funds_list: vec![Funds::new(400_000u128, &atom)],
+    }]);
+    assert_that(
+        &history
+            .claim_list
+            .iter()
+            .map(|x| (x.id.u128(), x.claim.funds.amount.u128()))
+            .collect::<Vec<_>>(),
+    )
+    .is_equal_to(vec![(2, 200_000), (1, 200_000)]);
+    assert_that(&history.claim_list[0].claim.claim_date).is_equal_to(project.app.block_info().time);
+
+    // claims are paginated from newest to oldest
+    let history =
+        project.staking_platform_query_claim_history(ProjectAccount::Alice, Some(2), Some(1))?;
+    assert_that(&history.claim_list.len()).is_equal_to(1);
+    assert_that(&history.claim_list[0].id.u128()).is_equal_to(1);
+
+    // recent claims log is bounded
+    for _ in 0..CLAIM_LOG_MAX_LENGTH {
+        project.wait(5_000_000_000);
+        project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    }
+
+    let history = project.staking_platform_query_claim_history(
+        ProjectAccount::Alice,
+        None,
+        Some(PAGINATION_MAX_LIMIT),
+    )?;
+    assert_that(&history.claim_list.len()).is_equal_to(CLAIM_LOG_MAX_LENGTH as usize);
+    assert_that(&history.claim_list.last().unwrap().id.u128()).is_equal_to(3);
+
+    Ok(())
+}
+
+#[test]
+fn distribute_bonus_to_stakers() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    let res = project
+        .staking_platform_try_distribute_bonus(
+            ProjectAccount::Owner,
+            ProjectNft::Gopniks,
+            300u128,
+            ProjectCoin::Noria,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::StakersAreNotFound);
+
+    let collection_info = |token_id_list: &[u128]| {
+        vec![StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: token_id_list
+                .iter()
+                .map(|x| StakedTokenInfo {
+                    token_id: Uint128::new(*x),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        }]
+    };
+
+    for staker in [ProjectAccount::Alice, ProjectAccount::Bob] {
+        project.increase_allowances_nft(
+            staker,
+            project.get_staking_platform_address(),
+            ProjectNft::Gopniks,
+        );
+    }
+
+    project.staking_platform_try_stake(ProjectAccount::Alice, &collection_info(&[1, 2]))?;
+    project.staking_platform_try_stake(ProjectAccount::Bob, &collection_info(&[4]))?;
+
+    let res = project
+        .staking_platform_try_distribute_bonus(
+            ProjectAccount::Alice,
+            ProjectNft::Gopniks,
+            300u128,
+            ProjectCoin::Noria,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    // bonus is split pro rata to staked NFTs
+    project.staking_platform_try_distribute_bonus(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        300u128,
+        ProjectCoin::Noria,
+    )?;
+
+    // unstaked NFTs don't get next bonuses
+    project.staking_platform_try_unstake(ProjectAccount::Bob, &collection_info(&[4]))?;
+    project.staking_platform_try_distribute_bonus(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        100u128,
+        ProjectToken::Luna,
+    )?;
+
+    let get_balance = |project: &Project, staker: ProjectAccount, token: Token| -> u128 {
+        project
+            .query_all_balances(staker)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == token)
+            .map(|x| x.amount.u128())
+            .unwrap_or_default()
+    };
+    let noria = Token::new_native(&ProjectCoin::Noria.to_string());
+    let luna = Token::new_cw20(&ProjectToken::Luna.into());
+
+    let alice_noria = get_balance(&project, ProjectAccount::Alice, noria.clone());
+    let alice_luna = get_balance(&project, ProjectAccount::Alice, luna.clone());
+    let bob_noria = get_balance(&project, ProjectAccount::Bob, noria.clone());
+    let bob_luna = get_balance(&project, ProjectAccount::Bob, luna.clone());
+
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Bob, &None)?;
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Bob, &None)?;
+
+    assert_that(&(get_balance(&project, ProjectAccount::Alice, noria.clone()) - alice_noria))
+        .is_equal_to(200);
+    assert_that(&(get_balance(&project, ProjectAccount::Alice, luna.clone()) - alice_luna))
+        .is_equal_to(100);
+    assert_that(&(get_balance(&project, ProjectAccount::Bob, noria) - bob_noria)).is_equal_to(100);
+    assert_that(&(get_balance(&project, ProjectAccount::Bob, luna) - bob_luna)).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn claim_staking_rewards_with_fixed_pool() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let get_proposal = |epoch_rewards: &str, epoch_duration: u64, is_update: bool| {
+        let collection = Collection {
+            name: ProjectNft::Gopniks.to_string(),
+            staking_currency: Currency::new(
+                &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                6,
+            ),
+            daily_rewards: str_to_dec("1"),
+            emission_type: EmissionType::Spending,
+            owner: ProjectAccount::Owner.to_string(),
+            max_staked_per_address: None,
+            max_total_staked: None,
+            min_stake_duration: None,
+            restake_cooldown: None,
+            fixed_pool: Some(FixedPool {
+                epoch_rewards: str_to_dec(epoch_rewards),
+                epoch_duration,
+            }),
+            hybrid_emission: None,
+            reward_curve: None,
+        };
+
+        Proposal {
+            proposal_status: None,
+            price: Funds::new(
+                100u128,
+                &Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                    6,
+                ),
+            ),
+            proposal_type: if is_update {
+                ProposalType::UpdateCollection {
+                    collection_address: ProjectNft::Gopniks.to_string(),
+                    new_collection_address: None,
+                    token_id_mapping: None,
+                    new_collection: collection,
+                }
+            } else {
+                ProposalType::AddCollection {
+                    collection_address: ProjectNft::Gopniks.to_string(),
+                    collection,
+                }
+            },
+        }
+    };
+
+    let day = MINS_PER_DAY as u64 * NANOS_PER_MIN as u64;
+    let epoch_duration = day / 1_000_000_000;
+
+    let res = project
+        .staking_platform_try_create_proposal(
+            ProjectAccount::Admin,
+            &get_proposal("100000", 0, false),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongFixedPool);
+
+    project.staking_platform_try_create_proposal(
+        ProjectAccount::Admin,
+        &get_proposal("100000", epoch_duration, false),
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        1_000_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    let collection_info = |token_id: u128| {
+        vec![StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(token_id),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }]
+    };
+
+    for staker in [ProjectAccount::Alice, ProjectAccount::Bob] {
+        project.increase_allowances_nft(
+            staker,
+            project.get_staking_platform_address(),
+            ProjectNft::Gopniks,
+        );
+    }
+
+    // single staker gets whole epoch rewards, then they are shared
+    project.staking_platform_try_stake(ProjectAccount::Alice, &collection_info(1))?;
+    project.wait(day);
+    project.staking_platform_try_stake(ProjectAccount::Bob, &collection_info(4))?;
+    project.wait(day);
+
+    let get_rewards = |project: &Project, staker: ProjectAccount| -> u128 {
+        project
+            .staking_platform_query_staking_rewards(staker)
+            .unwrap()
+            .funds_list[0]
+            .amount
+            .u128()
+    };
+    assert_that(&get_rewards(&project, ProjectAccount::Alice)).is_equal_to(150_000);
+    assert_that(&get_rewards(&project, ProjectAccount::Bob)).is_equal_to(50_000);
+
+    let get_atom_balance = |project: &Project, staker: ProjectAccount| -> u128 {
+        project
+            .query_all_balances(staker)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_cw20(&ProjectToken::Atom.into()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let alice_balance = get_atom_balance(&project, ProjectAccount::Alice);
+    let bob_balance = get_atom_balance(&project, ProjectAccount::Bob);
+
+    // rewards are claimed before updating fixed pool
+    project.staking_platform_try_create_proposal(
+        ProjectAccount::Admin,
+        &get_proposal("200000", epoch_duration, true),
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Alice) - alice_balance))
+        .is_equal_to(150_000);
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Bob) - bob_balance))
+        .is_equal_to(50_000);
+
+    project.wait(day);
+    assert_that(&get_rewards(&project, ProjectAccount::Alice)).is_equal_to(100_000);
+    assert_that(&get_rewards(&project, ProjectAccount::Bob)).is_equal_to(100_000);
+
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&(get_atom_balance(&project, ProjectAccount::Alice) - alice_balance))
+        .is_equal_to(250_000);
+
+    Ok(())
+}
+
+#[test]
+fn claim_staking_rewards_with_hybrid_emission() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let get_proposal = |emission_type: EmissionType| Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("200000"),
+                emission_type,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: Some(HybridEmission {
+                    mint_cap: Some(Uint128::new(150_000)),
+                }),
+                reward_curve: None,
+            },
+        },
+    };
+
+    // hybrid emission requires spending collection with token created by owner
+    let res = project
+        .staking_platform_try_create_proposal(
+            ProjectAccount::Admin,
+            &get_proposal(EmissionType::Minting),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongHybridEmission);
+
+    let res = project
+        .staking_platform_try_create_proposal(
+            ProjectAccount::Admin,
+            &get_proposal(EmissionType::Spending),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::UnownedStakingCurrency);
+
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+    project.staking_platform_try_create_proposal(
+        ProjectAccount::Admin,
+        &get_proposal(EmissionType::Spending),
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        100_000u128,
+        ProjectCoin::Noria,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+
+    let get_noria_balance = |project: &Project| -> u128 {
+        project
+            .query_all_balances(ProjectAccount::Alice)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let get_collection_balance = |project: &Project| -> u128 {
+        project
+            .staking_platform_query_collections_balances(&None)
+            .unwrap()[0]
+            .funds
+            .amount
+            .u128()
+    };
+    let day = MINS_PER_DAY as u64 * NANOS_PER_MIN as u64;
+
+    // balance is spent first, the shortfall is minted
+    let alice_balance = get_noria_balance(&project);
+    project.wait(day);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&(get_noria_balance(&project) - alice_balance)).is_equal_to(200_000);
+    assert_that(&get_collection_balance(&project)).is_equal_to(0);
+
+    // rewards exceeding mint cap aren't paid
+    let alice_balance = get_noria_balance(&project);
+    project.wait(day);
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&(get_noria_balance(&project) - alice_balance)).is_equal_to(0);
+
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        150_000u128,
+        ProjectCoin::Noria,
+    )?;
+    project.staking_platform_try_claim_staking_rewards(ProjectAccount::Alice, &None)?;
+    assert_that(&(get_noria_balance(&project) - alice_balance)).is_equal_to(200_000);
+    assert_that(&get_collection_balance(&project)).is_equal_to(0);
 
     Ok(())
 }
 
 #[test]
-fn create_denom_and_set_metadata_unauth() -> StdResult<()> {
+fn migrate_collection_with_hybrid_emission() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let get_collection = |collection: ProjectNft| Collection {
+        name: collection.to_string(),
+        staking_currency: Currency::new(
+            &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+            6,
+        ),
+        daily_rewards: str_to_dec("200000"),
+        emission_type: EmissionType::Spending,
+        owner: ProjectAccount::Owner.to_string(),
+        max_staked_per_address: None,
+        max_total_staked: None,
+        min_stake_duration: None,
+        restake_cooldown: None,
+        fixed_pool: None,
+        hybrid_emission: Some(HybridEmission {
+            mint_cap: Some(Uint128::new(150_000)),
+        }),
+        reward_curve: None,
+    };
+    let get_proposal = |proposal_type: ProposalType<String, TokenUnverified>| Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type,
+    };
+
+    project.minter_try_create_denom(
+        ProjectAccount::Admin,
+        ProjectAccount::Owner,
+        ProjectCoin::Noria,
+        (1, ProjectCoin::Denom),
+    )?;
+    project.staking_platform_try_create_proposal(
+        ProjectAccount::Admin,
+        &get_proposal(ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: get_collection(ProjectNft::Gopniks),
+        }),
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        100_000u128,
+        ProjectCoin::Noria,
+    )?;
+
+    project.increase_allowances_nft(
+        ProjectAccount::Alice,
+        project.get_staking_platform_address(),
+        ProjectNft::Gopniks,
+    );
+    project.staking_platform_try_stake(
+        ProjectAccount::Alice,
+        &[StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: vec![StakedTokenInfo {
+                token_id: Uint128::new(1),
+                staking_start_date: None,
+                last_claim_date: None,
+            }],
+        }],
+    )?;
+    project.mint_nft(
+        ProjectAccount::Owner,
+        project.get_staking_platform_address(),
+        ProjectNft::Pinjeons,
+        &vec![101u128],
+    );
+
+    let get_noria_balance = |project: &Project| -> u128 {
+        project
+            .query_all_balances(ProjectAccount::Alice)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+            .unwrap()
+            .amount
+            .u128()
+    };
+    let alice_balance = get_noria_balance(&project);
+    project.wait(MINS_PER_DAY as u64 * NANOS_PER_MIN as u64);
+
+    project.staking_platform_try_create_proposal(
+        ProjectAccount::Admin,
+        &get_proposal(ProposalType::UpdateCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            new_collection_address: Some(ProjectNft::Pinjeons.to_string()),
+            token_id_mapping: Some(vec![(Uint128::new(1), Uint128::new(101))]),
+            new_collection: get_collection(ProjectNft::Pinjeons),
+        }),
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        2,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_migrate_collection(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        None,
+    )?;
+
+    // balance is spent first, the shortfall is minted within mint cap
+    assert_that(&(get_noria_balance(&project) - alice_balance)).is_equal_to(200_000);
+
+    let collections_balances = project.staking_platform_query_collections_balances(&None)?;
+    assert_that(&collections_balances.len()).is_equal_to(1);
+    assert_that(&collections_balances[0].funds.amount.u128()).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn claim_staking_rewards_with_reward_curve() -> StdResult<()> {
     let mut project = Project::new();
 
+    let get_proposal = |point_list: Vec<(&str, &str)>| Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_cw20(&ProjectToken::Atom.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("1"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: Some(RewardCurve {
+                    point_list: point_list
+                        .into_iter()
+                        .map(|(utilization, daily_rewards)| RewardCurvePoint {
+                            utilization: str_to_dec(utilization),
+                            daily_rewards: str_to_dec(daily_rewards),
+                        })
+                        .collect(),
+                }),
+            },
+        },
+    };
+
     let res = project
-        .minter_try_create_denom(
-            ProjectAccount::Alice,
+        .staking_platform_try_create_proposal(
+            ProjectAccount::Admin,
+            &get_proposal(vec![("1", "0"), ("0", "60000")]),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongRewardCurve);
+
+    // rate is tapering from 60_000 to 0 as utilization grows
+    project.staking_platform_try_create_proposal(
+        ProjectAccount::Admin,
+        &get_proposal(vec![("0", "60000"), ("1", "0")]),
+    )?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+    project.staking_platform_try_deposit_tokens(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        1_000_000u128,
+        ProjectToken::Atom,
+    )?;
+
+    let collection_info = |token_id_list: Vec<u128>| {
+        vec![StakedCollectionInfo {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            staked_token_info_list: token_id_list
+                .into_iter()
+                .map(|token_id| StakedTokenInfo {
+                    token_id: Uint128::new(token_id),
+                    staking_start_date: None,
+                    last_claim_date: None,
+                })
+                .collect(),
+        }]
+    };
+
+    for staker in [ProjectAccount::Alice, ProjectAccount::Bob] {
+        project.increase_allowances_nft(
+            staker,
+            project.get_staking_platform_address(),
+            ProjectNft::Gopniks,
+        );
+    }
+
+    let get_rewards = |project: &Project, staker: ProjectAccount| -> u128 {
+        project
+            .staking_platform_query_staking_rewards(staker)
+            .unwrap()
+            .funds_list[0]
+            .amount
+            .u128()
+    };
+    let day = MINS_PER_DAY as u64 * NANOS_PER_MIN as u64;
+
+    // 1 of 6 NFTs is staked, then 3 of 6
+    project.staking_platform_try_stake(ProjectAccount::Alice, &collection_info(vec![1]))?;
+    project.wait(day);
+    project.staking_platform_try_stake(ProjectAccount::Bob, &collection_info(vec![4, 5]))?;
+    project.wait(day);
+
+    assert_that(&get_rewards(&project, ProjectAccount::Alice)).is_equal_to(80_000);
+    assert_that(&get_rewards(&project, ProjectAccount::Bob)).is_equal_to(60_000);
+
+    // 3 of 12 NFTs are staked after supply refresh
+    project.mint_nft(
+        ProjectAccount::Owner,
+        ProjectAccount::Alice,
+        ProjectNft::Gopniks,
+        &(7..=12).collect::<Vec<u128>>(),
+    );
+
+    let res = project
+        .staking_platform_try_refresh_collection_supply(ProjectAccount::Alice, ProjectNft::Gopniks)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    project.staking_platform_try_refresh_collection_supply(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+    )?;
+    project.wait(day);
+
+    assert_that(&get_rewards(&project, ProjectAccount::Alice)).is_equal_to(125_000);
+    assert_that(&get_rewards(&project, ProjectAccount::Bob)).is_equal_to(150_000);
+
+    // rewards aren't accumulated during pause and accrued ones are kept after resume
+    for (id, proposal_type) in [
+        ProposalType::PauseCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            owner: ProjectAccount::Owner.to_string(),
+        },
+        ProposalType::ResumeCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            owner: ProjectAccount::Owner.to_string(),
+        },
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        project.staking_platform_try_create_proposal(
+            ProjectAccount::Admin,
+            &Proposal {
+                proposal_status: None,
+                price: Funds::new(
+                    0u128,
+                    &Currency::new(
+                        &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                        6,
+                    ),
+                ),
+                proposal_type,
+            },
+        )?;
+        project.staking_platform_try_accept_proposal(
             ProjectAccount::Owner,
+            id as u128 + 2,
+            0,
+            ProjectCoin::Denom,
+        )?;
+        project.wait(day);
+    }
+
+    assert_that(&get_rewards(&project, ProjectAccount::Alice)).is_equal_to(170_000);
+    assert_that(&get_rewards(&project, ProjectAccount::Bob)).is_equal_to(240_000);
+
+    Ok(())
+}
+
+#[test]
+fn claim_and_reclaim_merkle_campaign() -> StdResult<()> {
+    let mut project = Project::new();
+
+    let proposal: &Proposal<String, TokenUnverified> = &Proposal {
+        proposal_status: None,
+        price: Funds::new(
+            100u128,
+            &Currency::new(
+                &TokenUnverified::new_native(&ProjectCoin::Denom.to_string()),
+                6,
+            ),
+        ),
+        proposal_type: ProposalType::AddCollection {
+            collection_address: ProjectNft::Gopniks.to_string(),
+            collection: Collection {
+                name: ProjectNft::Gopniks.to_string(),
+                staking_currency: Currency::new(
+                    &TokenUnverified::new_native(&ProjectCoin::Noria.to_string()),
+                    6,
+                ),
+                daily_rewards: str_to_dec("100000"),
+                emission_type: EmissionType::Spending,
+                owner: ProjectAccount::Owner.to_string(),
+                max_staked_per_address: None,
+                max_total_staked: None,
+                min_stake_duration: None,
+                restake_cooldown: None,
+                fixed_pool: None,
+                hybrid_emission: None,
+                reward_curve: None,
+            },
+        },
+    };
+
+    project.staking_platform_try_create_proposal(ProjectAccount::Admin, proposal)?;
+    project.staking_platform_try_accept_proposal(
+        ProjectAccount::Owner,
+        1,
+        100,
+        ProjectCoin::Denom,
+    )?;
+
+    // tree of 2 leaves, each leaf is a proof of another one
+    let hash = |x: &[u8]| -> [u8; 32] { Sha256::digest(x).into() };
+    let get_leaf = |project: &Project, account: ProjectAccount, amount: u128| {
+        hash(
+            format!(
+                "{}:{}:{}:{}",
+                project.get_staking_platform_address(),
+                1,
+                account,
+                amount
+            )
+            .as_bytes(),
+        )
+    };
+    let alice_leaf = get_leaf(&project, ProjectAccount::Alice, 100_000);
+    let bob_leaf = get_leaf(&project, ProjectAccount::Bob, 200_000);
+    let merkle_root = hex::encode(hash(&if alice_leaf <= bob_leaf {
+        [alice_leaf, bob_leaf].concat()
+    } else {
+        [bob_leaf, alice_leaf].concat()
+    }));
+    let alice_proof = vec![hex::encode(bob_leaf)];
+
+    let day = MINS_PER_DAY as u64 * NANOS_PER_MIN as u64;
+    let expiration_date = project.get_timestamp().plus_nanos(day);
+
+    let res = project
+        .staking_platform_try_create_campaign(
+            ProjectAccount::Alice,
+            ProjectNft::Gopniks,
+            &merkle_root,
+            expiration_date,
+            300_000u128,
             ProjectCoin::Noria,
-            (1, ProjectCoin::Denom),
         )
         .unwrap_err();
-
     assert_error(&res, ContractError::Unauthorized);
 
-    project.minter_try_create_denom(
-        ProjectAccount::Admin,
+    let res = project
+        .staking_platform_try_create_campaign(
+            ProjectAccount::Owner,
+            ProjectNft::Gopniks,
+            "abc",
+            expiration_date,
+            300_000u128,
+            ProjectCoin::Noria,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongMerkleRoot);
+
+    project.staking_platform_try_create_campaign(
         ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        &merkle_root,
+        expiration_date,
+        300_000u128,
         ProjectCoin::Noria,
-        (1, ProjectCoin::Denom),
     )?;
 
-    let metadata = &Metadata {
-        description: "Noria token".to_string(),
-        denom_units: vec![DenomUnit {
-            denom: ProjectCoin::Noria.to_string(),
-            exponent: ProjectCoin::Noria.get_decimals() as u32,
-            aliases: vec![],
-        }],
-        base: ProjectCoin::Noria.to_string(),
-        display: "noria".to_string(),
-        name: "Noria token".to_string(),
-        symbol: "NORIA".to_string(),
-        uri: None,
-        uri_hash: None,
+    let get_noria_balance = |project: &Project, account: ProjectAccount| -> u128 {
+        project
+            .query_all_balances(account)
+            .unwrap()
+            .iter()
+            .find(|x| x.currency.token == Token::new_native(&ProjectCoin::Noria.to_string()))
+            .unwrap()
+            .amount
+            .u128()
     };
 
+    // leaf can be claimed only once and only with its amount
     let res = project
-        .minter_try_set_metadata(ProjectAccount::Alice, metadata.to_owned())
+        .staking_platform_try_claim_campaign(ProjectAccount::Alice, 1, 200_000u128, &alice_proof)
         .unwrap_err();
+    assert_error(&res, ContractError::WrongMerkleProof);
 
-    assert_error(&res, ContractError::Unauthorized);
+    let res = project
+        .staking_platform_try_claim_campaign(ProjectAccount::Alice, 1, 0u128, &alice_proof)
+        .unwrap_err();
+    assert_error(&res, ContractError::CampaignClaimAmountIsZero);
 
-    project.minter_try_set_metadata(ProjectAccount::Owner, metadata.to_owned())?;
+    // paused claiming of staking rewards doesn't block campaign claims
+    project.staking_platform_try_pause_actions(
+        ProjectAccount::Admin,
+        &[ActionGroup::Claiming],
+        Some(ProjectNft::Gopniks),
+        "incident",
+        None,
+    )?;
+
+    let alice_balance = get_noria_balance(&project, ProjectAccount::Alice);
+    project.staking_platform_try_claim_campaign(
+        ProjectAccount::Alice,
+        1,
+        100_000u128,
+        &alice_proof,
+    )?;
+    assert_that(&(get_noria_balance(&project, ProjectAccount::Alice) - alice_balance))
+        .is_equal_to(100_000);
+
+    let res = project
+        .staking_platform_try_claim_campaign(ProjectAccount::Alice, 1, 100_000u128, &alice_proof)
+        .unwrap_err();
+    assert_error(&res, ContractError::CampaignRewardsAreClaimed);
+
+    assert_that(&project.staking_platform_query_campaign_claim(1, ProjectAccount::Alice)?)
+        .is_equal_to(Some(Uint128::new(100_000)));
+    assert_that(&project.staking_platform_query_campaign_claim(1, ProjectAccount::Bob)?)
+        .is_equal_to(None);
+
+    // unclaimed funds are reclaimable only after expiration
+    let res = project
+        .staking_platform_try_reclaim_campaign(ProjectAccount::Owner, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::CampaignIsNotExpired);
+
+    project.wait(day);
+
+    let res = project
+        .staking_platform_try_claim_campaign(
+            ProjectAccount::Bob,
+            1,
+            200_000u128,
+            &[hex::encode(alice_leaf)],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::CampaignIsExpired);
+
+    let owner_balance = get_noria_balance(&project, ProjectAccount::Owner);
+    project.staking_platform_try_reclaim_campaign(ProjectAccount::Owner, 1)?;
+    assert_that(&(get_noria_balance(&project, ProjectAccount::Owner) - owner_balance))
+        .is_equal_to(200_000);
+    assert_that(&project.staking_platform_query_campaigns(None, None)?.len()).is_equal_to(0);
 
     Ok(())
 }